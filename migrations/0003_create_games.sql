@@ -0,0 +1,8 @@
+-- Create games table so games in progress survive a server restart
+-- Each row holds one room's game, serialized as JSON by the server
+
+CREATE TABLE IF NOT EXISTS games (
+    room_id VARCHAR(255) PRIMARY KEY,  -- Pet-name room ID
+    state TEXT NOT NULL,  -- Serialized Game
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()  -- Last time the game changed
+);
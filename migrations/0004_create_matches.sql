@@ -0,0 +1,8 @@
+-- Create matches table so a match's standings survive a server restart
+-- Each row holds one room's match, serialized as JSON by the server
+
+CREATE TABLE IF NOT EXISTS matches (
+    room_id VARCHAR(255) PRIMARY KEY,  -- Pet-name room ID
+    state TEXT NOT NULL,  -- Serialized Match
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()  -- Last time the match changed
+);
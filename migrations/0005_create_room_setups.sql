@@ -0,0 +1,8 @@
+-- Create room_setups table so a room's settings and bots survive a server restart
+-- Each row holds one room's setup, serialized as JSON by the server
+
+CREATE TABLE IF NOT EXISTS room_setups (
+    room_id VARCHAR(255) PRIMARY KEY,  -- Pet-name room ID
+    state TEXT NOT NULL,  -- Serialized RoomSetup
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()  -- Last time the setup changed
+);
@@ -2,7 +2,7 @@ use async_trait::async_trait;
 use rand::Rng;
 use tracing::debug;
 
-use crate::game::{Card, Game, Hand, Rank, Suit};
+use crate::game::{Card, Game};
 
 use super::types::BotStrategy;
 
@@ -20,96 +20,14 @@ impl BasicBotStrategy {
         Self
     }
 
-    /// Find all valid single card moves
-    fn find_valid_singles(&self, game: &Game, available_cards: &[Card]) -> Vec<Vec<Card>> {
-        Self::find_ordered_moves(game, available_cards, 1)
-    }
-
-    /// Find all valid pair moves
-    fn find_valid_pairs(&self, game: &Game, available_cards: &[Card]) -> Vec<Vec<Card>> {
-        Self::find_ordered_moves(game, available_cards, 2)
-    }
-
-    /// Find all valid triple moves
-    fn find_valid_triples(&self, game: &Game, available_cards: &[Card]) -> Vec<Vec<Card>> {
-        Self::find_ordered_moves(game, available_cards, 3)
-    }
-
-    /// Find all valid five-card moves (straights, flushes, full houses, etc.)
-    fn find_valid_five_card_hands(&self, game: &Game, available_cards: &[Card]) -> Vec<Vec<Card>> {
-        Self::find_ordered_moves(game, available_cards, 5)
-    }
-
-    fn find_ordered_moves(game: &Game, available_cards: &[Card], size: usize) -> Vec<Vec<Card>> {
-        if available_cards.len() < size || size == 0 {
-            return Vec::new();
-        }
-
-        Self::generate_suit_sorted_combinations(available_cards, size)
-            .into_iter()
-            .filter(|combo| Self::is_valid_move(game, combo))
-            .collect()
-    }
-
-    fn generate_suit_sorted_combinations(cards: &[Card], size: usize) -> Vec<Vec<Card>> {
-        if cards.len() < size || size == 0 {
-            return Vec::new();
-        }
-
-        let mut sorted_cards = cards.to_vec();
-        sorted_cards.sort();
-
-        let mut result = Vec::new();
-        let mut current = Vec::with_capacity(size);
-        Self::build_combinations(&sorted_cards, size, 0, &mut current, &mut result);
-        result
-    }
-
-    fn build_combinations(
-        cards: &[Card],
-        size: usize,
-        start: usize,
-        current: &mut Vec<Card>,
-        result: &mut Vec<Vec<Card>>,
-    ) {
-        if current.len() == size {
-            result.push(current.clone());
-            return;
-        }
-
-        let remaining = size - current.len();
-        for i in start..=cards.len() - remaining {
-            current.push(cards[i]);
-            Self::build_combinations(cards, size, i + 1, current, result);
-            current.pop();
-        }
-    }
-
-    fn is_valid_move(game: &Game, cards: &[Card]) -> bool {
-        let hand = match Hand::from_cards(cards) {
-            Ok(hand) => hand,
-            Err(_) => return false,
-        };
-
-        let first_turn = game.played_hands().is_empty();
-        let table_clear = game.consecutive_passes() >= 3;
-
-        if first_turn {
-            let three_diamonds = Card::new(Rank::Three, Suit::Diamonds);
-            if !cards.contains(&three_diamonds) {
-                return false;
-            }
+    /// Drop five-card bombs against a single, pair or triple unless nothing else beats it
+    fn hold_back_bombs(game: &Game, valid_moves: Vec<Vec<Card>>) -> Vec<Vec<Card>> {
+        let facing_small_hand = !game.is_table_clear() && game.last_non_pass_cards().len() < 5;
+        if !facing_small_hand || valid_moves.iter().all(|mv| mv.len() == 5) {
+            return valid_moves;
         }
 
-        if table_clear || first_turn {
-            return true;
-        }
-
-        if let Some(last_hand) = game.played_hands().iter().rev().find(|h| **h != Hand::Pass) {
-            hand.can_beat(last_hand)
-        } else {
-            true
-        }
+        valid_moves.into_iter().filter(|mv| mv.len() < 5).collect()
     }
 
     /// Choose the best move from available options
@@ -218,37 +136,17 @@ impl BotStrategy for BasicBotStrategy {
             return None;
         }
 
-        // Get the bot's available cards
-        let bot_player = game.players().iter().find(|p| p.uuid == bot_uuid)?;
-        let available_cards = &bot_player.cards;
-
-        debug!(
-            bot_uuid = %bot_uuid,
-            card_count = available_cards.len(),
-            "Bot deciding move"
-        );
-
-        // Find all valid moves
-        let mut all_valid_moves = Vec::new();
+        debug!(bot_uuid = %bot_uuid, "Bot deciding move");
 
-        // Try singles
-        all_valid_moves.extend(self.find_valid_singles(game, available_cards));
-
-        // Try pairs (only if we have at least 2 cards)
-        if available_cards.len() >= 2 {
-            all_valid_moves.extend(self.find_valid_pairs(game, available_cards));
-        }
-
-        if available_cards.len() >= 3 {
-            all_valid_moves.extend(self.find_valid_triples(game, available_cards));
-        }
-
-        if available_cards.len() >= 5 {
-            all_valid_moves.extend(self.find_valid_five_card_hands(game, available_cards));
-        }
+        // The game enumerates every legal play, so the bot never disagrees with play_cards
+        let all_valid_moves: Vec<Vec<Card>> = game
+            .legal_moves(bot_uuid)
+            .hands()
+            .map(|hand| hand.to_cards())
+            .collect();
 
         // Choose the best move
-        let chosen_move = self.choose_best_move(all_valid_moves);
+        let chosen_move = self.choose_best_move(Self::hold_back_bombs(game, all_valid_moves));
 
         debug!(
             bot_uuid = %bot_uuid,
@@ -267,8 +165,8 @@ impl BotStrategy for BasicBotStrategy {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::game::SingleHand;
     use crate::game::{Game, Player};
+    use crate::game::{Hand, Rank, RuleSet, SingleHand, Suit};
     use rand::{rngs::StdRng, Rng, SeedableRng};
 
     #[tokio::test]
@@ -566,6 +464,72 @@ mod tests {
         assert_eq!(move_decision.unwrap(), expected_straight);
     }
 
+    fn bomb_game(bot_cards: Vec<Card>) -> Game {
+        let players = vec![
+            Player {
+                name: "Bot".to_string(),
+                uuid: "bot-123".to_string(),
+                cards: bot_cards,
+            },
+            Player {
+                name: "Human".to_string(),
+                uuid: "human-456".to_string(),
+                cards: vec![Card::new(Rank::Five, Suit::Spades)],
+            },
+        ];
+
+        Game::new(
+            "test".to_string(),
+            players.clone(),
+            0,
+            0,
+            vec![Hand::Single(SingleHand::new(Card::new(
+                Rank::Ace,
+                Suit::Spades,
+            )))],
+            players
+                .iter()
+                .map(|p| (p.uuid.clone(), p.cards.clone()))
+                .collect(),
+        )
+        .with_rules(RuleSet::standard().with_bombs())
+    }
+
+    fn quad_fours() -> Vec<Card> {
+        vec![
+            Card::new(Rank::Four, Suit::Diamonds),
+            Card::new(Rank::Four, Suit::Clubs),
+            Card::new(Rank::Four, Suit::Hearts),
+            Card::new(Rank::Four, Suit::Spades),
+            Card::new(Rank::Six, Suit::Diamonds),
+        ]
+    }
+
+    #[tokio::test]
+    async fn test_bot_bombs_when_nothing_else_beats_the_table() {
+        let strategy = BasicBotStrategy::new();
+        let game = bomb_game(quad_fours());
+
+        let move_decision = strategy.decide_move(&game, "bot-123").await.unwrap();
+        let mut expected = quad_fours();
+        expected.sort();
+        assert_eq!(move_decision, expected);
+    }
+
+    #[tokio::test]
+    async fn test_bot_holds_back_bomb_when_a_single_beats_the_table() {
+        let strategy = BasicBotStrategy::new();
+        let mut cards = quad_fours();
+        cards.push(Card::new(Rank::Two, Suit::Diamonds));
+        let game = bomb_game(cards);
+
+        let move_decision = strategy.decide_move(&game, "bot-123").await;
+        assert_eq!(
+            move_decision,
+            Some(vec![Card::new(Rank::Two, Suit::Diamonds)])
+        );
+    }
+
     #[test]
     fn test_choose_best_move_uses_average_within_category() {
         let strategy = BasicBotStrategy::new();
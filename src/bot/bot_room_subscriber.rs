@@ -5,11 +5,14 @@ use tracing::{debug, error, info};
 
 use crate::{
     event::{EventBus, RoomEvent, RoomEventError, RoomEventHandler},
-    game::GameService,
+    game::{Game, GameService},
 };
 
 use super::{manager::BotManager, strategy_factory::BotStrategyFactory};
 
+/// Longest a strategy may think before the bot is made to pass
+pub const STRATEGY_TIMEOUT: Duration = Duration::from_secs(5);
+
 /// Event subscriber that handles bot actions in response to game events
 pub struct BotRoomSubscriber {
     bot_manager: Arc<BotManager>,
@@ -58,50 +61,37 @@ impl BotRoomSubscriber {
             "Bot's turn detected, deciding move"
         );
 
-        // Get the current game state
-        let game = match self.game_service.get_game(room_id).await {
-            Some(game) => game,
-            None => {
-                debug!(
-                    room_id = %room_id,
-                    bot_uuid = %player_uuid,
-                    "Game not found (possibly deleted or reset), skipping bot move"
-                );
-                return Ok(());
-            }
-        };
-
-        // Verify it's still the bot's turn (guard against race conditions)
-        if game.current_player_turn() != player_uuid {
-            debug!(
-                room_id = %room_id,
-                bot_uuid = %player_uuid,
-                current_turn = %game.current_player_turn(),
-                "Turn changed before bot could act, skipping move"
-            );
-            return Ok(());
-        }
-
-        // Add a small delay to simulate human thinking (100-500ms random)
-        let delay_ms = 100 + (rand::random::<u64>() % 400);
-        sleep(Duration::from_millis(delay_ms)).await;
-
-        // Get strategy based on bot difficulty
-        let strategy = BotStrategyFactory::create_strategy(bot.difficulty);
+        // Get strategy based on bot difficulty, time budget and profile
+        let strategy = BotStrategyFactory::create_strategy_for(&bot);
+
+        // Pause to simulate human thinking, for as long as the bot's profile says. The pause
+        // counts against STRATEGY_TIMEOUT and is taken off the strategy's own thinking time.
+        let delay = bot.profile.think_time.sample(&mut rand::rng());
+        let decision = tokio::time::timeout(STRATEGY_TIMEOUT, async {
+            sleep(delay).await;
+
+            // Read the table only after the pause, so the move answers the game as it is now
+            let Some(game) = self.game_on_turn(room_id, player_uuid).await? else {
+                return Ok(None);
+            };
+            Ok(Some(
+                strategy.decide_move_after(&game, player_uuid, delay).await,
+            ))
+        })
+        .await;
 
         // Use strategy to decide on a move with error handling
-        let move_decision = match tokio::time::timeout(
-            Duration::from_secs(5),
-            strategy.decide_move(&game, player_uuid),
-        )
-        .await
-        {
-            Ok(decision) => decision,
+        let move_decision = match decision {
+            Ok(Ok(Some(decision))) => decision,
+            // The game is gone or the turn moved on while the bot was waiting
+            Ok(Ok(None)) => return Ok(()),
+            Ok(Err(e)) => return Err(e),
             Err(_) => {
                 error!(
                     room_id = %room_id,
                     bot_uuid = %player_uuid,
-                    "Bot strategy timed out after 5 seconds, forcing pass"
+                    timeout = ?STRATEGY_TIMEOUT,
+                    "Bot strategy timed out, forcing pass"
                 );
                 None
             }
@@ -138,6 +128,44 @@ impl BotRoomSubscriber {
 
         Ok(())
     }
+
+    /// The room's game, if it is still the bot's turn in it
+    async fn game_on_turn(
+        &self,
+        room_id: &str,
+        player_uuid: &str,
+    ) -> Result<Option<Game>, RoomEventError> {
+        let game = match self.game_service.get_game(room_id).await {
+            Ok(Some(game)) => game,
+            Ok(None) => {
+                debug!(
+                    room_id = %room_id,
+                    bot_uuid = %player_uuid,
+                    "Game not found (possibly deleted or reset), skipping bot move"
+                );
+                return Ok(None);
+            }
+            Err(e) => {
+                return Err(RoomEventError::HandlerError(format!(
+                    "Failed to load game: {}",
+                    e
+                )));
+            }
+        };
+
+        // Verify it's still the bot's turn (guard against race conditions)
+        if game.current_player_turn() != player_uuid {
+            debug!(
+                room_id = %room_id,
+                bot_uuid = %player_uuid,
+                current_turn = %game.current_player_turn(),
+                "Turn changed before bot could act, skipping move"
+            );
+            return Ok(None);
+        }
+
+        Ok(Some(game))
+    }
 }
 
 #[async_trait]
@@ -151,6 +179,23 @@ impl RoomEventHandler for BotRoomSubscriber {
             RoomEvent::TurnChanged { player } => {
                 self.handle_turn_changed(room_id, &player).await?;
             }
+            RoomEvent::TakeBackRequested { voters, .. } => {
+                // Bots never object to a take-back
+                for voter in voters {
+                    if self.bot_manager.is_bot(&voter).await {
+                        self.event_bus
+                            .emit_to_room(
+                                room_id,
+                                RoomEvent::TakeBackVote {
+                                    player: voter,
+                                    approve: true,
+                                },
+                            )
+                            .await;
+                    }
+                }
+            }
+            #[allow(clippy::collapsible_match)] // Keep awaiting out of the match guard
             RoomEvent::GameWon { winner, .. } => {
                 if self.bot_manager.is_bot(&winner).await {
                     info!(
@@ -176,6 +221,7 @@ impl RoomEventHandler for BotRoomSubscriber {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::bot::profile::BotProfile;
     use crate::{
         game::{Card, Rank, Suit},
         user::{mapping_service::InMemoryPlayerMappingService, PlayerMappingService},
@@ -196,6 +242,8 @@ mod tests {
             .create_bot(
                 "room1".to_string(),
                 super::super::types::BotDifficulty::Easy,
+                None,
+                BotProfile::default(),
             )
             .await
             .unwrap();
@@ -272,4 +320,70 @@ mod tests {
         let recv_result = tokio::time::timeout(Duration::from_millis(100), rx.recv()).await;
         assert!(recv_result.is_err()); // Timeout means no event was received
     }
+
+    #[tokio::test]
+    async fn test_bot_reads_the_game_after_its_pause() {
+        use crate::bot::profile::ThinkTime;
+        let bot_manager = Arc::new(BotManager::new());
+        let player_mapping = Arc::new(InMemoryPlayerMappingService::new());
+        let game_service = Arc::new(GameService::new(player_mapping));
+        let event_bus = EventBus::new();
+        let subscriber = Arc::new(BotRoomSubscriber::new(
+            bot_manager.clone(),
+            game_service.clone(),
+            event_bus.clone(),
+        ));
+
+        let bot = bot_manager
+            .create_bot(
+                "room1".to_string(),
+                super::super::types::BotDifficulty::Easy,
+                None,
+                BotProfile {
+                    think_time: ThinkTime {
+                        min_ms: 300,
+                        max_ms: 300,
+                    },
+                    ..BotProfile::default()
+                },
+            )
+            .await
+            .unwrap();
+        game_service
+            .create_game_with_cards(
+                "room1",
+                vec![
+                    (
+                        bot.name.clone(),
+                        bot.uuid.clone(),
+                        vec![Card::new(Rank::Three, Suit::Diamonds)],
+                    ),
+                    (
+                        "Human".to_string(),
+                        "human-123".to_string(),
+                        vec![Card::new(Rank::Four, Suit::Hearts)],
+                    ),
+                ],
+            )
+            .await
+            .unwrap();
+        let mut rx = event_bus.subscribe_to_room("room1").await;
+
+        let turn = tokio::spawn({
+            let subscriber = subscriber.clone();
+            let player = bot.uuid.clone();
+            async move {
+                subscriber
+                    .handle_room_event("room1", RoomEvent::TurnChanged { player })
+                    .await
+            }
+        });
+        // The game ends while the bot is still pausing, so it has nothing to play
+        sleep(Duration::from_millis(100)).await;
+        game_service.remove_game("room1").await;
+
+        assert!(turn.await.unwrap().is_ok());
+        let recv_result = tokio::time::timeout(Duration::from_millis(100), rx.recv()).await;
+        assert!(recv_result.is_err());
+    }
 }
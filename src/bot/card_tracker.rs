@@ -0,0 +1,426 @@
+// Card counting from public information only: the bot's own hand, the hands played so far,
+// how many cards each opponent holds, and which hands each opponent passed on. Unseen cards
+// and card counts are hard facts. A pass is only a hint that the opponent held nothing that
+// beats the hand, since players also pass on purpose (holding back a 2, say), so passes shape
+// sampled deals and heuristics but never what the bot treats as certain. Cards nobody was
+// dealt are never revealed, so they count as unseen like any other.
+use std::collections::HashSet;
+
+use rand::{seq::SliceRandom, Rng};
+use strum::IntoEnumIterator;
+
+use crate::game::{Card, CardSet, Game, Hand, Rank, RuleSet, Suit};
+
+/// Deals tried to find one that fits every opponent's passes
+const DEAL_ATTEMPTS: usize = 20;
+/// Above this many unseen cards, every five-card hand is assumed possible rather than searched
+const FIVE_CARD_SEARCH_LIMIT: usize = 20;
+
+/// What the bot knows about one opponent
+#[derive(Debug, Clone)]
+pub struct Opponent {
+    pub uuid: String,
+    pub card_count: usize,
+    /// Hands this opponent chose not to beat
+    passed_on: Vec<Hand>,
+}
+
+impl Opponent {
+    pub fn is_out(&self) -> bool {
+        self.card_count == 0
+    }
+}
+
+/// Everything a bot can work out about the cards it cannot see
+#[derive(Debug, Clone)]
+pub struct CardTracker {
+    bot_uuid: String,
+    /// Cards neither held by the bot nor played yet
+    unseen: CardSet,
+    opponents: Vec<Opponent>,
+    rules: RuleSet,
+    /// Singles, pairs, triples and bombs that could be made from unseen cards
+    possible_hands: Vec<Hand>,
+    /// Five-card hands that could be made from unseen cards, None if too many to list
+    possible_five_card_hands: Option<Vec<Hand>>,
+}
+
+impl CardTracker {
+    pub fn from_game(game: &Game, bot_uuid: &str) -> Self {
+        let rules = *game.rules();
+        let mut unseen: CardSet = Rank::iter()
+            .flat_map(|rank| Suit::iter().map(move |suit| Card::new(rank, suit)))
+            .collect();
+        let seen = game
+            .played_hands()
+            .iter()
+            .flat_map(|hand| hand.to_cards())
+            .chain(
+                game.players()
+                    .iter()
+                    .filter(|p| p.uuid == bot_uuid)
+                    .flat_map(|p| p.cards.iter().copied()),
+            );
+        for card in seen {
+            unseen.remove(card);
+        }
+
+        let mut opponents: Vec<Opponent> = game
+            .players()
+            .iter()
+            .filter(|p| p.uuid != bot_uuid)
+            .map(|p| Opponent {
+                uuid: p.uuid.clone(),
+                card_count: p.cards.len(),
+                passed_on: Vec::new(),
+            })
+            .collect();
+
+        // Replay the move log to find what each opponent was facing when they passed
+        let mut to_beat: Option<&Hand> = None;
+        let mut passed_this_round: HashSet<&str> = HashSet::new();
+        for record in game.moves() {
+            if record.table_clear {
+                to_beat = None;
+                passed_this_round.clear();
+            }
+            if record.hand != Hand::Pass {
+                to_beat = Some(&record.hand);
+                continue;
+            }
+
+            // A player locked out by an earlier pass had no choice, so it says nothing new
+            let forced = game.house_rules().pass_lockout
+                && passed_this_round.contains(record.player_uuid.as_str());
+            passed_this_round.insert(&record.player_uuid);
+            if let (Some(hand), false) = (to_beat, forced) {
+                if let Some(opponent) = opponents.iter_mut().find(|o| o.uuid == record.player_uuid)
+                {
+                    opponent.passed_on.push(hand.clone());
+                }
+            }
+        }
+
+        let possible_hands = Self::small_hands(unseen, &rules)
+            .into_iter()
+            .chain(Self::bombs(unseen, &rules))
+            .collect();
+        let possible_five_card_hands = (unseen.len() <= FIVE_CARD_SEARCH_LIMIT)
+            .then(|| Self::classify_all(unseen.subsets(5), &rules));
+
+        Self {
+            bot_uuid: bot_uuid.to_string(),
+            unseen,
+            opponents,
+            rules,
+            possible_hands,
+            possible_five_card_hands,
+        }
+    }
+
+    fn classify_all(sets: Vec<CardSet>, rules: &RuleSet) -> Vec<Hand> {
+        sets.into_iter()
+            .filter_map(|set| Hand::from_cards_with_rules(&set.to_vec(), rules).ok())
+            .collect()
+    }
+
+    fn small_hands(unseen: CardSet, rules: &RuleSet) -> Vec<Hand> {
+        let sets = Rank::iter()
+            .flat_map(|rank| (1..=3).flat_map(move |size| unseen.of_rank(rank).subsets(size)))
+            .collect();
+        Self::classify_all(sets, rules)
+    }
+
+    /// Four of a kinds and straight flushes; only worth listing when they beat smaller hands
+    fn bombs(unseen: CardSet, rules: &RuleSet) -> Vec<Hand> {
+        if !rules.bombs && !rules.quad_beats_single_two {
+            return Vec::new();
+        }
+
+        let mut sets = Vec::new();
+        for rank in Rank::iter() {
+            let quad = unseen.of_rank(rank);
+            // Any kicker will do: it never changes what a four of a kind beats
+            if let (4, Some(kicker)) = (quad.len(), unseen.difference(&quad).lowest()) {
+                let mut set = quad;
+                set.insert(kicker);
+                sets.push(set);
+            }
+        }
+        for suit in Suit::iter() {
+            sets.extend(unseen.of_suit(suit).subsets(5));
+        }
+
+        Self::classify_all(sets, rules)
+            .into_iter()
+            .filter(|hand| matches!(hand, Hand::Five(five) if five.is_bomb()))
+            .collect()
+    }
+
+    /// Cards neither held by the bot nor played yet
+    #[allow(dead_code)] // Public API for bots
+    pub fn unseen(&self) -> CardSet {
+        self.unseen
+    }
+
+    #[allow(dead_code)] // Public API for bots
+    pub fn opponents(&self) -> &[Opponent] {
+        &self.opponents
+    }
+
+    /// Opponents still holding cards
+    pub fn active_opponents(&self) -> impl Iterator<Item = &Opponent> {
+        self.opponents.iter().filter(|o| !o.is_out())
+    }
+
+    /// Card count of the opponent closest to going out
+    #[allow(dead_code)] // Public API for bots
+    pub fn fewest_cards(&self) -> Option<usize> {
+        self.active_opponents().map(|o| o.card_count).min()
+    }
+
+    /// Whether `opponent` might hold a play that beats `hand`, judged from the unseen cards and
+    /// their card count alone
+    pub fn could_beat(&self, opponent: &Opponent, hand: &Hand) -> bool {
+        self.has_beating_play(opponent, hand, false)
+    }
+
+    /// Like `could_beat`, but also assumes the opponent would have played rather than pass on
+    /// anything they could beat; a guess, not a certainty
+    pub fn likely_to_beat(&self, opponent: &Opponent, hand: &Hand) -> bool {
+        self.has_beating_play(opponent, hand, true)
+    }
+
+    fn has_beating_play(&self, opponent: &Opponent, hand: &Hand, trust_passes: bool) -> bool {
+        if opponent.is_out() {
+            return false;
+        }
+
+        let held_possible = |play: &Hand| {
+            play.to_cards().len() <= opponent.card_count
+                && play.can_beat_with_rules(hand, &self.rules)
+                // They would have played it rather than pass
+                && !(trust_passes
+                    && opponent
+                        .passed_on
+                        .iter()
+                        .any(|passed| play.can_beat_with_rules(passed, &self.rules)))
+        };
+
+        if self.possible_hands.iter().any(held_possible) {
+            return true;
+        }
+        match (hand, &self.possible_five_card_hands) {
+            (Hand::Five(_), Some(fives)) => fives.iter().any(held_possible),
+            // Too many unseen cards to rule anything out
+            (Hand::Five(_), None) => opponent.card_count >= 5,
+            _ => false,
+        }
+    }
+
+    /// Whether `opponent` could be holding exactly `cards`, given the hands they passed on
+    ///
+    /// Only singles, pairs and triples are checked, which keeps it cheap enough to run on
+    /// every sampled deal.
+    pub fn could_hold(&self, opponent: &Opponent, cards: CardSet) -> bool {
+        cards.len() == opponent.card_count
+            && (opponent.passed_on.is_empty()
+                || Self::small_hands(cards, &self.rules).iter().all(|play| {
+                    !opponent
+                        .passed_on
+                        .iter()
+                        .any(|passed| play.can_beat_with_rules(passed, &self.rules))
+                }))
+    }
+
+    /// Deal the unseen cards at random, each opponent getting as many as they hold
+    ///
+    /// Hands are in seating order, the bot's own included. No opponent is dealt a single card
+    /// that beats a hand they passed on; pairs and triples that would are redrawn a few times
+    /// before a hand is accepted anyway.
+    pub fn sample_deal<R: Rng + ?Sized>(&self, game: &Game, rng: &mut R) -> Vec<CardSet> {
+        let mut hands: Vec<CardSet> = game
+            .players()
+            .iter()
+            .map(|p| {
+                if p.uuid == self.bot_uuid {
+                    p.cards.iter().copied().collect()
+                } else {
+                    CardSet::EMPTY
+                }
+            })
+            .collect();
+
+        // Deal to the most restricted opponents first, while there is the most to choose from
+        let mut opponents: Vec<(&Opponent, CardSet)> = self
+            .active_opponents()
+            .map(|o| (o, self.ruled_out_singles(o)))
+            .collect();
+        opponents.sort_by_key(|(_, ruled_out)| std::cmp::Reverse(ruled_out.len()));
+
+        let mut pool = self.unseen;
+        for (opponent, ruled_out) in opponents {
+            let allowed = pool.difference(&ruled_out);
+            let source = if allowed.len() >= opponent.card_count {
+                allowed
+            } else {
+                pool
+            };
+            let mut cards = source.to_vec();
+            let count = opponent.card_count.min(cards.len());
+
+            let mut hand = CardSet::EMPTY;
+            for _ in 0..DEAL_ATTEMPTS {
+                cards.shuffle(rng);
+                hand = cards[..count].iter().copied().collect();
+                if self.could_hold(opponent, hand) {
+                    break;
+                }
+            }
+
+            pool = pool.difference(&hand);
+            if let Some(seat) = game.players().iter().position(|p| p.uuid == opponent.uuid) {
+                hands[seat] = hand;
+            }
+        }
+        hands
+    }
+
+    /// Unseen cards that on their own beat a hand the opponent passed on
+    fn ruled_out_singles(&self, opponent: &Opponent) -> CardSet {
+        self.unseen
+            .iter()
+            .filter(|card| {
+                Hand::from_cards_with_rules(&[*card], &self.rules).is_ok_and(|single| {
+                    opponent
+                        .passed_on
+                        .iter()
+                        .any(|passed| single.can_beat_with_rules(passed, &self.rules))
+                })
+            })
+            .collect()
+    }
+
+    /// Whether no opponent can beat `hand` whatever they hold, so playing it wins the lead
+    ///
+    /// Passes are not trusted here: an opponent who passed may still be holding a winner.
+    pub fn is_unbeatable(&self, hand: &Hand) -> bool {
+        !self
+            .active_opponents()
+            .any(|opponent| self.could_beat(opponent, hand))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::test_utils::{cards, game};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    fn hand(codes: &str) -> Hand {
+        Hand::from_cards(&cards(codes)).unwrap()
+    }
+
+    #[test]
+    fn test_played_and_held_cards_are_not_unseen() {
+        let mut game = game(&[("alice", "3D 9H KS"), ("bot", "4C 2S")]);
+        game.play_cards("alice", &cards("3D")).unwrap();
+
+        let tracker = CardTracker::from_game(&game, "bot");
+        assert_eq!(tracker.unseen().len(), 52 - 3);
+        assert!(!tracker.unseen().contains(Card::from_string("3D").unwrap()));
+        assert!(!tracker.unseen().contains(Card::from_string("2S").unwrap()));
+        assert!(tracker.unseen().contains(Card::from_string("KS").unwrap()));
+        assert_eq!(tracker.fewest_cards(), Some(2));
+    }
+
+    #[test]
+    fn test_passes_rule_out_higher_hands() {
+        let mut game = game(&[
+            ("alice", "3D 5C 8H"),
+            ("bot", "4C 9D AS"),
+            ("carol", "6H 7S"),
+        ]);
+        game.play_cards("alice", &cards("3D")).unwrap();
+        game.play_cards("bot", &cards("9D")).unwrap();
+        game.play_cards("carol", &[]).unwrap();
+
+        let tracker = CardTracker::from_game(&game, "bot");
+        let [alice, carol] = tracker.opponents() else {
+            panic!("expected two opponents");
+        };
+        // Carol passed on the 9, so she probably holds nothing that beats an ace either
+        assert!(!tracker.likely_to_beat(carol, &hand("AS")));
+        assert!(tracker.likely_to_beat(alice, &hand("AS")));
+        // ...but she may have passed on purpose, so she could still hold a 2
+        assert!(tracker.could_beat(carol, &hand("AS")));
+        assert!(!tracker.is_unbeatable(&hand("AS")));
+
+        let held = |codes: &str| -> CardSet { cards(codes).into_iter().collect() };
+        assert!(tracker.could_hold(carol, held("6H 7S")));
+        assert!(!tracker.could_hold(carol, held("6H TS")));
+        assert!(!tracker.could_hold(carol, held("6H")));
+    }
+
+    #[test]
+    fn test_passes_never_make_a_hand_unbeatable() {
+        let mut game = game(&[("alice", "3D 5C 2H"), ("bot", "4C 9D AS")]);
+        game.play_cards("alice", &cards("3D")).unwrap();
+        game.play_cards("bot", &cards("9D")).unwrap();
+        // Alice holds back her 2 and passes on the 9
+        game.play_cards("alice", &[]).unwrap();
+
+        let tracker = CardTracker::from_game(&game, "bot");
+        let [alice] = tracker.opponents() else {
+            panic!("expected one opponent");
+        };
+        assert!(!tracker.likely_to_beat(alice, &hand("AS")));
+        assert!(!tracker.is_unbeatable(&hand("AS")));
+    }
+
+    #[test]
+    fn test_card_counts_limit_what_can_be_played() {
+        let game = game(&[("alice", "3D"), ("bot", "4C 4S 9D")]);
+        let tracker = CardTracker::from_game(&game, "bot");
+
+        // One card left cannot make a pair
+        assert!(tracker.is_unbeatable(&hand("4C 4S")));
+        assert!(!tracker.is_unbeatable(&hand("9D")));
+        assert!(tracker.is_unbeatable(&hand("2S")));
+    }
+
+    #[test]
+    fn test_bombs_count_against_small_hands() {
+        let game = game(&[("alice", "3D 5C 6C 7C 8C"), ("bot", "2S")]);
+        let plain = CardTracker::from_game(&game, "bot");
+        assert!(plain.is_unbeatable(&hand("2S")));
+
+        let game = game.with_rules(RuleSet::standard().with_bombs());
+        let with_bombs = CardTracker::from_game(&game, "bot");
+        assert!(!with_bombs.is_unbeatable(&hand("2S")));
+    }
+
+    #[test]
+    fn test_deals_match_card_counts_and_passes() {
+        let mut game = game(&[
+            ("alice", "3D 5C 8H"),
+            ("bot", "4C 9D AS"),
+            ("carol", "6H 7S"),
+        ]);
+        game.play_cards("alice", &cards("3D")).unwrap();
+        game.play_cards("bot", &cards("9D")).unwrap();
+        game.play_cards("carol", &[]).unwrap();
+        let tracker = CardTracker::from_game(&game, "bot");
+
+        let mut rng = StdRng::seed_from_u64(1);
+        for _ in 0..10 {
+            let hands = tracker.sample_deal(&game, &mut rng);
+            let sizes: Vec<usize> = hands.iter().map(|h| h.len()).collect();
+            assert_eq!(sizes, vec![2, 2, 2]);
+            assert_eq!(hands[1].to_vec(), cards("4C AS"));
+            assert!(hands[0].is_subset(&tracker.unseen()));
+            // Carol passed on the 9, so she is never dealt anything higher
+            assert!(hands[2].iter().all(|card| card.rank < Rank::Ten));
+        }
+    }
+}
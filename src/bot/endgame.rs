@@ -0,0 +1,506 @@
+// Exact endgame search. Whether a player is sure to go out first is an AND-OR question: on their
+// own turn one move must keep the win (OR), on anyone else's turn every move must (AND), with the
+// other players treated as working together against them. Searching it depth-first and stopping
+// at the first refutation is alpha-beta on a win/loss score, and a transposition table keyed on
+// the simulated table catches the many ways of reaching the same hands. The search runs over
+// `SimState`, which leaves out pass lockout and the highest-single rule, so games played with
+// either are never solved: bots keep to their own strategy and analysis refuses them.
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use rand::Rng;
+use serde::Serialize;
+use thiserror::Error;
+use tracing::{debug, error};
+
+use crate::game::{Card, CardSet, Game};
+
+use super::card_tracker::CardTracker;
+use super::simulation::{SimKey, SimMove, SimState};
+use super::types::BotStrategy;
+
+/// Bots switch to the solver once no player holds more than this many cards
+pub const ENDGAME_CARDS: usize = 6;
+/// Positions a bot may search per move before handing back to its own strategy
+pub const DEFAULT_NODE_LIMIT: usize = 100_000;
+/// Longest a bot searches an endgame; whatever it spends comes out of its own strategy's budget
+pub const ENDGAME_TIME_BUDGET: Duration = Duration::from_secs(1);
+/// Longest `POST /analysis/endgame` searches a position before giving up
+pub const ANALYSIS_TIME_BUDGET: Duration = Duration::from_secs(2);
+/// Deals of the unseen cards a bot checks each move against
+const ENDGAME_DEALS: usize = 16;
+
+/// Whether every player still in the game is down to `ENDGAME_CARDS` or fewer
+pub fn is_endgame(game: &Game) -> bool {
+    !game.is_over()
+        && game
+            .players()
+            .iter()
+            .all(|player| player.cards.len() <= ENDGAME_CARDS)
+}
+
+/// Whether the solver plays by the game's rules: it does not model pass lockout or the
+/// highest-single rule, and either can change who wins
+fn rules_are_modelled(game: &Game) -> bool {
+    let house_rules = game.house_rules();
+    !house_rules.pass_lockout && !house_rules.highest_single_against_last_card
+}
+
+/// Why a position could not be analysed
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum EndgameError {
+    #[error("Player {0} is not in the game")]
+    PlayerNotFound(String),
+    #[error("The game is already over")]
+    GameOver,
+    #[error("Pass lockout and the highest-single rule are not supported")]
+    UnsupportedRules,
+    #[error("Deciding the position takes more than {0} positions")]
+    TooLarge(usize),
+    #[error("Deciding the position takes longer than the search is allowed")]
+    TimedOut,
+}
+
+/// Result of solving a position with every hand known
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct EndgameAnalysis {
+    /// Whether the player goes out first however everyone else plays
+    pub forced_win: bool,
+    /// A move that keeps the forced win, when it is the player's turn
+    pub winning_move: Option<Vec<Card>>,
+    /// Positions searched
+    pub nodes: usize,
+}
+
+/// Solve a position exactly for one player, reading every hand
+///
+/// This sees the other players' cards, so it is for analysis and replays rather than for
+/// play; `POST /analysis/endgame` runs it on a position written in the game notation.
+/// The search gives up after `node_limit` positions or once `deadline` passes.
+pub fn analyze_endgame(
+    game: &Game,
+    player_uuid: &str,
+    node_limit: usize,
+    deadline: Instant,
+) -> Result<EndgameAnalysis, EndgameError> {
+    let seat = game
+        .players()
+        .iter()
+        .position(|p| p.uuid == player_uuid)
+        .ok_or_else(|| EndgameError::PlayerNotFound(player_uuid.to_string()))?;
+    if game.is_over() {
+        return Err(EndgameError::GameOver);
+    }
+    if !rules_are_modelled(game) {
+        return Err(EndgameError::UnsupportedRules);
+    }
+
+    let hands = game
+        .players()
+        .iter()
+        .map(|player| player.cards.iter().copied().collect())
+        .collect();
+    let state = SimState::from_game(game, hands);
+    let mut solver = EndgameSolver::new(node_limit, Some(deadline));
+    let gave_up = || {
+        if Instant::now() >= deadline {
+            EndgameError::TimedOut
+        } else {
+            EndgameError::TooLarge(node_limit)
+        }
+    };
+
+    let winning_move = if game.current_player_turn() == player_uuid {
+        solver
+            .winning_move(&state, seat, &root_moves(game, player_uuid))
+            .ok_or_else(gave_up)?
+    } else {
+        None
+    };
+    let forced_win = match winning_move {
+        Some(_) => true,
+        None if game.current_player_turn() == player_uuid => false,
+        None => solver.forced_win(&state, seat).ok_or_else(gave_up)?,
+    };
+
+    Ok(EndgameAnalysis {
+        forced_win,
+        winning_move: winning_move.map(|mv| mv.to_vec()),
+        nodes: solver.nodes,
+    })
+}
+
+/// A move that wins for the bot in every sampled deal of the unseen cards
+///
+/// Checks every legal move against `ENDGAME_DEALS` deals consistent with what the bot has
+/// seen, so a deal that was not drawn may still beat it. Returns None if no move wins in
+/// all of them, if the search grew too large or ran past `deadline`, or if the game is
+/// played with house rules the solver leaves out.
+pub fn sampled_win<R: Rng + ?Sized>(
+    game: &Game,
+    bot_uuid: &str,
+    node_limit: usize,
+    deadline: Instant,
+    rng: &mut R,
+) -> Option<Vec<Card>> {
+    if !rules_are_modelled(game) {
+        return None;
+    }
+    let seat = game.players().iter().position(|p| p.uuid == bot_uuid)?;
+    let tracker = CardTracker::from_game(game, bot_uuid);
+    let mut deals: Vec<Vec<CardSet>> = Vec::new();
+    for _ in 0..ENDGAME_DEALS {
+        let deal = tracker.sample_deal(game, rng);
+        if !deals.contains(&deal) {
+            deals.push(deal);
+        }
+    }
+
+    // One table for every deal: positions repeat across them once the differing cards are gone
+    let mut solver = EndgameSolver::new(node_limit, Some(deadline));
+    'moves: for mv in root_moves(game, bot_uuid) {
+        for hands in &deals {
+            let mut state = SimState::from_game(game, hands.clone());
+            state.apply(mv);
+            if !solver.forced_win(&state, seat)? {
+                continue 'moves;
+            }
+        }
+        return Some(mv.to_vec());
+    }
+    None
+}
+
+/// The player's legal moves in the real game, the pass last
+fn root_moves(game: &Game, player_uuid: &str) -> Vec<SimMove> {
+    let legal = game.legal_moves(player_uuid);
+    let mut moves: Vec<SimMove> = legal
+        .hands()
+        .map(|hand| hand.to_cards().into_iter().collect())
+        .collect();
+    if legal.can_pass {
+        moves.push(CardSet::EMPTY);
+    }
+    moves
+}
+
+/// Depth-first win/loss search with a transposition table
+struct EndgameSolver {
+    node_limit: usize,
+    /// Give up once this passes, as if the node limit had been reached
+    deadline: Option<Instant>,
+    nodes: usize,
+    /// (seat solved for, position) -> whether that seat is sure to win
+    table: HashMap<(usize, SimKey), bool>,
+}
+
+impl EndgameSolver {
+    fn new(node_limit: usize, deadline: Option<Instant>) -> Self {
+        Self {
+            node_limit,
+            deadline,
+            nodes: 0,
+            table: HashMap::new(),
+        }
+    }
+
+    /// A move from `moves` after which `seat` is sure to win; None inside if there is none,
+    /// None outside if the search ran out of nodes
+    fn winning_move(
+        &mut self,
+        state: &SimState,
+        seat: usize,
+        moves: &[SimMove],
+    ) -> Option<Option<SimMove>> {
+        for &mv in moves {
+            let mut child = state.clone();
+            child.apply(mv);
+            if self.forced_win(&child, seat)? {
+                return Some(Some(mv));
+            }
+        }
+        Some(None)
+    }
+
+    /// Whether `seat` goes out first however the others play; None if the search ran out
+    /// of nodes or time
+    fn forced_win(&mut self, state: &SimState, seat: usize) -> Option<bool> {
+        if let Some(winner) = state.winner() {
+            return Some(winner == seat);
+        }
+        let key = (seat, state.key());
+        if let Some(&known) = self.table.get(&key) {
+            return Some(known);
+        }
+
+        self.nodes += 1;
+        if self.nodes > self.node_limit
+            || self
+                .deadline
+                .is_some_and(|deadline| Instant::now() >= deadline)
+        {
+            return None;
+        }
+
+        // On their own turn one winning move is enough; on anyone else's, one refutation ends it
+        let ours = state.turn == seat;
+        let mut state = state.clone();
+        let mut result = !ours;
+        for mv in state.legal_moves() {
+            let mut child = state.clone();
+            child.apply(mv);
+            if self.forced_win(&child, seat)? == ours {
+                result = ours;
+                break;
+            }
+        }
+
+        self.table.insert(key, result);
+        Some(result)
+    }
+}
+
+/// Wraps any strategy so that it plays out won endgames exactly
+///
+/// Once every player is down to `ENDGAME_CARDS` cards, the bot looks for a move that wins
+/// against every deal of the unseen cards it samples, and plays it. Otherwise, or if the
+/// search grows past its node limit or `ENDGAME_TIME_BUDGET`, the wrapped strategy decides
+/// with the time spent searching taken off its own budget.
+pub struct EndgameOverride<S> {
+    inner: S,
+    node_limit: usize,
+}
+
+impl<S: BotStrategy> EndgameOverride<S> {
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner,
+            node_limit: DEFAULT_NODE_LIMIT,
+        }
+    }
+
+    /// Positions the solver may search per move
+    #[allow(dead_code)] // Public API for tuning and tests
+    pub fn with_node_limit(mut self, node_limit: usize) -> Self {
+        self.node_limit = node_limit;
+        self
+    }
+}
+
+#[async_trait]
+impl<S: BotStrategy> BotStrategy for EndgameOverride<S> {
+    async fn decide_move(&self, game: &Game, bot_uuid: &str) -> Option<Vec<Card>> {
+        self.decide_move_after(game, bot_uuid, Duration::ZERO).await
+    }
+
+    async fn decide_move_after(
+        &self,
+        game: &Game,
+        bot_uuid: &str,
+        spent: Duration,
+    ) -> Option<Vec<Card>> {
+        let started = Instant::now();
+        if game.current_player_turn() == bot_uuid && is_endgame(game) && rules_are_modelled(game) {
+            // The search is CPU-bound, so keep it off the async workers
+            let node_limit = self.node_limit;
+            let deadline = started + ENDGAME_TIME_BUDGET;
+            let snapshot = game.clone();
+            let uuid = bot_uuid.to_string();
+            let solved = tokio::task::spawn_blocking(move || {
+                sampled_win(&snapshot, &uuid, node_limit, deadline, &mut rand::rng())
+            })
+            .await
+            .unwrap_or_else(|e| {
+                error!(bot_uuid = %bot_uuid, error = %e, "Endgame search failed");
+                None
+            });
+
+            if let Some(cards) = solved {
+                debug!(
+                    bot_uuid = %bot_uuid,
+                    chosen_move = ?cards,
+                    "Bot playing a solved endgame"
+                );
+                return (!cards.is_empty()).then_some(cards);
+            }
+        }
+
+        self.inner
+            .decide_move_after(game, bot_uuid, spent + started.elapsed())
+            .await
+    }
+
+    fn strategy_name(&self) -> &'static str {
+        self.inner.strategy_name()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bot::basic_strategy::BasicBotStrategy;
+    use crate::game::HouseRules;
+    use crate::shared::test_utils::{cards, game};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    /// The human opened with the 3D; the bot wins only by taking the lead with the 2S, as
+    /// the 4D would let the human go out with their 9
+    fn two_then_out() -> Game {
+        let mut game = game(&[("human", "3D 9C"), ("bot", "4D 2S")]);
+        game.play_cards("human", &cards("3D")).unwrap();
+        game
+    }
+
+    /// A deadline no test search gets near
+    fn later() -> Instant {
+        Instant::now() + Duration::from_secs(60)
+    }
+
+    #[test]
+    fn test_reports_forced_wins() {
+        let game = two_then_out();
+
+        let analysis = analyze_endgame(&game, "bot", DEFAULT_NODE_LIMIT, later()).unwrap();
+        assert!(analysis.forced_win);
+        assert_eq!(analysis.winning_move, Some(cards("2S")));
+
+        let analysis = analyze_endgame(&game, "human", DEFAULT_NODE_LIMIT, later()).unwrap();
+        assert!(!analysis.forced_win);
+        assert_eq!(analysis.winning_move, None);
+    }
+
+    #[test]
+    fn test_reports_lost_positions() {
+        // Whatever the bot plays, the human's 2S wins the lead and their last card goes out
+        let mut game = game(&[("human", "3D 5C 2S"), ("bot", "4D 6D 7H")]);
+        game.play_cards("human", &cards("3D")).unwrap();
+
+        let analysis = analyze_endgame(&game, "bot", DEFAULT_NODE_LIMIT, later()).unwrap();
+        assert!(!analysis.forced_win);
+        assert_eq!(analysis.winning_move, None);
+        assert!(
+            analyze_endgame(&game, "human", DEFAULT_NODE_LIMIT, later())
+                .unwrap()
+                .forced_win
+        );
+    }
+
+    #[test]
+    fn test_seats_already_out_are_not_a_loss() {
+        // Alice goes out first; with a finishing order the others play on for second place
+        let mut game = game(&[("alice", "3D"), ("bob", "4C 2S"), ("carol", "5D 6D")])
+            .with_house_rules(HouseRules {
+                finishing_order: true,
+                ..HouseRules::default()
+            });
+        game.play_cards("alice", &cards("3D")).unwrap();
+        assert!(!game.is_over());
+
+        let analysis = analyze_endgame(&game, "bob", DEFAULT_NODE_LIMIT, later()).unwrap();
+        assert!(analysis.forced_win);
+        assert!(analysis.winning_move.is_some());
+    }
+
+    #[test]
+    fn test_gives_up_past_the_node_limit() {
+        let game = game(&[
+            ("alice", "3D 5C 7H 9S JD KC"),
+            ("bob", "4D 6C 8H TS QD AC"),
+            ("carol", "4C 6H 8S TD QC AH"),
+        ]);
+        assert_eq!(
+            analyze_endgame(&game, "alice", 1, later()),
+            Err(EndgameError::TooLarge(1))
+        );
+        assert_eq!(
+            analyze_endgame(&game, "nobody", DEFAULT_NODE_LIMIT, later()),
+            Err(EndgameError::PlayerNotFound("nobody".to_string()))
+        );
+        assert_eq!(
+            analyze_endgame(&game, "alice", DEFAULT_NODE_LIMIT, Instant::now()),
+            Err(EndgameError::TimedOut)
+        );
+
+        // Out of time counts the same as out of nodes
+        let mut rng = StdRng::seed_from_u64(5);
+        assert_eq!(
+            sampled_win(
+                &two_then_out(),
+                "bot",
+                DEFAULT_NODE_LIMIT,
+                Instant::now(),
+                &mut rng
+            ),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_house_rules_the_solver_leaves_out_are_not_solved() {
+        let game = two_then_out().with_house_rules(HouseRules {
+            pass_lockout: true,
+            ..HouseRules::default()
+        });
+        assert_eq!(
+            analyze_endgame(&game, "bot", DEFAULT_NODE_LIMIT, later()),
+            Err(EndgameError::UnsupportedRules)
+        );
+
+        // The override leaves the move to the basic strategy's lowest card
+        let solved = EndgameOverride::new(BasicBotStrategy::new());
+        assert_eq!(solved.decide_move(&game, "bot").await, Some(cards("4D")));
+    }
+
+    #[test]
+    fn test_endgame_starts_when_every_hand_is_small() {
+        assert!(is_endgame(&two_then_out()));
+
+        let opening = Game::new_game_with_seed(
+            "endgame".to_string(),
+            &[
+                ("A".to_string(), "a".to_string()),
+                ("B".to_string(), "b".to_string()),
+            ],
+            3,
+        )
+        .unwrap();
+        assert!(!is_endgame(&opening));
+    }
+
+    #[tokio::test]
+    async fn test_override_plays_the_sampled_win() {
+        let game = two_then_out();
+
+        // The basic strategy would lead with its lowest card
+        let basic = BasicBotStrategy::new();
+        assert_eq!(basic.decide_move(&game, "bot").await, Some(cards("4D")));
+
+        let solved = EndgameOverride::new(BasicBotStrategy::new());
+        assert_eq!(solved.decide_move(&game, "bot").await, Some(cards("2S")));
+        assert_eq!(solved.strategy_name(), "BasicBotStrategy");
+    }
+
+    #[test]
+    fn test_sampled_win_holds_for_every_sampled_deal() {
+        let mut rng = StdRng::seed_from_u64(5);
+        assert_eq!(
+            sampled_win(
+                &two_then_out(),
+                "bot",
+                DEFAULT_NODE_LIMIT,
+                later(),
+                &mut rng
+            ),
+            Some(cards("2S"))
+        );
+
+        // Against unseen cards the 9 may lose, so no move wins every deal
+        let mut game = game(&[("human", "3D 6C 7C"), ("bot", "4D 9S")]);
+        game.play_cards("human", &cards("3D")).unwrap();
+        assert_eq!(
+            sampled_win(&game, "bot", DEFAULT_NODE_LIMIT, later(), &mut rng),
+            None
+        );
+    }
+}
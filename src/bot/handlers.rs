@@ -3,20 +3,51 @@ use axum::{
     Extension, Json,
 };
 use serde::{Deserialize, Serialize};
+use std::time::Instant;
 use tracing::{info, warn};
 
 use crate::{
     event::RoomEvent,
+    game::read_game,
     session::SessionClaims,
     shared::{AppError, AppState},
 };
 
-use super::{manager::MAX_BOTS_PER_ROOM, types::BotDifficulty};
+use super::{
+    endgame::{analyze_endgame, EndgameAnalysis, ANALYSIS_TIME_BUDGET, DEFAULT_NODE_LIMIT},
+    ismcts_strategy::MAX_TIME_BUDGET,
+    manager::MAX_BOTS_PER_ROOM,
+    profile::{BotPersonality, BotProfile},
+    types::BotDifficulty,
+};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct AddBotRequest {
     #[serde(default = "default_difficulty")]
     pub difficulty: BotDifficulty,
+    /// Thinking time per move for an expert bot, in milliseconds
+    #[serde(default)]
+    pub time_budget_ms: Option<u64>,
+    /// A named personality or a custom set of knobs; balanced when left out
+    #[serde(default)]
+    pub profile: Option<BotProfileRequest>,
+}
+
+/// Either `"cautious"` or `{"aggression": 0.5, ...}`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum BotProfileRequest {
+    Preset(BotPersonality),
+    Custom(BotProfile),
+}
+
+impl BotProfileRequest {
+    fn into_profile(self) -> BotProfile {
+        match self {
+            Self::Preset(personality) => personality.profile(),
+            Self::Custom(profile) => profile,
+        }
+    }
 }
 
 fn default_difficulty() -> BotDifficulty {
@@ -28,6 +59,39 @@ pub struct BotResponse {
     pub uuid: String,
     pub name: String,
     pub difficulty: BotDifficulty,
+    pub time_budget_ms: Option<u64>,
+    pub profile: BotProfile,
+}
+
+/// Only expert bots search for a set time, and it has to finish well before the move times out
+fn validate_time_budget(
+    difficulty: BotDifficulty,
+    time_budget_ms: Option<u64>,
+) -> Result<(), AppError> {
+    let Some(time_budget_ms) = time_budget_ms else {
+        return Ok(());
+    };
+    if difficulty != BotDifficulty::Expert {
+        return Err(AppError::BadRequest(
+            "A time budget can only be set for expert bots".to_string(),
+        ));
+    }
+    let max_ms = MAX_TIME_BUDGET.as_millis() as u64;
+    if time_budget_ms == 0 || time_budget_ms > max_ms {
+        return Err(AppError::BadRequest(format!(
+            "Time budget must be between 1 and {} ms",
+            max_ms
+        )));
+    }
+    Ok(())
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EndgameAnalysisRequest {
+    /// The position, as a game in the text notation that stops at the move to analyse
+    pub notation: String,
+    /// UUID of the player to analyse the position for
+    pub player: String,
 }
 
 /// Add a bot to a room
@@ -46,6 +110,13 @@ pub async fn add_bot_to_room(
         "Request to add bot to room"
     );
 
+    validate_time_budget(request.difficulty, request.time_budget_ms)?;
+    let profile = request
+        .profile
+        .map(BotProfileRequest::into_profile)
+        .unwrap_or_default();
+    profile.validate().map_err(AppError::BadRequest)?;
+
     // Get the room
     let room = state
         .room_service
@@ -95,7 +166,12 @@ pub async fn add_bot_to_room(
     // Create the bot
     let bot = state
         .bot_manager
-        .create_bot(room_id.clone(), request.difficulty)
+        .create_bot(
+            room_id.clone(),
+            request.difficulty,
+            request.time_budget_ms,
+            profile,
+        )
         .await?;
 
     // Register the bot in player mapping
@@ -166,6 +242,8 @@ pub async fn add_bot_to_room(
         uuid: bot.uuid,
         name: bot.name,
         difficulty: bot.difficulty,
+        time_budget_ms: bot.time_budget_ms,
+        profile: bot.profile,
     }))
 }
 
@@ -288,15 +366,64 @@ pub async fn remove_bot_from_room(
     })))
 }
 
+/// Report whether a position is a forced win, reading every hand
+/// POST /analysis/endgame
+pub async fn analyze_endgame_position(
+    Json(request): Json<EndgameAnalysisRequest>,
+) -> Result<Json<EndgameAnalysis>, AppError> {
+    let game = read_game(&request.notation).map_err(|e| AppError::BadRequest(e.to_string()))?;
+
+    info!(
+        game_id = %game.id(),
+        player = %request.player,
+        "Request to analyse endgame"
+    );
+
+    // The search is CPU-bound, so keep it off the async workers, and stop it in good time
+    let deadline = Instant::now() + ANALYSIS_TIME_BUDGET;
+    let analysis = tokio::task::spawn_blocking(move || {
+        analyze_endgame(&game, &request.player, DEFAULT_NODE_LIMIT, deadline)
+    })
+    .await
+    .map_err(|e| {
+        warn!(error = %e, "Endgame analysis failed");
+        AppError::Internal
+    })?
+    .map_err(|e| AppError::BadRequest(e.to_string()))?;
+
+    Ok(Json(analysis))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::game::write_game;
+    use crate::shared::test_utils::{cards, game};
 
     #[tokio::test]
     async fn test_add_bot_request_deserialization() {
         let json = r#"{"difficulty": "easy"}"#;
         let request: AddBotRequest = serde_json::from_str(json).unwrap();
         assert_eq!(request.difficulty, BotDifficulty::Easy);
+
+        let json = r#"{"difficulty": "expert"}"#;
+        let request: AddBotRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(request.difficulty, BotDifficulty::Expert);
+        assert_eq!(request.time_budget_ms, None);
+
+        let json = r#"{"difficulty": "expert", "time_budget_ms": 500}"#;
+        let request: AddBotRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(request.time_budget_ms, Some(500));
+    }
+
+    #[test]
+    fn test_validate_time_budget() {
+        assert!(validate_time_budget(BotDifficulty::Easy, None).is_ok());
+        assert!(validate_time_budget(BotDifficulty::Expert, Some(500)).is_ok());
+        assert!(validate_time_budget(BotDifficulty::Hard, Some(500)).is_err());
+        assert!(validate_time_budget(BotDifficulty::Expert, Some(0)).is_err());
+        let too_long = MAX_TIME_BUDGET.as_millis() as u64 + 1;
+        assert!(validate_time_budget(BotDifficulty::Expert, Some(too_long)).is_err());
     }
 
     #[tokio::test]
@@ -312,12 +439,63 @@ mod tests {
             uuid: "bot-123".to_string(),
             name: "happy-turtle Bot".to_string(),
             difficulty: BotDifficulty::Medium,
+            time_budget_ms: None,
+            profile: BotPersonality::Cautious.profile(),
         };
 
         let json = serde_json::to_string(&response).unwrap();
         assert!(json.contains("bot-123"));
         assert!(json.contains("happy-turtle Bot"));
         assert!(json.contains("medium"));
+        assert!(json.contains("hoard_twos"));
+    }
+
+    #[tokio::test]
+    async fn test_add_bot_request_profiles() {
+        let json = r#"{"difficulty": "hard", "profile": "reckless"}"#;
+        let request: AddBotRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            request.profile.unwrap().into_profile(),
+            BotPersonality::Reckless.profile()
+        );
+
+        let json = r#"{"profile": {"aggression": 0.5, "think_time": {"min_ms": 0, "max_ms": 50}}}"#;
+        let request: AddBotRequest = serde_json::from_str(json).unwrap();
+        let profile = request.profile.unwrap().into_profile();
+        assert_eq!(profile.aggression, 0.5);
+        assert_eq!(profile.think_time.max_ms, 50);
+        assert_eq!(profile.hoard_twos, BotProfile::default().hoard_twos);
+
+        // Misspelled knobs are rejected rather than ignored
+        let json = r#"{"profile": {"agression": 0.5}}"#;
+        assert!(serde_json::from_str::<AddBotRequest>(json).is_err());
+        assert!(serde_json::from_str::<AddBotRequest>(r#"{"profile": "grumpy"}"#).is_err());
+
+        let request: AddBotRequest = serde_json::from_str(r#"{}"#).unwrap();
+        assert!(request.profile.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_analyze_endgame_position() {
+        let mut game = game(&[("human", "3D 9C"), ("bot", "4D 2S")]);
+        game.play_cards("human", &cards("3D")).unwrap();
+        let request = EndgameAnalysisRequest {
+            notation: write_game(&game),
+            player: "bot".to_string(),
+        };
+
+        let Json(analysis) = analyze_endgame_position(Json(request)).await.unwrap();
+        assert!(analysis.forced_win);
+        assert_eq!(analysis.winning_move, Some(cards("2S")));
+
+        let request = EndgameAnalysisRequest {
+            notation: "not a game".to_string(),
+            player: "bot".to_string(),
+        };
+        assert!(matches!(
+            analyze_endgame_position(Json(request)).await,
+            Err(AppError::BadRequest(_))
+        ));
     }
 
     // Additional integration-style tests would go here
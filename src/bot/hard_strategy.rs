@@ -0,0 +1,249 @@
+use async_trait::async_trait;
+use tracing::debug;
+
+use crate::game::{Card, Game};
+
+use super::card_tracker::{CardTracker, Opponent};
+use super::planning::{Candidate, Plan};
+use super::types::BotStrategy;
+
+/// Opponents with this many cards or fewer are close enough to going out to block
+const THREAT_CARDS: usize = 3;
+
+/// What the bot knows about the table when choosing a play
+struct Reading<'a> {
+    tracker: &'a CardTracker,
+    /// Opponents close to going out
+    threats: Vec<&'a Opponent>,
+    /// The plan has at most one play that can be beaten, so the bot can win every lead
+    /// until it goes out
+    can_run_out: bool,
+}
+
+impl Reading<'_> {
+    fn is_unbeatable(&self, candidate: &Candidate) -> bool {
+        self.tracker.is_unbeatable(&candidate.hand)
+    }
+
+    /// No opponent close to going out is likely to beat it
+    fn stops_threats(&self, candidate: &Candidate) -> bool {
+        !self
+            .threats
+            .iter()
+            .any(|opponent| self.tracker.likely_to_beat(opponent, &candidate.hand))
+    }
+}
+
+/// Bot strategy that counts cards and models its opponents on top of planning its hand
+///
+/// It tracks every card played and watches how many cards each opponent has left. With that
+/// it works out which of its plays no one can beat, and runs out its hand once those
+/// guaranteed winners cover all but one play. Passes hint at what an opponent lacks, which it
+/// uses to pick the play most likely to stop an opponent who is about to go out.
+pub struct HardBotStrategy;
+
+impl Default for HardBotStrategy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HardBotStrategy {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn choose_move(game: &Game, bot_uuid: &str) -> Option<Vec<Card>> {
+        let legal = game.legal_moves(bot_uuid);
+        let plan = Plan::new(game, bot_uuid, &legal)?;
+        if let Some(out) = plan.going_out() {
+            return Some(out.cards.clone());
+        }
+
+        let tracker = CardTracker::from_game(game, bot_uuid);
+        let losers = plan.best().map_or(0, |best| {
+            best.hands
+                .iter()
+                .filter(|hand| !tracker.is_unbeatable(hand))
+                .count()
+        });
+        let reading = Reading {
+            tracker: &tracker,
+            threats: tracker
+                .active_opponents()
+                .filter(|o| o.card_count <= THREAT_CARDS)
+                .collect(),
+            can_run_out: losers <= 1,
+        };
+
+        let chosen = if legal.can_pass {
+            Self::follow(game, &plan.candidates, &reading)
+        } else {
+            Self::lead(&plan.candidates, &reading)
+        };
+        chosen.map(|c| c.cards.clone())
+    }
+
+    fn lead<'a>(candidates: &'a [Candidate], reading: &Reading) -> Option<&'a Candidate> {
+        // Keep the lead with a guaranteed winner, saving the one play that can lose for last
+        if reading.can_run_out {
+            if let Some(winner) = candidates
+                .iter()
+                .filter(|c| c.fits_plan && reading.is_unbeatable(c))
+                .min_by_key(|c| c.lowest)
+            {
+                return Some(winner);
+            }
+        }
+
+        if !reading.threats.is_empty() {
+            // Lead something they cannot follow, else the play they are least likely to beat
+            return candidates
+                .iter()
+                .filter(|c| reading.stops_threats(c))
+                .min_by_key(|c| (!c.fits_plan, c.is_power, c.lowest))
+                .or_else(|| {
+                    candidates
+                        .iter()
+                        .max_by_key(|c| (c.fits_plan, !c.is_power, c.strength))
+                });
+        }
+
+        // The weakest combo in the plan; power cards and sure winners are kept to regain the lead
+        candidates.iter().min_by_key(|c| {
+            (
+                !c.fits_plan,
+                c.is_power || reading.is_unbeatable(c),
+                c.lowest,
+                std::cmp::Reverse(c.cards.len()),
+            )
+        })
+    }
+
+    fn follow<'a>(
+        game: &Game,
+        candidates: &'a [Candidate],
+        reading: &Reading,
+    ) -> Option<&'a Candidate> {
+        if reading.can_run_out {
+            if let Some(winner) = candidates
+                .iter()
+                .filter(|c| c.fits_plan && reading.is_unbeatable(c))
+                .min_by_key(|c| c.strength)
+            {
+                return Some(winner);
+            }
+        }
+
+        if !reading.threats.is_empty() {
+            if let Some(block) = candidates
+                .iter()
+                .filter(|c| reading.stops_threats(c))
+                .min_by_key(|c| (!c.fits_plan, c.is_power, c.strength))
+            {
+                return Some(block);
+            }
+
+            // Nothing is safe; still take the lead away from a player about to go out
+            let threat_on_table = game
+                .last_non_pass_player_uuid()
+                .is_some_and(|uuid| reading.threats.iter().any(|o| o.uuid == uuid));
+            if threat_on_table {
+                return candidates.iter().max_by_key(|c| (c.fits_plan, c.strength));
+            }
+        }
+
+        // The cheapest combo that fits the plan, keeping power cards back
+        candidates
+            .iter()
+            .filter(|c| c.fits_plan && !c.is_power)
+            .min_by_key(|c| c.strength)
+    }
+}
+
+#[async_trait]
+impl BotStrategy for HardBotStrategy {
+    async fn decide_move(&self, game: &Game, bot_uuid: &str) -> Option<Vec<Card>> {
+        if game.current_player_turn() != bot_uuid {
+            debug!(bot_uuid = %bot_uuid, "Not bot's turn");
+            return None;
+        }
+
+        let chosen_move = Self::choose_move(game, bot_uuid);
+
+        debug!(
+            bot_uuid = %bot_uuid,
+            chosen_move = ?chosen_move,
+            "Hard bot decided on move"
+        );
+
+        chosen_move
+    }
+
+    fn strategy_name(&self) -> &'static str {
+        "HardBotStrategy"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::test_utils::{cards, game};
+
+    /// Two-player game where the bot has just won a round with a pair of 5s and must lead
+    fn bot_to_lead(bot: &str, human_left: &str) -> Game {
+        let mut game = game(&[
+            ("human", &format!("3D 3C {human_left}")),
+            ("bot", &format!("5D 5S {bot}")),
+        ]);
+        game.play_cards("human", &cards("3D 3C")).unwrap();
+        game.play_cards("bot", &cards("5D 5S")).unwrap();
+        game.play_cards("human", &[]).unwrap();
+        assert!(game.is_table_clear());
+        game
+    }
+
+    async fn decide(game: &Game) -> Option<Vec<Card>> {
+        HardBotStrategy::new().decide_move(game, "bot").await
+    }
+
+    #[tokio::test]
+    async fn test_runs_out_with_a_guaranteed_winner() {
+        // Nothing beats the 2S, so win the lead with it and go out with the 4D
+        let mut game = game(&[("human", "3D 6C 7C 8H 9C JD"), ("bot", "4D 2S")]);
+        game.play_cards("human", &cards("3D")).unwrap();
+
+        assert_eq!(decide(&game).await, Some(cards("2S")));
+    }
+
+    #[tokio::test]
+    async fn test_blocks_a_player_about_to_go_out() {
+        // Carol has one card: only the 2S is sure to keep her from going out
+        let mut game = game(&[
+            ("alice", "3D 5H 6C 7C 8D 9D"),
+            ("bot", "6D 9C KS 2S"),
+            ("carol", "4C"),
+        ]);
+        game.play_cards("alice", &cards("3D")).unwrap();
+
+        assert_eq!(decide(&game).await, Some(cards("2S")));
+    }
+
+    #[tokio::test]
+    async fn test_leads_what_a_short_hand_cannot_follow() {
+        // A player with one card cannot answer a pair
+        let game = bot_to_lead("4H 8C 8S KH", "9C");
+        assert_eq!(decide(&game).await, Some(cards("8C 8S")));
+
+        // With only singles, lead the one they are least likely to beat
+        let game = bot_to_lead("4H 8S KH", "9C");
+        assert_eq!(decide(&game).await, Some(cards("KH")));
+    }
+
+    #[tokio::test]
+    async fn test_keeps_sure_winners_for_regaining_the_lead() {
+        // Nobody can beat a pair of 2s holding the 2S; lead the weak single and keep the pair
+        let game = bot_to_lead("4H 9C JD 2H 2S", "6C 7H 8D TS KD");
+        assert_eq!(decide(&game).await, Some(cards("4H")));
+    }
+}
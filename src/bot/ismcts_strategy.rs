@@ -0,0 +1,381 @@
+// Information-set Monte Carlo tree search. Each iteration deals the unseen cards to the opponents
+// at random (matching their card counts and, where possible, the hands they passed on), walks one
+// shared tree using only the moves open in that deal, and finishes the game with the simulation's
+// default policy. Every node scores wins for the seat that made its move, and UCB weighs a node by
+// how often it was available rather than by its parent's visits.
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use tracing::{debug, error};
+
+use crate::game::{Card, CardSet, Game};
+
+use super::card_tracker::CardTracker;
+use super::simulation::{SimMove, SimState};
+use super::types::BotStrategy;
+
+/// Thinking time per move, well inside `STRATEGY_TIMEOUT`
+pub const DEFAULT_TIME_BUDGET: Duration = Duration::from_secs(2);
+/// Longest thinking time a bot can be given, leaving headroom under `STRATEGY_TIMEOUT`
+pub const MAX_TIME_BUDGET: Duration = Duration::from_secs(4);
+/// UCB exploration constant
+const EXPLORATION: f64 = 0.7;
+
+/// One move in the search tree
+struct Node {
+    mv: SimMove,
+    /// Seat that made the move
+    seat: usize,
+    children: Vec<usize>,
+    visits: u32,
+    wins: u32,
+    /// Iterations in which the move was open to the player
+    available: u32,
+}
+
+impl Node {
+    fn new(mv: SimMove, seat: usize) -> Self {
+        Self {
+            mv,
+            seat,
+            children: Vec::new(),
+            visits: 0,
+            wins: 0,
+            available: 0,
+        }
+    }
+
+    fn win_rate(&self) -> f64 {
+        f64::from(self.wins) / f64::from(self.visits.max(1))
+    }
+
+    fn ucb(&self) -> f64 {
+        let visits = f64::from(self.visits.max(1));
+        self.win_rate() + EXPLORATION * (f64::from(self.available.max(1)).ln() / visits).sqrt()
+    }
+}
+
+/// Bot strategy that searches ahead with information-set Monte Carlo tree search
+///
+/// It samples opponent hands consistent with everything seen so far, plays each game out,
+/// and picks the move that won most often, thinking for up to its time budget.
+#[derive(Debug, Clone, Copy)]
+pub struct IsmctsBotStrategy {
+    time_budget: Duration,
+    max_iterations: Option<usize>,
+    seed: Option<u64>,
+}
+
+impl Default for IsmctsBotStrategy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IsmctsBotStrategy {
+    pub fn new() -> Self {
+        Self {
+            time_budget: DEFAULT_TIME_BUDGET,
+            max_iterations: None,
+            seed: None,
+        }
+    }
+
+    /// Stop searching after `budget`; keep it below `STRATEGY_TIMEOUT`
+    pub fn with_time_budget(mut self, budget: Duration) -> Self {
+        self.time_budget = budget;
+        self
+    }
+
+    /// Stop searching after this many playouts, even with time to spare
+    #[allow(dead_code)] // Public API for tuning and tests
+    pub fn with_max_iterations(mut self, iterations: usize) -> Self {
+        self.max_iterations = Some(iterations);
+        self
+    }
+
+    /// Sample deals from a fixed seed, for reproducible decisions
+    #[allow(dead_code)] // Public API for tuning and tests
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    fn search(&self, game: &Game, bot_uuid: &str, budget: Duration) -> Option<Vec<Card>> {
+        let bot_seat = game.players().iter().position(|p| p.uuid == bot_uuid)?;
+        let legal = game.legal_moves(bot_uuid);
+        let mut root_moves: Vec<SimMove> = legal
+            .hands()
+            .map(|hand| hand.to_cards().into_iter().collect())
+            .collect();
+        if legal.can_pass {
+            root_moves.push(CardSet::EMPTY);
+        }
+        if root_moves.len() <= 1 {
+            return root_moves.first().map(|mv| mv.to_vec());
+        }
+
+        let tracker = CardTracker::from_game(game, bot_uuid);
+        let mut rng = self
+            .seed
+            .map_or_else(StdRng::from_os_rng, StdRng::seed_from_u64);
+        let deadline = Instant::now() + budget;
+
+        let mut tree = vec![Node::new(CardSet::EMPTY, bot_seat)];
+        let mut iterations = 0;
+        // Every move is played out once even when the budget is already spent
+        while (iterations < root_moves.len() || Instant::now() < deadline)
+            && self.max_iterations.is_none_or(|max| iterations < max)
+        {
+            let hands = tracker.sample_deal(game, &mut rng);
+            let mut state = SimState::from_game(game, hands);
+            Self::iterate(&mut tree, &mut state, &root_moves, &mut rng);
+            iterations += 1;
+        }
+
+        // The best win rate among moves searched at least a quarter as much as the most
+        // searched one, so a lucky move tried a handful of times cannot win
+        let children = &tree[0].children;
+        let most_visits = children.iter().map(|&c| tree[c].visits).max()?;
+        let chosen = children
+            .iter()
+            .map(|&c| &tree[c])
+            .filter(|node| node.visits > 0 && node.visits * 4 >= most_visits)
+            .max_by(|a, b| a.win_rate().total_cmp(&b.win_rate()))?;
+
+        debug!(
+            bot_uuid = %bot_uuid,
+            iterations,
+            visits = chosen.visits,
+            win_rate = chosen.win_rate(),
+            "ISMCTS search finished"
+        );
+        Some(chosen.mv.to_vec())
+    }
+
+    /// One pass of select, expand, play out and update over a sampled deal
+    fn iterate(
+        tree: &mut Vec<Node>,
+        state: &mut SimState,
+        root_moves: &[SimMove],
+        rng: &mut StdRng,
+    ) {
+        let mut path = vec![0];
+        let mut node = 0;
+
+        while state.winner().is_none() {
+            let moves = if node == 0 {
+                root_moves.to_vec()
+            } else {
+                state.legal_moves()
+            };
+
+            let mut available = Vec::new();
+            let mut untried = Vec::new();
+            for mv in moves {
+                match tree[node].children.iter().find(|&&c| tree[c].mv == mv) {
+                    Some(&child) => available.push(child),
+                    None => untried.push(mv),
+                }
+            }
+            for &child in &available {
+                tree[child].available += 1;
+            }
+
+            if !untried.is_empty() {
+                let mv = untried[rng.random_range(0..untried.len())];
+                let mut child = Node::new(mv, state.turn);
+                child.available = 1;
+                tree.push(child);
+                let child = tree.len() - 1;
+                tree[node].children.push(child);
+                state.apply(mv);
+                path.push(child);
+                break;
+            }
+
+            let Some(&child) = available
+                .iter()
+                .max_by(|&&a, &&b| tree[a].ucb().total_cmp(&tree[b].ucb()))
+            else {
+                break;
+            };
+            state.apply(tree[child].mv);
+            path.push(child);
+            node = child;
+        }
+
+        let winner = state.play_out();
+        for node in path {
+            tree[node].visits += 1;
+            if winner == Some(tree[node].seat) {
+                tree[node].wins += 1;
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl BotStrategy for IsmctsBotStrategy {
+    async fn decide_move(&self, game: &Game, bot_uuid: &str) -> Option<Vec<Card>> {
+        self.decide_move_after(game, bot_uuid, Duration::ZERO).await
+    }
+
+    async fn decide_move_after(
+        &self,
+        game: &Game,
+        bot_uuid: &str,
+        spent: Duration,
+    ) -> Option<Vec<Card>> {
+        if game.current_player_turn() != bot_uuid {
+            debug!(bot_uuid = %bot_uuid, "Not bot's turn");
+            return None;
+        }
+
+        // The search is CPU-bound, so keep it off the async workers
+        let strategy = *self;
+        let game = game.clone();
+        let uuid = bot_uuid.to_string();
+        let budget = self.time_budget.saturating_sub(spent);
+        let chosen_move =
+            tokio::task::spawn_blocking(move || strategy.search(&game, &uuid, budget))
+                .await
+                .unwrap_or_else(|e| {
+                    error!(bot_uuid = %bot_uuid, error = %e, "ISMCTS search failed");
+                    None
+                })
+                .filter(|cards| !cards.is_empty());
+
+        debug!(
+            bot_uuid = %bot_uuid,
+            chosen_move = ?chosen_move,
+            "Expert bot decided on move"
+        );
+
+        chosen_move
+    }
+
+    fn strategy_name(&self) -> &'static str {
+        "IsmctsBotStrategy"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bot::bot_room_subscriber::STRATEGY_TIMEOUT;
+    use crate::game::HouseRules;
+    use crate::shared::test_utils::{cards, game};
+
+    fn strategy() -> IsmctsBotStrategy {
+        IsmctsBotStrategy::new()
+            .with_max_iterations(400)
+            .with_seed(7)
+    }
+
+    #[test]
+    fn test_default_budget_fits_the_timeout() {
+        assert!(DEFAULT_TIME_BUDGET < STRATEGY_TIMEOUT);
+        assert!(MAX_TIME_BUDGET < STRATEGY_TIMEOUT);
+    }
+
+    #[tokio::test]
+    async fn test_finds_the_winning_line() {
+        // The 2S keeps the lead for the 4D; the 4D first lets the human go out with one card
+        let mut game = game(&[("human", "3D 9C"), ("bot", "4D 2S")]);
+        game.play_cards("human", &cards("3D")).unwrap();
+
+        let mut losing_line = game.clone();
+        losing_line.play_cards("bot", &cards("4D")).unwrap();
+        losing_line.play_cards("human", &cards("9C")).unwrap();
+        assert_eq!(losing_line.winner().as_deref(), Some("human"));
+
+        let chosen = strategy().decide_move(&game, "bot").await;
+        assert_eq!(chosen, Some(cards("2S")));
+    }
+
+    fn opening_game() -> Game {
+        Game::new_game_with_seed(
+            "ismcts".to_string(),
+            &[
+                ("A".to_string(), "a".to_string()),
+                ("B".to_string(), "b".to_string()),
+                ("C".to_string(), "c".to_string()),
+                ("D".to_string(), "d".to_string()),
+            ],
+            42,
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_opens_with_a_legal_play() {
+        let game = opening_game();
+        let bot = game.current_player_turn();
+
+        let chosen = strategy()
+            .decide_move(&game, &bot)
+            .await
+            .expect("the opening player must play");
+        assert!(game.validate_move(&bot, &chosen).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_stays_within_its_time_budget() {
+        let game = opening_game();
+        let bot = game.current_player_turn();
+
+        let started = Instant::now();
+        IsmctsBotStrategy::new()
+            .with_time_budget(Duration::from_millis(200))
+            .with_seed(7)
+            .decide_move(&game, &bot)
+            .await;
+        assert!(started.elapsed() < Duration::from_secs(2));
+    }
+
+    #[tokio::test]
+    async fn test_time_already_spent_comes_off_the_budget() {
+        let game = opening_game();
+        let bot = game.current_player_turn();
+
+        let started = Instant::now();
+        let chosen = IsmctsBotStrategy::new()
+            .with_time_budget(Duration::from_secs(1))
+            .with_seed(7)
+            .decide_move_after(&game, &bot, Duration::from_secs(1))
+            .await
+            .expect("the opening player must play");
+        assert!(started.elapsed() < Duration::from_millis(500));
+        assert!(game.validate_move(&bot, &chosen).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_leads_after_another_player_went_out() {
+        let game = game(&[("alice", "3D 9C KD"), ("bob", "4C"), ("bot", "5D 6S 7C")]);
+        let mut game = game.with_house_rules(HouseRules {
+            finishing_order: true,
+            ..HouseRules::default()
+        });
+        game.play_cards("alice", &cards("3D")).unwrap();
+        game.play_cards("bob", &cards("4C")).unwrap();
+        game.play_cards("bot", &[]).unwrap();
+        game.play_cards("alice", &[]).unwrap();
+        assert!(game.is_table_clear());
+        assert_eq!(game.current_player_turn(), "bot");
+
+        // Bob is out with an empty hand, but the bot still has second place to play for
+        let chosen = strategy()
+            .decide_move(&game, "bot")
+            .await
+            .expect("the bot must lead");
+        assert!(game.validate_move("bot", &chosen).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_only_acts_on_its_turn() {
+        let game = game(&[("human", "3D 6C"), ("bot", "4D 2S")]);
+        assert_eq!(strategy().decide_move(&game, "bot").await, None);
+    }
+}
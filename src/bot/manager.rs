@@ -5,7 +5,10 @@ use tracing::{debug, info};
 
 use crate::shared::AppError;
 
-use super::types::{BotDifficulty, BotPlayer};
+use super::{
+    profile::BotProfile,
+    types::{BotDifficulty, BotPlayer},
+};
 
 pub const MAX_BOTS_PER_ROOM: usize = 3;
 
@@ -23,11 +26,13 @@ impl BotManager {
         }
     }
 
-    /// Create a new bot for a room
+    /// Create a new bot for a room with the given personality
     pub async fn create_bot(
         &self,
         room_id: String,
         difficulty: BotDifficulty,
+        time_budget_ms: Option<u64>,
+        profile: BotProfile,
     ) -> Result<BotPlayer, AppError> {
         // Check bot count limit first
         let bot_count = self.get_bots_in_room(&room_id).await.len();
@@ -43,7 +48,10 @@ impl BotManager {
         let petname = petname::Petnames::default().generate_one(2, "-");
         let bot_name = format!("{} Bot", petname);
 
-        let bot = BotPlayer::new(room_id, bot_name, difficulty);
+        let bot = BotPlayer {
+            time_budget_ms,
+            ..BotPlayer::new(room_id, bot_name, difficulty).with_profile(profile)
+        };
 
         info!(
             bot_uuid = %bot.uuid,
@@ -59,6 +67,20 @@ impl BotManager {
         Ok(bot)
     }
 
+    /// Bring back a bot that was seated in a stored game, e.g. after a restart
+    pub async fn restore_bot(&self, bot: BotPlayer) {
+        info!(
+            bot_uuid = %bot.uuid,
+            bot_name = %bot.name,
+            room_id = %bot.room_id,
+            difficulty = ?bot.difficulty,
+            "Restoring bot"
+        );
+
+        let mut bots = self.bots.write().await;
+        bots.insert(bot.uuid.clone(), bot);
+    }
+
     /// Get a bot by UUID
     pub async fn get_bot(&self, bot_uuid: &str) -> Option<BotPlayer> {
         let bots = self.bots.read().await;
@@ -141,7 +163,12 @@ mod tests {
     async fn test_create_bot() {
         let manager = BotManager::new();
         let bot = manager
-            .create_bot("room1".to_string(), BotDifficulty::Easy)
+            .create_bot(
+                "room1".to_string(),
+                BotDifficulty::Easy,
+                None,
+                BotProfile::default(),
+            )
             .await
             .unwrap();
 
@@ -156,7 +183,12 @@ mod tests {
     async fn test_get_bot() {
         let manager = BotManager::new();
         let bot = manager
-            .create_bot("room1".to_string(), BotDifficulty::Easy)
+            .create_bot(
+                "room1".to_string(),
+                BotDifficulty::Easy,
+                None,
+                BotProfile::default(),
+            )
             .await
             .unwrap();
 
@@ -169,7 +201,12 @@ mod tests {
     async fn test_remove_bot() {
         let manager = BotManager::new();
         let bot = manager
-            .create_bot("room1".to_string(), BotDifficulty::Easy)
+            .create_bot(
+                "room1".to_string(),
+                BotDifficulty::Easy,
+                None,
+                BotProfile::default(),
+            )
             .await
             .unwrap();
 
@@ -184,15 +221,30 @@ mod tests {
     async fn test_get_bots_in_room() {
         let manager = BotManager::new();
         let bot1 = manager
-            .create_bot("room1".to_string(), BotDifficulty::Easy)
+            .create_bot(
+                "room1".to_string(),
+                BotDifficulty::Easy,
+                None,
+                BotProfile::default(),
+            )
             .await
             .unwrap();
         let bot2 = manager
-            .create_bot("room1".to_string(), BotDifficulty::Medium)
+            .create_bot(
+                "room1".to_string(),
+                BotDifficulty::Medium,
+                None,
+                BotProfile::default(),
+            )
             .await
             .unwrap();
         let _bot3 = manager
-            .create_bot("room2".to_string(), BotDifficulty::Hard)
+            .create_bot(
+                "room2".to_string(),
+                BotDifficulty::Hard,
+                None,
+                BotProfile::default(),
+            )
             .await
             .unwrap();
 
@@ -206,15 +258,30 @@ mod tests {
     async fn test_remove_all_bots_in_room() {
         let manager = BotManager::new();
         manager
-            .create_bot("room1".to_string(), BotDifficulty::Easy)
+            .create_bot(
+                "room1".to_string(),
+                BotDifficulty::Easy,
+                None,
+                BotProfile::default(),
+            )
             .await
             .unwrap();
         manager
-            .create_bot("room1".to_string(), BotDifficulty::Medium)
+            .create_bot(
+                "room1".to_string(),
+                BotDifficulty::Medium,
+                None,
+                BotProfile::default(),
+            )
             .await
             .unwrap();
         manager
-            .create_bot("room2".to_string(), BotDifficulty::Hard)
+            .create_bot(
+                "room2".to_string(),
+                BotDifficulty::Hard,
+                None,
+                BotProfile::default(),
+            )
             .await
             .unwrap();
 
@@ -239,11 +306,21 @@ mod tests {
     async fn test_bot_naming() {
         let manager = BotManager::new();
         let bot1 = manager
-            .create_bot("room1".to_string(), BotDifficulty::Easy)
+            .create_bot(
+                "room1".to_string(),
+                BotDifficulty::Easy,
+                None,
+                BotProfile::default(),
+            )
             .await
             .unwrap();
         let bot2 = manager
-            .create_bot("room1".to_string(), BotDifficulty::Easy)
+            .create_bot(
+                "room1".to_string(),
+                BotDifficulty::Easy,
+                None,
+                BotProfile::default(),
+            )
             .await
             .unwrap();
 
@@ -262,13 +339,23 @@ mod tests {
 
         for _ in 0..MAX_BOTS_PER_ROOM {
             manager
-                .create_bot("room1".to_string(), BotDifficulty::Easy)
+                .create_bot(
+                    "room1".to_string(),
+                    BotDifficulty::Easy,
+                    None,
+                    BotProfile::default(),
+                )
                 .await
                 .unwrap();
         }
 
         let result = manager
-            .create_bot("room1".to_string(), BotDifficulty::Easy)
+            .create_bot(
+                "room1".to_string(),
+                BotDifficulty::Easy,
+                None,
+                BotProfile::default(),
+            )
             .await;
 
         assert!(matches!(
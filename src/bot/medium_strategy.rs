@@ -0,0 +1,215 @@
+use async_trait::async_trait;
+use tracing::debug;
+
+use crate::game::{Card, Game};
+
+use super::planning::{Candidate, Plan};
+use super::types::BotStrategy;
+
+/// Opponents with this many cards or fewer are about to go out
+const URGENT_CARDS: usize = 2;
+
+/// Bot strategy that plans its whole hand into combos before playing
+///
+/// It plays combos from its plan rather than breaking them up, keeps Twos and bombs to win
+/// back the lead, and leads its weakest combo when the table is clear. It only breaks its
+/// plan or spends a power card to finish, or to stop an opponent who is about to go out.
+pub struct MediumBotStrategy;
+
+impl Default for MediumBotStrategy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MediumBotStrategy {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn choose_move(game: &Game, bot_uuid: &str) -> Option<Vec<Card>> {
+        let legal = game.legal_moves(bot_uuid);
+        let plan = Plan::new(game, bot_uuid, &legal)?;
+        if let Some(out) = plan.going_out() {
+            return Some(out.cards.clone());
+        }
+        let candidates = &plan.candidates;
+
+        let urgent = game
+            .players()
+            .iter()
+            .filter(|p| p.uuid != bot_uuid && !p.cards.is_empty())
+            .any(|p| p.cards.len() <= URGENT_CARDS);
+        // Power cards come out to stop an opponent, or when the rest is one combo away
+        let finishing = plan.best().is_some_and(|plan| plan.play_count() <= 2);
+        let may_use = |c: &&Candidate| c.fits_plan && (!c.is_power || urgent || finishing);
+
+        if !legal.can_pass {
+            // Leading: the weakest combo that fits the plan, bigger combos first when
+            // an opponent is close to going out
+            let pool: Vec<&Candidate> = candidates.iter().filter(may_use).collect();
+            let pool = if pool.is_empty() {
+                candidates.iter().filter(|c| c.fits_plan).collect()
+            } else {
+                pool
+            };
+            let pool = if pool.is_empty() {
+                candidates.iter().collect()
+            } else {
+                pool
+            };
+
+            return pool
+                .into_iter()
+                .min_by_key(|c| {
+                    let size = if urgent { c.cards.len() } else { 0 };
+                    (
+                        std::cmp::Reverse(size),
+                        !c.fits_plan,
+                        c.is_power,
+                        c.lowest,
+                        std::cmp::Reverse(c.cards.len()),
+                    )
+                })
+                .map(|c| c.cards.clone());
+        }
+
+        // Following: the cheapest combo that fits, else pass unless someone must be stopped
+        if let Some(play) = candidates
+            .iter()
+            .filter(may_use)
+            .min_by_key(|c| (c.is_power, c.strength))
+        {
+            return Some(play.cards.clone());
+        }
+
+        if urgent {
+            return candidates
+                .iter()
+                .max_by_key(|c| (c.relief, std::cmp::Reverse(c.strength)))
+                .map(|c| c.cards.clone());
+        }
+
+        None
+    }
+}
+
+#[async_trait]
+impl BotStrategy for MediumBotStrategy {
+    async fn decide_move(&self, game: &Game, bot_uuid: &str) -> Option<Vec<Card>> {
+        if game.current_player_turn() != bot_uuid {
+            debug!(bot_uuid = %bot_uuid, "Not bot's turn");
+            return None;
+        }
+
+        let chosen_move = Self::choose_move(game, bot_uuid);
+
+        debug!(
+            bot_uuid = %bot_uuid,
+            chosen_move = ?chosen_move,
+            "Medium bot decided on move"
+        );
+
+        chosen_move
+    }
+
+    fn strategy_name(&self) -> &'static str {
+        "MediumBotStrategy"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::test_utils::cards;
+
+    /// A game where the bot is to act, after the human (who holds `human`) opened with `table`
+    fn game_after(bot: &str, human: &str, table: Option<&str>) -> Game {
+        let mut human_cards = cards(human);
+        if let Some(table) = table {
+            human_cards.extend(cards(table));
+        }
+        human_cards.push(Card::from_string("3D").unwrap());
+        let mut game = Game::new_game_with_cards(
+            "medium".to_string(),
+            vec![
+                ("Human".to_string(), "human".to_string(), human_cards),
+                ("Bot".to_string(), "bot".to_string(), cards(bot)),
+            ],
+        )
+        .unwrap();
+
+        game.play_cards("human", &cards("3D")).unwrap();
+        if let Some(table) = table {
+            game.play_cards("bot", &[]).unwrap();
+            game.play_cards("human", &cards(table)).unwrap();
+        }
+        game
+    }
+
+    async fn decide(game: &Game) -> Option<Vec<Card>> {
+        MediumBotStrategy::new().decide_move(game, "bot").await
+    }
+
+    #[tokio::test]
+    async fn test_does_not_break_a_pair_for_a_single() {
+        // Beating the 5 with a lone 9 keeps the pair of 8s together
+        let game = game_after("8D 8S 9C KH KS", "6C 7C JD QD", Some("5H"));
+        assert_eq!(decide(&game).await, Some(cards("9C")));
+
+        // With only the pair above the table, pass rather than split it
+        let game = game_after("4D 4S 8D 8S KH KS", "6C 7C JD QD", Some("5H"));
+        assert_eq!(decide(&game).await, None);
+    }
+
+    #[tokio::test]
+    async fn test_does_not_break_a_straight() {
+        let game = game_after("4C 5D 6S 7H 8C JS", "6C 7C JD QD", Some("4H"));
+        assert_eq!(decide(&game).await, Some(cards("JS")));
+    }
+
+    #[tokio::test]
+    async fn test_holds_twos_until_needed() {
+        let game = game_after("9C TD 2S", "6C 7C JD QD KC", Some("AH"));
+        assert_eq!(decide(&game).await, None);
+
+        // The human is down to two cards, so the Two stops them
+        let game = game_after("9C TD 2S", "6C", Some("AH"));
+        assert_eq!(decide(&game).await, Some(cards("2S")));
+    }
+
+    #[tokio::test]
+    async fn test_leads_weakest_combo() {
+        // Table cleared back to the bot: lead the low pair, not the Two or the high straight
+        let mut game = game_after(
+            "4D 4S 9C TD JH QS KC 2S",
+            "6C 7C JD QD KD AC",
+            Some("5H 6S 7S 8H 9H"),
+        );
+        game.play_cards("bot", &cards("9C TD JH QS KC")).unwrap();
+        game.play_cards("human", &[]).unwrap();
+
+        assert!(game.is_table_clear());
+        assert_eq!(decide(&game).await, Some(cards("4D 4S")));
+    }
+
+    #[tokio::test]
+    async fn test_goes_out_when_it_can() {
+        let game = game_after("9C 9S", "6C 7C JD QD", Some("5H 5S"));
+        assert_eq!(decide(&game).await, Some(cards("9C 9S")));
+    }
+
+    #[tokio::test]
+    async fn test_plays_the_opening_three() {
+        let game = Game::new_game_with_cards(
+            "medium".to_string(),
+            vec![
+                ("Bot".to_string(), "bot".to_string(), cards("3D 3S 7H 9C")),
+                ("Human".to_string(), "human".to_string(), cards("4D 5C")),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(decide(&game).await, Some(cards("3D 3S")));
+    }
+}
@@ -1,7 +1,15 @@
 pub mod basic_strategy;
 pub mod bot_room_subscriber;
+pub mod card_tracker;
+pub mod endgame;
 pub mod handlers;
+pub mod hard_strategy;
+pub mod ismcts_strategy;
 pub mod manager;
+pub mod medium_strategy;
+mod planning;
+pub mod profile;
+mod simulation;
 pub mod strategy_factory;
 pub mod types;
 
@@ -0,0 +1,122 @@
+// Hand planning shared by the smarter bots: split the hand into combos with `partition_hand`
+// and weigh every legal play by what it does to the plan for the cards that would be left.
+use crate::game::{partition_hand, Card, Game, Hand, HandPartition, LegalMoves, Rank, RuleSet};
+
+/// Cost of the best plan for a set of cards (see `partition_hand`)
+fn plan_cost(cards: &[Card], rules: &RuleSet) -> u32 {
+    partition_hand(cards, rules, 1)
+        .first()
+        .map(|plan| plan.cost)
+        .unwrap_or(0)
+}
+
+/// Position of a card in the rule set's order, 0 for the 3 of the lowest suit
+fn card_value(card: &Card, rules: &RuleSet) -> u8 {
+    card.rank as u8 * 4 + rules.suit_value(card.suit)
+}
+
+/// A legal play, weighed against the bot's plan for the rest of its hand
+pub(super) struct Candidate {
+    pub hand: Hand,
+    pub cards: Vec<Card>,
+    /// Playing it leaves the rest of the hand no harder to play out than before
+    pub fits_plan: bool,
+    /// How much the plan for the whole hand improves; negative when it breaks up a combo
+    pub relief: i64,
+    /// A Two, four of a kind or straight flush, kept back to win the lead
+    pub is_power: bool,
+    /// Plays the bot's last cards
+    pub goes_out: bool,
+    /// Ordering within a hand type, weakest first
+    pub strength: (u8, u8),
+    /// Weakest card, for choosing what to lead
+    pub lowest: u8,
+}
+
+impl Candidate {
+    fn new(hand: &Hand, held: &[Card], held_cost: u32, rules: &RuleSet) -> Self {
+        let cards = hand.to_cards();
+        let rest: Vec<Card> = held
+            .iter()
+            .filter(|card| !cards.contains(card))
+            .copied()
+            .collect();
+        let rest_cost = plan_cost(&rest, rules);
+        let own_cost = plan_cost(&cards, rules);
+
+        let five_card_rank = match hand {
+            Hand::Five(five) => five.hand_type_value() + 1,
+            _ => 0,
+        };
+        let is_bomb = matches!(hand, Hand::Five(five) if five.is_bomb());
+
+        Self {
+            hand: hand.clone(),
+            fits_plan: own_cost + rest_cost <= held_cost,
+            relief: i64::from(held_cost) - i64::from(rest_cost),
+            is_power: is_bomb || cards.iter().any(|card| card.rank == Rank::Two),
+            goes_out: rest.is_empty(),
+            strength: (
+                five_card_rank,
+                card_value(&rules.highest_card(&cards), rules),
+            ),
+            lowest: cards
+                .iter()
+                .map(|card| card_value(card, rules))
+                .min()
+                .unwrap_or(0),
+            cards,
+        }
+    }
+}
+
+/// The bot's best split of its hand, and every legal play weighed against it
+pub(super) struct Plan {
+    /// Best partitions of the hand, cheapest first
+    pub partitions: Vec<HandPartition>,
+    pub candidates: Vec<Candidate>,
+}
+
+impl Plan {
+    /// Plan the bot's hand; None if the bot is not in the game
+    pub fn new(game: &Game, bot_uuid: &str, legal: &LegalMoves) -> Option<Self> {
+        let held = &game.players().iter().find(|p| p.uuid == bot_uuid)?.cards;
+        let rules = game.rules();
+
+        let partitions = game.hand_partitions(bot_uuid, 3);
+        let held_cost = partitions.first().map(|plan| plan.cost).unwrap_or(0);
+        let planned = |hand: &Hand| {
+            partitions
+                .iter()
+                .any(|partition| partition.hands.iter().any(|p| p == hand))
+        };
+
+        // Only five-card hands from a good plan are worth weighing, unless there are no others
+        let mut options: Vec<&Hand> = legal
+            .hands()
+            .filter(|hand| !matches!(hand, Hand::Five(_)) || planned(hand))
+            .collect();
+        if options.is_empty() {
+            options = legal.hands().collect();
+        }
+        let candidates = options
+            .into_iter()
+            .map(|hand| Candidate::new(hand, held, held_cost, rules))
+            .collect();
+
+        Some(Self {
+            partitions,
+            candidates,
+        })
+    }
+
+    /// The best partition, if the hand is not empty
+    pub fn best(&self) -> Option<&HandPartition> {
+        self.partitions.first()
+    }
+
+    /// A play that empties the hand, if there is one
+    pub fn going_out(&self) -> Option<&Candidate> {
+        self.candidates.iter().find(|c| c.goes_out)
+    }
+}
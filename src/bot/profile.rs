@@ -0,0 +1,399 @@
+// Bot personalities. A profile leaves the choice of move to the difficulty's strategy and then
+// nudges it: each knob is the chance of overriding that choice in one direction, so a profile of
+// zeroes plays exactly like the bare strategy. A play that goes out is never changed.
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use tokio::time::Duration;
+use tracing::debug;
+
+use crate::game::{Card, Game, Rank};
+
+use super::{basic_strategy::BasicBotStrategy, types::BotStrategy};
+
+/// Longest a profile may make a bot wait before it starts thinking; the wait counts against
+/// `STRATEGY_TIMEOUT`, so this leaves the strategy headroom under it
+pub const MAX_THINK_TIME_MS: u64 = 4_000;
+
+/// How long a bot waits before moving, drawn uniformly from `min_ms..=max_ms`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ThinkTime {
+    pub min_ms: u64,
+    pub max_ms: u64,
+}
+
+impl ThinkTime {
+    pub fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Duration {
+        Duration::from_millis(rng.random_range(self.min_ms..=self.max_ms))
+    }
+}
+
+/// Named profiles hosts can pick when adding a bot
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum BotPersonality {
+    /// Plays as its difficulty decides
+    Balanced,
+    /// Passes readily, saves its 2s and takes its time
+    Cautious,
+    /// Contests every hand, spends 2s freely and moves fast
+    Reckless,
+}
+
+impl BotPersonality {
+    pub fn profile(self) -> BotProfile {
+        match self {
+            Self::Balanced => BotProfile::default(),
+            Self::Cautious => BotProfile {
+                aggression: 0.0,
+                pass_rate: 0.3,
+                hoard_twos: 0.8,
+                temperature: 0.0,
+                think_time: ThinkTime {
+                    min_ms: 600,
+                    max_ms: 1_500,
+                },
+            },
+            Self::Reckless => BotProfile {
+                aggression: 0.8,
+                pass_rate: 0.0,
+                hoard_twos: 0.0,
+                temperature: 0.15,
+                think_time: ThinkTime {
+                    min_ms: 50,
+                    max_ms: 200,
+                },
+            },
+        }
+    }
+}
+
+/// Tunable play style layered over a bot's difficulty
+///
+/// The chances are between 0 and 1. Fields left out of a request keep their default.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BotProfile {
+    /// Chance of contesting a hand the strategy would pass on
+    pub aggression: f64,
+    /// Chance of passing instead of following, even with a legal play
+    pub pass_rate: f64,
+    /// Chance of holding back a play that spends a 2
+    pub hoard_twos: f64,
+    /// Chance of playing a random legal move instead of the strategy's choice
+    pub temperature: f64,
+    pub think_time: ThinkTime,
+}
+
+impl Default for BotProfile {
+    fn default() -> Self {
+        Self {
+            aggression: 0.0,
+            pass_rate: 0.0,
+            hoard_twos: 0.0,
+            temperature: 0.0,
+            think_time: ThinkTime {
+                min_ms: 100,
+                max_ms: 500,
+            },
+        }
+    }
+}
+
+impl BotProfile {
+    /// Whether the profile never changes the strategy's choice
+    pub fn is_neutral(&self) -> bool {
+        self.aggression == 0.0
+            && self.pass_rate == 0.0
+            && self.hoard_twos == 0.0
+            && self.temperature == 0.0
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        let chances = [
+            ("aggression", self.aggression),
+            ("pass_rate", self.pass_rate),
+            ("hoard_twos", self.hoard_twos),
+            ("temperature", self.temperature),
+        ];
+        if let Some((name, value)) = chances
+            .iter()
+            .find(|(_, value)| !(0.0..=1.0).contains(value))
+        {
+            return Err(format!("{} must be between 0 and 1, got {}", name, value));
+        }
+
+        let ThinkTime { min_ms, max_ms } = self.think_time;
+        if min_ms > max_ms {
+            return Err(format!(
+                "think_time min_ms ({}) is above max_ms ({})",
+                min_ms, max_ms
+            ));
+        }
+        if max_ms > MAX_THINK_TIME_MS {
+            return Err(format!(
+                "think_time max_ms must be at most {}, got {}",
+                MAX_THINK_TIME_MS, max_ms
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Wraps a strategy so that its moves follow a `BotProfile`
+pub struct ProfiledStrategy {
+    inner: Arc<dyn BotStrategy>,
+    profile: BotProfile,
+    seed: Option<u64>,
+}
+
+impl ProfiledStrategy {
+    pub fn new(inner: Arc<dyn BotStrategy>, profile: BotProfile) -> Self {
+        Self {
+            inner,
+            profile,
+            seed: None,
+        }
+    }
+
+    /// Roll the profile's chances from a fixed seed, for reproducible decisions
+    #[allow(dead_code)] // Public API for tuning and tests
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    fn adjust<R: Rng + ?Sized>(
+        &self,
+        game: &Game,
+        bot_uuid: &str,
+        chosen: Option<Vec<Card>>,
+        rng: &mut R,
+    ) -> Option<Vec<Card>> {
+        let held = game
+            .players()
+            .iter()
+            .find(|p| p.uuid == bot_uuid)
+            .map_or(0, |p| p.cards.len());
+        if chosen.as_ref().is_some_and(|cards| cards.len() == held) {
+            return chosen;
+        }
+
+        let legal = game.legal_moves(bot_uuid);
+        let plays: Vec<Vec<Card>> = legal.hands().map(|hand| hand.to_cards()).collect();
+        if plays.is_empty() {
+            return chosen;
+        }
+        let profile = &self.profile;
+        let lowest = |plays: Vec<Vec<Card>>, rng: &mut R| {
+            BasicBotStrategy::new().choose_best_move_with_rng(plays, rng)
+        };
+
+        if rng.random_bool(profile.temperature) {
+            // A pass is one of the options whenever it is allowed
+            let options = plays.len() + usize::from(legal.can_pass);
+            return plays.get(rng.random_range(0..options)).cloned();
+        }
+
+        let Some(cards) = chosen else {
+            if legal.can_pass && rng.random_bool(profile.aggression) {
+                return lowest(plays, rng);
+            }
+            return None;
+        };
+
+        if legal.can_pass && rng.random_bool(profile.pass_rate) {
+            return None;
+        }
+
+        let spends_two = |cards: &[Card]| cards.iter().any(|card| card.rank == Rank::Two);
+        if spends_two(&cards) && rng.random_bool(profile.hoard_twos) {
+            let without_twos: Vec<Vec<Card>> =
+                plays.into_iter().filter(|play| !spends_two(play)).collect();
+            return match lowest(without_twos, rng) {
+                Some(play) => Some(play),
+                // Leading must play something, so the 2 goes after all
+                None if !legal.can_pass => Some(cards),
+                None => None,
+            };
+        }
+
+        Some(cards)
+    }
+}
+
+#[async_trait]
+impl BotStrategy for ProfiledStrategy {
+    async fn decide_move(&self, game: &Game, bot_uuid: &str) -> Option<Vec<Card>> {
+        self.decide_move_after(game, bot_uuid, Duration::ZERO).await
+    }
+
+    async fn decide_move_after(
+        &self,
+        game: &Game,
+        bot_uuid: &str,
+        spent: Duration,
+    ) -> Option<Vec<Card>> {
+        let chosen = self.inner.decide_move_after(game, bot_uuid, spent).await;
+        if game.current_player_turn() != bot_uuid {
+            return chosen;
+        }
+
+        let mut rng = self
+            .seed
+            .map_or_else(StdRng::from_os_rng, StdRng::seed_from_u64);
+        let adjusted = self.adjust(game, bot_uuid, chosen.clone(), &mut rng);
+        if adjusted != chosen {
+            debug!(
+                bot_uuid = %bot_uuid,
+                strategy_move = ?chosen,
+                chosen_move = ?adjusted,
+                "Bot profile changed the move"
+            );
+        }
+        adjusted
+    }
+
+    fn strategy_name(&self) -> &'static str {
+        self.inner.strategy_name()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::test_utils::{cards, game};
+
+    /// Always plays the same cards, or passes
+    struct Fixed(Option<&'static str>);
+
+    #[async_trait]
+    impl BotStrategy for Fixed {
+        async fn decide_move(&self, _game: &Game, _bot_uuid: &str) -> Option<Vec<Card>> {
+            self.0.map(cards)
+        }
+
+        fn strategy_name(&self) -> &'static str {
+            "Fixed"
+        }
+    }
+
+    async fn decide(
+        game: &Game,
+        strategy: Option<&'static str>,
+        profile: BotProfile,
+    ) -> Option<Vec<Card>> {
+        ProfiledStrategy::new(Arc::new(Fixed(strategy)), profile)
+            .with_seed(3)
+            .decide_move(game, "bot")
+            .await
+    }
+
+    /// The human has led the 3D and the bot, holding 5H 9C 2S, is to follow
+    fn bot_to_follow() -> Game {
+        let mut game = game(&[("human", "3D 6C 7C 8H"), ("bot", "5H 9C 2S")]);
+        game.play_cards("human", &cards("3D")).unwrap();
+        game
+    }
+
+    #[tokio::test]
+    async fn test_balanced_profile_keeps_the_strategy_move() {
+        let game = bot_to_follow();
+        let balanced = BotPersonality::Balanced.profile();
+        assert!(balanced.is_neutral());
+        assert_eq!(
+            decide(&game, Some("2S"), balanced.clone()).await,
+            Some(cards("2S"))
+        );
+        assert_eq!(decide(&game, None, balanced).await, None);
+    }
+
+    #[tokio::test]
+    async fn test_knobs_override_the_strategy() {
+        let game = bot_to_follow();
+        let always = |set: fn(&mut BotProfile)| {
+            let mut profile = BotProfile::default();
+            set(&mut profile);
+            profile
+        };
+
+        let aggressive = always(|p| p.aggression = 1.0);
+        assert_eq!(decide(&game, None, aggressive).await, Some(cards("5H")));
+
+        let passive = always(|p| p.pass_rate = 1.0);
+        assert_eq!(decide(&game, Some("9C"), passive).await, None);
+
+        let hoarder = always(|p| p.hoard_twos = 1.0);
+        assert_eq!(decide(&game, Some("2S"), hoarder).await, Some(cards("5H")));
+    }
+
+    #[tokio::test]
+    async fn test_profile_never_stops_a_bot_going_out() {
+        let mut game = game(&[("human", "3D 6C"), ("bot", "2S")]);
+        game.play_cards("human", &cards("3D")).unwrap();
+
+        let cautious = BotProfile {
+            pass_rate: 1.0,
+            hoard_twos: 1.0,
+            temperature: 1.0,
+            ..BotProfile::default()
+        };
+        assert_eq!(decide(&game, Some("2S"), cautious).await, Some(cards("2S")));
+    }
+
+    #[tokio::test]
+    async fn test_random_moves_stay_legal() {
+        let game = bot_to_follow();
+        let random = BotProfile {
+            temperature: 1.0,
+            ..BotProfile::default()
+        };
+        for seed in 0..20 {
+            let chosen = ProfiledStrategy::new(Arc::new(Fixed(Some("2S"))), random.clone())
+                .with_seed(seed)
+                .decide_move(&game, "bot")
+                .await
+                .unwrap_or_default();
+            assert!(game.validate_move("bot", &chosen).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_validate_rejects_out_of_range_knobs() {
+        assert!(BotPersonality::Cautious.profile().validate().is_ok());
+        assert!(BotPersonality::Reckless.profile().validate().is_ok());
+
+        let wild = BotProfile {
+            aggression: 1.5,
+            ..BotProfile::default()
+        };
+        assert!(wild.validate().unwrap_err().contains("aggression"));
+
+        let backwards = BotProfile {
+            think_time: ThinkTime {
+                min_ms: 900,
+                max_ms: 100,
+            },
+            ..BotProfile::default()
+        };
+        assert!(backwards.validate().is_err());
+
+        let sleepy = BotProfile {
+            think_time: ThinkTime {
+                min_ms: 0,
+                max_ms: MAX_THINK_TIME_MS + 1,
+            },
+            ..BotProfile::default()
+        };
+        assert!(sleepy.validate().is_err());
+    }
+
+    #[test]
+    fn test_longest_wait_leaves_time_to_decide() {
+        use crate::bot::bot_room_subscriber::STRATEGY_TIMEOUT;
+        assert!(std::time::Duration::from_millis(MAX_THINK_TIME_MS) < STRATEGY_TIMEOUT);
+    }
+}
@@ -0,0 +1,332 @@
+// A lightweight copy of the table for bots that search ahead. Hands are CardSets, moves are the
+// set of cards played (empty for a pass), and only the core rules are modelled: beat the hand on
+// the table or pass, and the table clears once everyone else has passed. House rules such as pass
+// lockout are left out; searches take the bot's own moves from `Game::legal_moves`, so whatever
+// they choose is always legal in the real game. A simulation ends when the next player goes out:
+// the winner of a plain game, or the next place when the finishing order is played out. Seats
+// already out when the table is copied hold no cards and are skipped.
+use strum::IntoEnumIterator;
+
+use crate::game::{CardSet, Game, Hand, Rank, RuleSet};
+
+/// A move in a simulation: the cards played, empty for a pass
+pub(super) type SimMove = CardSet;
+
+/// Everything that decides how a simulation can go on, for transposition tables
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(super) struct SimKey {
+    hands: Vec<CardSet>,
+    turn: usize,
+    to_beat: Option<CardSet>,
+    passes: usize,
+    passes_to_clear: usize,
+}
+
+/// Table state for searching ahead, with every hand known
+#[derive(Debug, Clone)]
+pub(super) struct SimState {
+    rules: RuleSet,
+    /// Cards held by each seat, in the game's seating order
+    pub hands: Vec<CardSet>,
+    /// Seat to act
+    pub turn: usize,
+    /// Hand to beat, None when the table is clear
+    to_beat: Option<Hand>,
+    passes: usize,
+    /// Passes in a row that clear the table
+    passes_to_clear: usize,
+    /// Seats that had already gone out when the table was copied
+    finished: Vec<bool>,
+    /// Five-card hands each seat was holding, listed on first use
+    five_card_hands: Vec<Option<Vec<(CardSet, Hand)>>>,
+}
+
+impl SimState {
+    /// Copy the table from `game`, with each seat holding the cards in `hands`
+    pub fn from_game(game: &Game, hands: Vec<CardSet>) -> Self {
+        let seat_of = |uuid: &str| game.players().iter().position(|p| p.uuid == uuid);
+        let turn = seat_of(&game.current_player_turn()).unwrap_or(0);
+        let active = hands.iter().filter(|hand| !hand.is_empty()).count();
+
+        let (to_beat, passes_to_clear) = if game.is_table_clear() {
+            (None, active.saturating_sub(1).max(1))
+        } else {
+            // A player who went out on the table's last hand is not there to win it back
+            let leader_out = game
+                .last_non_pass_player_uuid()
+                .and_then(|uuid| seat_of(&uuid))
+                .is_some_and(|seat| hands[seat].is_empty());
+            let to_beat = Hand::from_cards_with_rules(&game.last_non_pass_cards(), game.rules());
+            let clear = if leader_out { active } else { active - 1 };
+            (to_beat.ok(), clear.max(1))
+        };
+
+        Self {
+            rules: *game.rules(),
+            five_card_hands: vec![None; hands.len()],
+            finished: hands.iter().map(CardSet::is_empty).collect(),
+            hands,
+            turn,
+            to_beat,
+            passes: game.consecutive_passes(),
+            passes_to_clear,
+        }
+    }
+
+    pub fn key(&self) -> SimKey {
+        let to_beat = self
+            .to_beat
+            .as_ref()
+            .map(|hand| hand.to_cards().into_iter().collect());
+        SimKey {
+            hands: self.hands.clone(),
+            turn: self.turn,
+            // Passes only count while there is a hand to beat
+            passes: if to_beat.is_some() { self.passes } else { 0 },
+            to_beat,
+            passes_to_clear: self.passes_to_clear,
+        }
+    }
+
+    /// Seat that has gone out since the table was copied, which ends the simulation
+    pub fn winner(&self) -> Option<usize> {
+        self.hands
+            .iter()
+            .zip(&self.finished)
+            .position(|(hand, finished)| hand.is_empty() && !finished)
+    }
+
+    pub fn can_pass(&self) -> bool {
+        self.to_beat.is_some()
+    }
+
+    /// Every move open to the seat to act, the pass last
+    pub fn legal_moves(&mut self) -> Vec<SimMove> {
+        let hand = self.hands[self.turn];
+        let mut plays: Vec<(CardSet, Hand)> = hand
+            .iter()
+            .map(|card| CardSet::from_iter([card]))
+            .chain(Rank::iter().flat_map(|rank| {
+                let same_rank = hand.of_rank(rank);
+                same_rank.subsets(2).into_iter().chain(same_rank.subsets(3))
+            }))
+            .filter_map(|set| Some((set, self.to_hand(set)?)))
+            .collect();
+        plays.extend(self.five_card_hands_of(self.turn));
+
+        let mut moves: Vec<SimMove> = plays
+            .into_iter()
+            .filter(|(_, play)| self.beats_table(play))
+            .map(|(set, _)| set)
+            .collect();
+        if self.can_pass() {
+            moves.push(CardSet::EMPTY);
+        }
+        moves
+    }
+
+    /// Fast default policy for playouts
+    ///
+    /// Leading, it plays the lowest card together with the rest of its rank (up to a triple),
+    /// or the weakest five-card hand it starts. Following, it plays the weakest hand of the
+    /// same size that beats the table, and passes if there is none.
+    pub fn default_move(&mut self) -> SimMove {
+        let hand = self.hands[self.turn];
+        let Some(to_beat) = self.to_beat.clone() else {
+            let Some(lowest) = hand.lowest() else {
+                return CardSet::EMPTY;
+            };
+            if let Some((five, _)) = self
+                .five_card_hands_of(self.turn)
+                .into_iter()
+                .find(|(set, _)| set.contains(lowest))
+            {
+                return five;
+            }
+            return hand.of_rank(lowest.rank).iter().take(3).collect();
+        };
+
+        let size = to_beat.to_cards().len();
+        let options: Vec<(CardSet, Hand)> = if size == 5 {
+            self.five_card_hands_of(self.turn)
+        } else {
+            Rank::iter()
+                .flat_map(|rank| hand.of_rank(rank).subsets(size))
+                .filter_map(|set| Some((set, self.to_hand(set)?)))
+                .collect()
+        };
+
+        let mut weakest: Option<(CardSet, Hand)> = None;
+        for (set, play) in options {
+            if !play.can_beat_with_rules(&to_beat, &self.rules) {
+                continue;
+            }
+            let weaker = weakest
+                .as_ref()
+                .is_none_or(|(_, best)| best.can_beat_with_rules(&play, &self.rules));
+            if weaker {
+                weakest = Some((set, play));
+            }
+        }
+        weakest.map_or(CardSet::EMPTY, |(set, _)| set)
+    }
+
+    /// Play `mv` for the seat to act; the move must come from `legal_moves` or `default_move`
+    pub fn apply(&mut self, mv: SimMove) {
+        if mv.is_empty() {
+            self.passes += 1;
+            if self.passes >= self.passes_to_clear {
+                self.to_beat = None;
+            }
+        } else {
+            self.hands[self.turn] = self.hands[self.turn].difference(&mv);
+            self.to_beat = self.to_hand(mv);
+            self.passes = 0;
+            let active = self.hands.iter().filter(|hand| !hand.is_empty()).count();
+            self.passes_to_clear = active.saturating_sub(1).max(1);
+        }
+        self.advance_turn();
+    }
+
+    /// Play default moves until someone goes out, returning their seat
+    pub fn play_out(&mut self) -> Option<usize> {
+        // Every round removes cards, so this is only a guard against a stuck table
+        for _ in 0..1000 {
+            if let Some(winner) = self.winner() {
+                return Some(winner);
+            }
+            let mv = self.default_move();
+            self.apply(mv);
+        }
+        None
+    }
+
+    fn advance_turn(&mut self) {
+        let seats = self.hands.len();
+        self.turn = (1..=seats)
+            .map(|step| (self.turn + step) % seats)
+            .find(|&seat| !self.hands[seat].is_empty())
+            .unwrap_or(self.turn);
+    }
+
+    fn to_hand(&self, set: CardSet) -> Option<Hand> {
+        Hand::from_cards_with_rules(&set.to_vec(), &self.rules).ok()
+    }
+
+    fn beats_table(&self, play: &Hand) -> bool {
+        self.to_beat
+            .as_ref()
+            .is_none_or(|to_beat| play.can_beat_with_rules(to_beat, &self.rules))
+    }
+
+    /// Five-card hands the seat still holds
+    fn five_card_hands_of(&mut self, seat: usize) -> Vec<(CardSet, Hand)> {
+        let hand = self.hands[seat];
+        if self.five_card_hands[seat].is_none() {
+            let fives = hand
+                .subsets(5)
+                .into_iter()
+                .filter_map(|set| Some((set, self.to_hand(set)?)))
+                .collect();
+            self.five_card_hands[seat] = Some(fives);
+        }
+
+        self.five_card_hands[seat]
+            .iter()
+            .flatten()
+            .filter(|(set, _)| set.is_subset(&hand))
+            .cloned()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::HouseRules;
+    use crate::shared::test_utils::{cards, game};
+
+    fn set(codes: &str) -> CardSet {
+        cards(codes).into_iter().collect()
+    }
+
+    fn sim(hands: &[(&str, &str)]) -> (Game, SimState) {
+        let game = game(hands);
+        let held = hands.iter().map(|(_, codes)| set(codes)).collect();
+        let state = SimState::from_game(&game, held);
+        (game, state)
+    }
+
+    #[test]
+    fn test_legal_moves_follow_the_table() {
+        let (_, mut state) = sim(&[("alice", "3D 3S 9C"), ("bob", "4C 4H 5D 6S 7C 8H")]);
+        assert!(!state.can_pass());
+        assert_eq!(state.legal_moves().len(), 3 + 1);
+
+        state.apply(set("3D 3S"));
+        assert_eq!(state.turn, 1);
+        // Only the pair of 4s beats the 3s, or bob passes
+        assert_eq!(state.legal_moves(), vec![set("4C 4H"), CardSet::EMPTY]);
+
+        state.apply(CardSet::EMPTY);
+        // Bob passed, so alice leads anything
+        assert_eq!(state.turn, 0);
+        assert!(!state.can_pass());
+    }
+
+    #[test]
+    fn test_default_policy_plays_out_a_game() {
+        let (_, mut state) = sim(&[("alice", "3D 3S 9C KD"), ("bob", "4C 5D 6S 7C 8H JD")]);
+        // Leading, the policy plays its lowest rank together
+        assert_eq!(state.default_move(), set("3D 3S"));
+        // Bob leads a straight once he has the table
+        state.apply(set("3D 3S"));
+        assert_eq!(state.default_move(), CardSet::EMPTY);
+        state.apply(CardSet::EMPTY);
+        state.apply(set("9C"));
+        assert_eq!(state.default_move(), set("JD"));
+        state.apply(set("JD"));
+        state.apply(CardSet::EMPTY);
+        assert_eq!(state.default_move(), set("4C 5D 6S 7C 8H"));
+
+        let (_, mut state) = sim(&[("alice", "3D 3S 9C KD"), ("bob", "4C 5D 6S 7C 8H JD")]);
+        // Alice's 9 and king win both rounds of singles
+        assert_eq!(state.play_out(), Some(0));
+    }
+
+    #[test]
+    fn test_seats_already_out_do_not_end_the_simulation() {
+        let (game, _) = sim(&[("alice", "3D 9C"), ("bob", "4C"), ("carol", "6S 7C")]);
+        let mut game = game.with_house_rules(HouseRules {
+            finishing_order: true,
+            ..HouseRules::default()
+        });
+        game.play_cards("alice", &cards("3D")).unwrap();
+        game.play_cards("bob", &cards("4C")).unwrap();
+
+        // Bob is out, but the game goes on for second place
+        let hands = vec![set("9C"), CardSet::EMPTY, set("6S 7C")];
+        let mut state = SimState::from_game(&game, hands);
+        assert_eq!(state.winner(), None);
+        assert_eq!(state.turn, 2);
+        assert!(state.legal_moves().contains(&set("6S")));
+        // Carol follows with her 6 and alice takes second place with the 9
+        assert_eq!(state.play_out(), Some(0));
+    }
+
+    #[test]
+    fn test_copies_the_table_from_the_game() {
+        let (mut game, _) = sim(&[("alice", "3D 9C"), ("bob", "4C 5D"), ("carol", "6S 7C")]);
+        game.play_cards("alice", &cards("3D")).unwrap();
+        game.play_cards("bob", &[]).unwrap();
+
+        let hands = vec![set("9C"), set("4C 5D"), set("6S 7C")];
+        let mut state = SimState::from_game(&game, hands);
+        assert_eq!(state.turn, 2);
+        assert!(state.can_pass());
+        state.apply(CardSet::EMPTY);
+        // Both others passed on the 3D, so the table is clear for alice
+        assert_eq!(state.turn, 0);
+        assert!(!state.can_pass());
+    }
+}
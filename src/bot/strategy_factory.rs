@@ -1,8 +1,13 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 use super::{
     basic_strategy::BasicBotStrategy,
-    types::{BotDifficulty, BotStrategy},
+    endgame::EndgameOverride,
+    hard_strategy::HardBotStrategy,
+    ismcts_strategy::IsmctsBotStrategy,
+    medium_strategy::MediumBotStrategy,
+    profile::ProfiledStrategy,
+    types::{BotDifficulty, BotPlayer, BotStrategy},
 };
 
 /// Factory for creating bot strategies based on difficulty level
@@ -12,18 +17,53 @@ impl BotStrategyFactory {
     /// Create a strategy instance for the given difficulty level
     pub fn create_strategy(difficulty: BotDifficulty) -> Arc<dyn BotStrategy> {
         match difficulty {
-            // All difficulty levels currently use BasicBotStrategy
-            // Future implementation: add Medium and Hard strategies
-            BotDifficulty::Easy | BotDifficulty::Medium | BotDifficulty::Hard => {
-                Arc::new(BasicBotStrategy::new())
+            BotDifficulty::Easy => Arc::new(BasicBotStrategy::new()),
+            // Stronger bots play out won endgames exactly
+            BotDifficulty::Medium => Arc::new(EndgameOverride::new(MediumBotStrategy::new())),
+            BotDifficulty::Hard => Arc::new(EndgameOverride::new(HardBotStrategy::new())),
+            BotDifficulty::Expert => Arc::new(EndgameOverride::new(IsmctsBotStrategy::new())),
+        }
+    }
+
+    /// Create the strategy for a bot, with its thinking time and following its profile
+    ///
+    /// The profile sits inside the endgame override, so it never throws away a solved win.
+    pub fn create_strategy_for(bot: &BotPlayer) -> Arc<dyn BotStrategy> {
+        if bot.profile.is_neutral() {
+            return match bot.difficulty {
+                BotDifficulty::Expert => Arc::new(EndgameOverride::new(Self::expert(bot))),
+                difficulty => Self::create_strategy(difficulty),
+            };
+        }
+
+        let profiled =
+            |inner: Arc<dyn BotStrategy>| ProfiledStrategy::new(inner, bot.profile.clone());
+        match bot.difficulty {
+            BotDifficulty::Easy => Arc::new(profiled(Arc::new(BasicBotStrategy::new()))),
+            BotDifficulty::Medium => Arc::new(EndgameOverride::new(profiled(Arc::new(
+                MediumBotStrategy::new(),
+            )))),
+            BotDifficulty::Hard => Arc::new(EndgameOverride::new(profiled(Arc::new(
+                HardBotStrategy::new(),
+            )))),
+            BotDifficulty::Expert => {
+                Arc::new(EndgameOverride::new(profiled(Arc::new(Self::expert(bot)))))
             }
         }
     }
+
+    fn expert(bot: &BotPlayer) -> IsmctsBotStrategy {
+        match bot.time_budget_ms {
+            Some(ms) => IsmctsBotStrategy::new().with_time_budget(Duration::from_millis(ms)),
+            None => IsmctsBotStrategy::new(),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::bot::profile::BotPersonality;
 
     #[test]
     fn test_create_easy_strategy() {
@@ -34,14 +74,64 @@ mod tests {
     #[test]
     fn test_create_medium_strategy() {
         let strategy = BotStrategyFactory::create_strategy(BotDifficulty::Medium);
-        // Currently uses BasicBotStrategy, will be updated when MediumBotStrategy is implemented
-        assert_eq!(strategy.strategy_name(), "BasicBotStrategy");
+        assert_eq!(strategy.strategy_name(), "MediumBotStrategy");
     }
 
     #[test]
     fn test_create_hard_strategy() {
         let strategy = BotStrategyFactory::create_strategy(BotDifficulty::Hard);
-        // Currently uses BasicBotStrategy, will be updated when HardBotStrategy is implemented
-        assert_eq!(strategy.strategy_name(), "BasicBotStrategy");
+        assert_eq!(strategy.strategy_name(), "HardBotStrategy");
+    }
+
+    #[test]
+    fn test_create_expert_strategy() {
+        let strategy = BotStrategyFactory::create_strategy(BotDifficulty::Expert);
+        assert_eq!(strategy.strategy_name(), "IsmctsBotStrategy");
+    }
+
+    #[test]
+    fn test_create_strategy_for_bot() {
+        let mut bot = BotPlayer::new("room".to_string(), "Bot".to_string(), BotDifficulty::Hard);
+        let strategy = BotStrategyFactory::create_strategy_for(&bot);
+        assert_eq!(strategy.strategy_name(), "HardBotStrategy");
+
+        bot.difficulty = BotDifficulty::Expert;
+        bot.time_budget_ms = Some(500);
+        let strategy = BotStrategyFactory::create_strategy_for(&bot);
+        assert_eq!(strategy.strategy_name(), "IsmctsBotStrategy");
+    }
+
+    #[test]
+    fn test_profiled_strategy_keeps_the_difficulty_name() {
+        let bot = BotPlayer::new("room1".to_string(), "Bot".to_string(), BotDifficulty::Hard)
+            .with_profile(BotPersonality::Reckless.profile());
+        let strategy = BotStrategyFactory::create_strategy_for(&bot);
+        assert_eq!(strategy.strategy_name(), "HardBotStrategy");
+    }
+
+    #[tokio::test]
+    async fn test_profile_never_overrides_a_solved_win() {
+        use crate::bot::profile::BotProfile;
+        use crate::shared::test_utils::{cards, game};
+
+        // Only the 2S wins; a profile that always passes or plays at random would throw it away
+        let mut bot = BotPlayer::new(
+            "room1".to_string(),
+            "Bot".to_string(),
+            BotDifficulty::Medium,
+        )
+        .with_profile(BotProfile {
+            pass_rate: 1.0,
+            temperature: 1.0,
+            ..BotProfile::default()
+        });
+        bot.uuid = "bot".to_string();
+        let mut game = game(&[("human", "3D 9C"), ("bot", "4D 2S")]);
+        game.play_cards("human", &cards("3D")).unwrap();
+
+        let strategy = BotStrategyFactory::create_strategy_for(&bot);
+        for _ in 0..10 {
+            assert_eq!(strategy.decide_move(&game, "bot").await, Some(cards("2S")));
+        }
     }
 }
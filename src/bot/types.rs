@@ -1,9 +1,13 @@
+use std::time::Duration;
+
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::game::{Card, Game};
 
+use super::profile::BotProfile;
+
 /// Represents a bot player in the game
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BotPlayer {
@@ -11,6 +15,11 @@ pub struct BotPlayer {
     pub name: String,    // Display name (e.g., "Bot 1")
     pub room_id: String, // Room the bot belongs to
     pub difficulty: BotDifficulty,
+    /// How long an expert bot searches per move; the default budget when unset
+    #[serde(default)]
+    pub time_budget_ms: Option<u64>,
+    #[serde(default)]
+    pub profile: BotProfile,
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
@@ -19,6 +28,8 @@ pub enum BotDifficulty {
     Easy,
     Medium,
     Hard,
+    /// Searches ahead with ISMCTS; slower to move than the heuristic tiers
+    Expert,
 }
 
 impl BotPlayer {
@@ -30,9 +41,17 @@ impl BotPlayer {
             name,
             room_id,
             difficulty,
+            time_budget_ms: None,
+            profile: BotProfile::default(),
         }
     }
 
+    /// Give the bot a personality on top of its difficulty
+    pub fn with_profile(mut self, profile: BotProfile) -> Self {
+        self.profile = profile;
+        self
+    }
+
     /// Check if a UUID belongs to a bot
     pub fn is_bot_uuid(uuid: &str) -> bool {
         uuid.starts_with("bot-")
@@ -46,6 +65,18 @@ pub trait BotStrategy: Send + Sync {
     /// Returns None if the bot should pass
     async fn decide_move(&self, game: &Game, bot_uuid: &str) -> Option<Vec<Card>>;
 
+    /// Decide a move when `spent` of the bot's thinking time is already gone, e.g. on an
+    /// endgame search; strategies that search for a fixed time take it off their budget
+    async fn decide_move_after(
+        &self,
+        game: &Game,
+        bot_uuid: &str,
+        spent: Duration,
+    ) -> Option<Vec<Card>> {
+        let _ = spent;
+        self.decide_move(game, bot_uuid).await
+    }
+
     /// Get the name of this strategy
     #[allow(dead_code)] // Trait method for strategy identification
     fn strategy_name(&self) -> &'static str;
@@ -1,6 +1,7 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::game::{Card, Game};
+use crate::game::{Card, GameConfig, InstantWin, MatchStanding, MatchTarget};
 
 /// Room-specific events (delivered only to room subscribers)
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -20,21 +21,72 @@ pub enum RoomEvent {
     /// WebSocket connection was lost for a player  
     PlayerDisconnected { player: String },
     /// Host attempt to start game
-    TryStartGame { host: String },
+    TryStartGame {
+        host: String,
+        /// Start a match with this target, unless one is already in progress
+        match_target: Option<MatchTarget>,
+        /// Deal from this seed instead of a random one, to replay a deal
+        seed: Option<u64>,
+    },
     /// Create game (emitted when TryStartGame is successful)
-    CreateGame { players: Vec<String> },
+    CreateGame {
+        players: Vec<String>,
+        /// Rules chosen in the room's settings
+        config: GameConfig,
+    },
     /// Start game (emitted when CreateGame is successful)
-    StartGame { game: Game },
+    ///
+    /// Only public table information is carried here; per-player hands are served
+    /// through `Game::view_for` so they never travel on the room bus.
+    StartGame {
+        /// Player UUIDs in turn order
+        players: Vec<String>,
+        current_turn: String,
+    },
     /// Player played move
     TryPlayMove { player: String, cards: Vec<Card> },
+    /// Player typed a move, e.g. "3D 3C" or "pair of 7s", to be read against their hand
+    TryPlayText { player: String, text: String },
+    /// A player asked for a redeal under the misdeal house rule
+    RedealRequested { player: String },
+    /// A player's weak hand was accepted as a misdeal; a new game is dealt
+    Misdeal { player: String },
+    /// A dealt hand won the game before anyone played (followed by GameWon)
+    InstantWin {
+        player: String,
+        kind: InstantWin,
+        /// The whole dealt hand, shown to the table
+        hand: Vec<Card>,
+    },
+    /// A player asked to undo their latest action
+    TryTakeBack { player: String },
+    /// A player answered a pending take-back request
+    TakeBackVote { player: String, approve: bool },
+    /// A take-back is waiting on every listed player's approval
+    TakeBackRequested { player: String, voters: Vec<String> },
+    /// A player's latest action was undone (followed by TurnChanged)
+    MoveTakenBack { player: String, cards: Vec<Card> },
     /// Player played move
     MovePlayed {
         player: String,
         cards: Vec<Card>,
-        game: Game,
+        /// Cards left in the mover's hand after the play
+        remaining_cards: usize,
+    },
+    /// A player's move, redeal or take-back request was refused
+    RequestRejected {
+        player: String,
+        /// Machine-readable error code (see `GameError::code`)
+        code: String,
+        message: String,
     },
     /// Turn changed to next player
     TurnChanged { player: String },
+    /// The room's turn clock started for a player, who must act by `expires_at`
+    TurnClockStarted {
+        player: String,
+        expires_at: DateTime<Utc>,
+    },
     /// Game won by a player
     GameWon {
         /// UUID of the player who won the game
@@ -52,6 +104,15 @@ pub enum RoomEvent {
     StatsUpdated {
         room_stats: crate::stats::models::RoomStats,
     },
+    /// A match reached its target
+    MatchOver {
+        /// Best first
+        standings: Vec<MatchStanding>,
+    },
     /// Heartbeat received from a player (for connection health check)
     HeartbeatReceived { player: String },
+    /// A player asked for a fresh snapshot of the game state
+    SyncRequested { player: String },
+    /// A player asked for suggested ways to split their hand into plays
+    ArrangeRequested { player: String },
 }
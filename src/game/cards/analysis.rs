@@ -0,0 +1,257 @@
+// Hand partitioning: split a hand into the fewest plays. The search always places the lowest
+// remaining card, either alone or in a pair, triple or five-card hand that contains it, so every
+// partition is generated exactly once, and it abandons any branch that cannot beat the best
+// partitions found so far.
+use std::cmp::{Ordering, Reverse};
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::basic::Card;
+use super::card_set::CardSet;
+use super::hands::{FiveCardHand, Hand};
+use super::rules::RuleSet;
+
+/// What every play in a partition costs
+const PLAY_COST: u32 = 2;
+/// Extra cost of a leftover single, the hardest kind of card to get rid of
+const SINGLE_COST: u32 = 1;
+
+/// One way to split a hand into valid plays
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HandPartition {
+    /// Five-card hands first, then triples, pairs and singles, lowest first within each
+    pub hands: Vec<Hand>,
+    /// Lower is better: two per play plus one per leftover single
+    pub cost: u32,
+}
+
+impl HandPartition {
+    /// Number of turns needed to play out the hand
+    #[allow(dead_code)] // Public API for bots
+    pub fn play_count(&self) -> usize {
+        self.hands.len()
+    }
+
+    #[allow(dead_code)] // Public API for bots
+    pub fn singles(&self) -> impl Iterator<Item = &Hand> {
+        self.hands
+            .iter()
+            .filter(|hand| matches!(hand, Hand::Single(_)))
+    }
+}
+
+/// The best `limit` ways to split `cards` into plays, cheapest first
+///
+/// Repeated cards are counted once. Straights follow the rule set, so a hand may split
+/// differently under, say, Pusoy Dos than under the standard rules.
+pub fn partition_hand(cards: &[Card], rules: &RuleSet, limit: usize) -> Vec<HandPartition> {
+    if limit == 0 {
+        return Vec::new();
+    }
+
+    let hand: CardSet = cards.iter().copied().collect();
+    let mut five_card_hands: HashMap<Card, Vec<CardSet>> = HashMap::new();
+    for set in hand.subsets(5) {
+        if FiveCardHand::classify_hand(set, rules).is_ok() {
+            if let Some(lowest) = set.lowest() {
+                five_card_hands.entry(lowest).or_default().push(set);
+            }
+        }
+    }
+
+    let mut search = Search {
+        rules,
+        limit,
+        five_card_hands,
+        current: Vec::new(),
+        best: Vec::new(),
+    };
+    search.run(hand);
+
+    search
+        .best
+        .into_iter()
+        .map(|(cost, _, plays)| HandPartition {
+            hands: to_hands(plays, rules),
+            cost,
+        })
+        .collect()
+}
+
+/// (cost, weakest single reversed, plays): the ranking key of a partition, best first
+type Scored = (u32, Reverse<u32>, Vec<CardSet>);
+
+/// Branch-and-bound over partitions, keeping only the best `limit`
+struct Search<'a> {
+    rules: &'a RuleSet,
+    limit: usize,
+    /// Classified five-card hands, keyed by their lowest card
+    five_card_hands: HashMap<Card, Vec<CardSet>>,
+    current: Vec<CardSet>,
+    best: Vec<Scored>,
+}
+
+impl Search<'_> {
+    fn run(&mut self, remaining: CardSet) {
+        let Some(lowest) = remaining.lowest() else {
+            self.record();
+            return;
+        };
+
+        // Even playing everything left in five-card hands would not make the cut
+        let bound = cost(&self.current) + PLAY_COST * remaining.len().div_ceil(5) as u32;
+        if self.best.len() == self.limit && self.best.last().is_some_and(|worst| bound > worst.0) {
+            return;
+        }
+
+        // Every other card left ranks above `lowest`, so a play containing it has it as its
+        // lowest card
+        let same_rank = remaining.of_rank(lowest.rank);
+        let mut candidates: Vec<CardSet> = (1..=3)
+            .flat_map(|size| same_rank.subsets(size))
+            .filter(|play| play.contains(lowest))
+            .collect();
+        if let Some(fives) = self.five_card_hands.get(&lowest) {
+            candidates.extend(fives.iter().filter(|play| play.is_subset(&remaining)));
+        }
+
+        // Try the biggest plays first so the bound tightens early
+        for play in candidates.into_iter().rev() {
+            self.current.push(play);
+            self.run(remaining.difference(&play));
+            self.current.pop();
+        }
+    }
+
+    fn record(&mut self) {
+        let scored = (
+            cost(&self.current),
+            Reverse(weakest_single(&self.current, self.rules)),
+            self.current.clone(),
+        );
+        let position = self.best.partition_point(|other| *other <= scored);
+        if position < self.limit {
+            self.best.insert(position, scored);
+            self.best.truncate(self.limit);
+        }
+    }
+}
+
+fn cost(plays: &[CardSet]) -> u32 {
+    plays
+        .iter()
+        .map(|play| match play.len() {
+            1 => PLAY_COST + SINGLE_COST,
+            _ => PLAY_COST,
+        })
+        .sum()
+}
+
+/// Strength of the weakest leftover single; partitions without singles score highest
+fn weakest_single(plays: &[CardSet], rules: &RuleSet) -> u32 {
+    plays
+        .iter()
+        .filter(|play| play.len() == 1)
+        .filter_map(|play| play.lowest())
+        .map(|card| card.rank as u32 * 4 + u32::from(rules.suit_value(card.suit)))
+        .min()
+        .unwrap_or(u32::MAX)
+}
+
+fn to_hands(plays: Vec<CardSet>, rules: &RuleSet) -> Vec<Hand> {
+    let mut plays: Vec<Vec<Card>> = plays
+        .into_iter()
+        .map(|play| {
+            let mut cards = play.to_vec();
+            cards.sort_by(|a, b| rules.compare_cards(a, b));
+            cards
+        })
+        .collect();
+    plays.sort_by(|a, b| match b.len().cmp(&a.len()) {
+        Ordering::Equal => rules.compare_cards(&a[0], &b[0]),
+        other => other,
+    });
+
+    plays
+        .iter()
+        .filter_map(|cards| Hand::from_cards_with_rules(cards, rules).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::test_utils::cards;
+
+    fn hand_cards(partition: &HandPartition) -> Vec<Vec<Card>> {
+        partition.hands.iter().map(|hand| hand.to_cards()).collect()
+    }
+
+    #[test]
+    fn test_best_partition_uses_fewest_plays() {
+        let hand = cards("3D 4C 5H 6S 7D 9C 9H 9S KD KS AC 2H 2S");
+        let best = &partition_hand(&hand, &RuleSet::standard(), 1)[0];
+
+        // Straight, full house, pair of twos and the lone ace
+        assert_eq!(best.play_count(), 4);
+        assert_eq!(best.cost, 4 * PLAY_COST + SINGLE_COST);
+        assert_eq!(best.singles().count(), 1);
+        assert!(matches!(
+            best.hands[0],
+            Hand::Five(FiveCardHand::Straight(_))
+        ));
+        assert!(matches!(
+            best.hands[1],
+            Hand::Five(FiveCardHand::FullHouse(_))
+        ));
+        assert_eq!(hand_cards(best)[2], cards("2H 2S"));
+        assert_eq!(hand_cards(best)[3], cards("AC"));
+
+        let mut played: Vec<Card> = hand_cards(best).concat();
+        played.sort();
+        let mut dealt = hand.clone();
+        dealt.sort();
+        assert_eq!(played, dealt);
+    }
+
+    #[test]
+    fn test_partitions_are_ranked_and_limited() {
+        let hand = cards("3D 3C 4H 8S");
+        let partitions = partition_hand(&hand, &RuleSet::standard(), 10);
+
+        // Pair plus two singles, or four singles
+        assert_eq!(partitions.len(), 2);
+        assert_eq!(partitions[0].cost, 3 * PLAY_COST + 2 * SINGLE_COST);
+        assert_eq!(partitions[1].play_count(), 4);
+        assert!(partitions.windows(2).all(|w| w[0].cost <= w[1].cost));
+
+        assert_eq!(partition_hand(&hand, &RuleSet::standard(), 1).len(), 1);
+        assert!(partition_hand(&[], &RuleSet::standard(), 3)[0]
+            .hands
+            .is_empty());
+    }
+
+    #[test]
+    fn test_ties_keep_the_stronger_single() {
+        let hand = cards("3D 3C 3S");
+        let partitions = partition_hand(&hand, &RuleSet::standard(), 10);
+
+        assert_eq!(hand_cards(&partitions[0]), vec![cards("3D 3C 3S")]);
+        // Of the three pair-plus-single splits, the one leaving the 3S single comes first
+        assert_eq!(
+            hand_cards(&partitions[1]),
+            vec![cards("3D 3C"), cards("3S")]
+        );
+    }
+
+    #[test]
+    fn test_straights_follow_the_rule_set() {
+        let hand = cards("AD 2C 3H 4S 5D");
+        let standard = partition_hand(&hand, &RuleSet::standard(), 1);
+        let hong_kong = partition_hand(&hand, &RuleSet::hong_kong(), 1);
+
+        assert_eq!(standard[0].play_count(), 1);
+        assert_eq!(hong_kong[0].play_count(), 5);
+    }
+}
@@ -2,7 +2,16 @@ use std::fmt;
 use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
 
-use super::hands::HandError;
+/// Why a card code could not be read
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum CardParseError {
+    #[error("A card needs a rank and a suit: '{0}'")]
+    Incomplete(String),
+    #[error("Unknown rank: '{0}'")]
+    UnknownRank(String),
+    #[error("Unknown suit: '{0}'")]
+    UnknownSuit(String),
+}
 
 #[derive(
     Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize, EnumIter,
@@ -44,12 +53,13 @@ impl fmt::Display for Suit {
 impl TryFrom<&str> for Suit {
     type Error = String;
 
+    /// A suit letter in either case, or a suit symbol (filled or outlined)
     fn try_from(s: &str) -> Result<Self, Self::Error> {
         match s {
-            "D" => Ok(Suit::Diamonds),
-            "C" => Ok(Suit::Clubs),
-            "H" => Ok(Suit::Hearts),
-            "S" => Ok(Suit::Spades),
+            "D" | "d" | "♦" | "♢" => Ok(Suit::Diamonds),
+            "C" | "c" | "♣" | "♧" => Ok(Suit::Clubs),
+            "H" | "h" | "♥" | "♡" => Ok(Suit::Hearts),
+            "S" | "s" | "♠" | "♤" => Ok(Suit::Spades),
             _ => Err(s.to_string()),
         }
     }
@@ -101,8 +111,9 @@ impl fmt::Display for Rank {
 impl TryFrom<&str> for Rank {
     type Error = String;
 
+    /// A rank code in either case; ten may be written `T` or `10`
     fn try_from(s: &str) -> Result<Self, Self::Error> {
-        match s {
+        match s.to_ascii_uppercase().as_str() {
             "3" => Ok(Rank::Three),
             "4" => Ok(Rank::Four),
             "5" => Ok(Rank::Five),
@@ -110,7 +121,7 @@ impl TryFrom<&str> for Rank {
             "7" => Ok(Rank::Seven),
             "8" => Ok(Rank::Eight),
             "9" => Ok(Rank::Nine),
-            "T" => Ok(Rank::Ten),
+            "T" | "10" => Ok(Rank::Ten),
             "J" => Ok(Rank::Jack),
             "Q" => Ok(Rank::Queen),
             "K" => Ok(Rank::King),
@@ -133,7 +144,7 @@ impl Ord for Rank {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub struct Card {
     pub suit: Suit,
     pub rank: Rank,
@@ -159,13 +170,16 @@ impl Card {
         Self { suit, rank }
     }
 
-    pub fn from_string(s: &str) -> Result<Self, HandError> {
-        if s.len() != 2 {
-            return Err(HandError::InvalidHandType);
-        }
+    /// Read a card code: a rank then a suit, as in `3D`, `10♦`, `td` or `Q♠`
+    pub fn from_string(s: &str) -> Result<Self, CardParseError> {
+        let s = s.trim();
+        let (rank, suit) = match s.char_indices().last() {
+            Some((index, _)) if index > 0 => s.split_at(index),
+            _ => return Err(CardParseError::Incomplete(s.to_string())),
+        };
 
-        let rank = Rank::try_from(&s[0..1]).map_err(|_| HandError::InvalidHandType)?;
-        let suit = Suit::try_from(&s[1..2]).map_err(|_| HandError::InvalidHandType)?;
+        let rank = Rank::try_from(rank).map_err(CardParseError::UnknownRank)?;
+        let suit = Suit::try_from(suit).map_err(CardParseError::UnknownSuit)?;
 
         Ok(Self::new(rank, suit))
     }
@@ -217,7 +231,26 @@ mod tests {
         assert_eq!(ten_diamonds.rank, Rank::Ten);
         assert_eq!(ten_diamonds.suit, Suit::Diamonds);
 
+        // Alternative spellings
+        assert_eq!(Card::from_string("10D").unwrap(), ten_diamonds);
+        assert_eq!(Card::from_string("td").unwrap(), ten_diamonds);
+        assert_eq!(Card::from_string("10♦").unwrap(), ten_diamonds);
+        assert_eq!(Card::from_string("k♥").unwrap(), king_hearts);
+        assert_eq!(Card::from_string(" 2♠ ").unwrap(), two_spades);
+
         // Test invalid strings
+        assert_eq!(
+            Card::from_string("ZH"),
+            Err(CardParseError::UnknownRank("Z".to_string()))
+        );
+        assert_eq!(
+            Card::from_string("KX"),
+            Err(CardParseError::UnknownSuit("X".to_string()))
+        );
+        assert_eq!(
+            Card::from_string("♦"),
+            Err(CardParseError::Incomplete("♦".to_string()))
+        );
         assert!(Card::from_string("ZH").is_err()); // Invalid rank
         assert!(Card::from_string("KX").is_err()); // Invalid suit
         assert!(Card::from_string("K").is_err()); // Too short
@@ -230,6 +263,9 @@ mod tests {
         assert_eq!(Suit::try_from("C"), Ok(Suit::Clubs));
         assert_eq!(Suit::try_from("H"), Ok(Suit::Hearts));
         assert_eq!(Suit::try_from("S"), Ok(Suit::Spades));
+        assert_eq!(Suit::try_from("d"), Ok(Suit::Diamonds));
+        assert_eq!(Suit::try_from("♣"), Ok(Suit::Clubs));
+        assert_eq!(Suit::try_from("♡"), Ok(Suit::Hearts));
 
         // Test invalid suits
         assert!(Suit::try_from("X").is_err());
@@ -261,6 +297,8 @@ mod tests {
         assert_eq!(Rank::try_from("K"), Ok(Rank::King));
         assert_eq!(Rank::try_from("A"), Ok(Rank::Ace));
         assert_eq!(Rank::try_from("2"), Ok(Rank::Two));
+        assert_eq!(Rank::try_from("10"), Ok(Rank::Ten));
+        assert_eq!(Rank::try_from("q"), Ok(Rank::Queen));
 
         // Test invalid ranks
         assert!(Rank::try_from("1").is_err());
@@ -0,0 +1,270 @@
+use super::basic::{Card, Rank, Suit};
+use super::hands::HandError;
+
+const RANKS: [Rank; 13] = [
+    Rank::Three,
+    Rank::Four,
+    Rank::Five,
+    Rank::Six,
+    Rank::Seven,
+    Rank::Eight,
+    Rank::Nine,
+    Rank::Ten,
+    Rank::Jack,
+    Rank::Queen,
+    Rank::King,
+    Rank::Ace,
+    Rank::Two,
+];
+const SUITS: [Suit; 4] = [Suit::Diamonds, Suit::Clubs, Suit::Hearts, Suit::Spades];
+
+/// Bits of one rank: the four suits of that rank sit next to each other
+const RANK_BITS: u64 = 0b1111;
+/// Bits of one suit: one bit in every rank's group of four
+const SUIT_BITS: u64 = 0x0001_1111_1111_1111;
+
+/// A set of distinct cards stored as a bitmask over the 52-card deck
+///
+/// Bit `rank * 4 + suit` is set when the card is present, so iteration yields cards in
+/// `Card`'s natural order and a card can never appear twice.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct CardSet(u64);
+
+impl CardSet {
+    pub const EMPTY: CardSet = CardSet(0);
+
+    fn bit(card: Card) -> u64 {
+        1 << (card.rank as u8 * 4 + card.suit as u8)
+    }
+
+    fn card_at(index: u32) -> Card {
+        Card::new(RANKS[(index / 4) as usize], SUITS[(index % 4) as usize])
+    }
+
+    /// Build a set from cards that must all be different
+    pub fn try_from_cards(cards: &[Card]) -> Result<Self, HandError> {
+        let mut set = Self::EMPTY;
+        for card in cards {
+            if !set.insert(*card) {
+                return Err(HandError::DuplicateCard(*card));
+            }
+        }
+        Ok(set)
+    }
+
+    /// Add a card, returning false if it was already present
+    pub fn insert(&mut self, card: Card) -> bool {
+        let bit = Self::bit(card);
+        let added = self.0 & bit == 0;
+        self.0 |= bit;
+        added
+    }
+
+    /// Remove a card, returning false if it was not present
+    #[allow(dead_code)] // Public API for hand analysis and bots
+    pub fn remove(&mut self, card: Card) -> bool {
+        let bit = Self::bit(card);
+        let removed = self.0 & bit != 0;
+        self.0 &= !bit;
+        removed
+    }
+
+    pub fn contains(&self, card: Card) -> bool {
+        self.0 & Self::bit(card) != 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn is_subset(&self, other: &CardSet) -> bool {
+        self.0 & !other.0 == 0
+    }
+
+    #[allow(dead_code)] // Public API for hand analysis and bots
+    pub fn union(&self, other: &CardSet) -> CardSet {
+        CardSet(self.0 | other.0)
+    }
+
+    pub fn difference(&self, other: &CardSet) -> CardSet {
+        CardSet(self.0 & !other.0)
+    }
+
+    /// The cards of one rank in this set
+    pub fn of_rank(&self, rank: Rank) -> CardSet {
+        CardSet(self.0 & (RANK_BITS << (rank as u8 * 4)))
+    }
+
+    /// The cards of one suit in this set
+    pub fn of_suit(&self, suit: Suit) -> CardSet {
+        CardSet(self.0 & (SUIT_BITS << suit as u8))
+    }
+
+    pub fn rank_count(&self, rank: Rank) -> usize {
+        self.of_rank(rank).len()
+    }
+
+    pub fn suit_count(&self, suit: Suit) -> usize {
+        self.of_suit(suit).len()
+    }
+
+    /// Whether every card shares one suit
+    pub fn is_single_suit(&self) -> bool {
+        !self.is_empty()
+            && SUITS
+                .into_iter()
+                .any(|suit| self.suit_count(suit) == self.len())
+    }
+
+    /// Rank-count profile: how many ranks appear exactly once, twice, three and four times
+    ///
+    /// A full house is `[0, 1, 1, 0]`, four of a kind plus a kicker `[1, 0, 0, 1]`.
+    pub fn rank_profile(&self) -> [u8; 4] {
+        let mut profile = [0; 4];
+        for rank in RANKS {
+            let count = self.rank_count(rank);
+            if count > 0 {
+                profile[count - 1] += 1;
+            }
+        }
+        profile
+    }
+
+    /// The rank held exactly `count` times, if there is one
+    pub fn rank_with_count(&self, count: usize) -> Option<Rank> {
+        RANKS
+            .into_iter()
+            .find(|rank| self.rank_count(*rank) == count)
+    }
+
+    /// Highest card by the standard order, if any
+    #[allow(dead_code)] // Public API for hand analysis and bots
+    pub fn highest(&self) -> Option<Card> {
+        (self.0 != 0).then(|| Self::card_at(63 - self.0.leading_zeros()))
+    }
+
+    /// Lowest card by the standard order, if any
+    pub fn lowest(&self) -> Option<Card> {
+        (self.0 != 0).then(|| Self::card_at(self.0.trailing_zeros()))
+    }
+
+    /// Cards lowest first
+    pub fn iter(&self) -> impl Iterator<Item = Card> {
+        let mut bits = self.0;
+        std::iter::from_fn(move || {
+            if bits == 0 {
+                return None;
+            }
+            let index = bits.trailing_zeros();
+            bits &= bits - 1;
+            Some(Self::card_at(index))
+        })
+    }
+
+    pub fn to_vec(self) -> Vec<Card> {
+        self.iter().collect()
+    }
+
+    /// Every `size`-card subset, in the same order as sorted lexicographic combinations
+    pub fn subsets(&self, size: usize) -> Vec<CardSet> {
+        let cards: Vec<u64> = self.iter().map(Self::bit).collect();
+        let mut result = Vec::new();
+        if size == 0 || size > cards.len() {
+            return result;
+        }
+        Self::build_subsets(&cards, size, 0, 0, &mut result);
+        result
+    }
+
+    fn build_subsets(
+        cards: &[u64],
+        remaining: usize,
+        start: usize,
+        current: u64,
+        result: &mut Vec<CardSet>,
+    ) {
+        if remaining == 0 {
+            result.push(CardSet(current));
+            return;
+        }
+        for i in start..=cards.len() - remaining {
+            Self::build_subsets(cards, remaining - 1, i + 1, current | cards[i], result);
+        }
+    }
+}
+
+impl FromIterator<Card> for CardSet {
+    /// Collect cards into a set; repeated cards are kept once
+    fn from_iter<I: IntoIterator<Item = Card>>(iter: I) -> Self {
+        let mut set = CardSet::EMPTY;
+        for card in iter {
+            set.insert(card);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::test_utils::cards;
+
+    #[test]
+    fn test_insert_remove_and_order() {
+        let mut set: CardSet = cards("2S 3D KH 3C").into_iter().collect();
+        assert_eq!(set.len(), 4);
+        assert_eq!(set.to_vec(), cards("3D 3C KH 2S"));
+        assert_eq!(set.lowest(), Some(Card::from_string("3D").unwrap()));
+        assert_eq!(set.highest(), Some(Card::from_string("2S").unwrap()));
+
+        assert!(!set.insert(Card::from_string("KH").unwrap()));
+        assert!(set.remove(Card::from_string("KH").unwrap()));
+        assert!(!set.remove(Card::from_string("KH").unwrap()));
+        assert_eq!(set.len(), 3);
+        assert_eq!(CardSet::EMPTY.highest(), None);
+    }
+
+    #[test]
+    fn test_duplicates_rejected() {
+        assert_eq!(
+            CardSet::try_from_cards(&cards("3D 3D")),
+            Err(HandError::DuplicateCard(Card::from_string("3D").unwrap()))
+        );
+        assert_eq!(CardSet::try_from_cards(&cards("3D 3C")).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_rank_and_suit_counts() {
+        let full_house = CardSet::try_from_cards(&cards("5D 5C 5S 9H 9S")).unwrap();
+        assert_eq!(full_house.rank_count(Rank::Five), 3);
+        assert_eq!(full_house.suit_count(Suit::Spades), 2);
+        assert_eq!(full_house.rank_profile(), [0, 1, 1, 0]);
+        assert_eq!(full_house.rank_with_count(3), Some(Rank::Five));
+        assert!(!full_house.is_single_suit());
+
+        let flush = CardSet::try_from_cards(&cards("3H 7H 9H JH 2H")).unwrap();
+        assert!(flush.is_single_suit());
+        assert_eq!(flush.of_suit(Suit::Hearts), flush);
+        assert_eq!(flush.of_rank(Rank::Two).len(), 1);
+    }
+
+    #[test]
+    fn test_subsets_and_set_ops() {
+        let hand = CardSet::try_from_cards(&cards("3D 4C 5H 6S")).unwrap();
+        assert_eq!(hand.subsets(2).len(), 6);
+        assert_eq!(hand.subsets(4), vec![hand]);
+        assert!(hand.subsets(5).is_empty());
+        assert!(hand.subsets(0).is_empty());
+        assert_eq!(hand.subsets(2)[0].to_vec(), cards("3D 4C"));
+
+        let low = CardSet::try_from_cards(&cards("3D 4C")).unwrap();
+        assert!(low.is_subset(&hand));
+        assert!(!hand.is_subset(&low));
+        assert_eq!(hand.difference(&low).to_vec(), cards("5H 6S"));
+        assert_eq!(low.union(&hand.difference(&low)), hand);
+    }
+}
@@ -1,6 +1,8 @@
 use thiserror::Error;
 
 use super::basic::{Card, Rank, Suit};
+use super::card_set::CardSet;
+use super::rules::{FlushOrder, RuleSet};
 
 #[derive(Debug, Clone, PartialEq, Eq, Error)]
 pub enum HandError {
@@ -8,6 +10,8 @@ pub enum HandError {
     InvalidHandSize,
     #[error("Invalid hand type")]
     InvalidHandType,
+    #[error("Card played more than once: {0}")]
+    DuplicateCard(Card),
     #[error("Cards not sorted")]
     #[allow(dead_code)] // Error variant for validation
     CardsNotSorted,
@@ -210,12 +214,12 @@ pub struct FullHouseHand {
 
 impl FullHouseHand {
     pub fn new(cards: Vec<Card>) -> Self {
-        let rank_counts = Self::count_ranks(&cards);
-        let triple_rank = *rank_counts
+        let triple_rank = cards
             .iter()
-            .find(|(_, &count)| count == 3)
-            .expect("FullHouseHand should have exactly one rank with 3 cards")
-            .0;
+            .copied()
+            .collect::<CardSet>()
+            .rank_with_count(3)
+            .expect("FullHouseHand should have exactly one rank with 3 cards");
         let high_card = *cards
             .iter()
             .max()
@@ -226,14 +230,6 @@ impl FullHouseHand {
             high_card,
         }
     }
-
-    fn count_ranks(cards: &[Card]) -> std::collections::HashMap<Rank, usize> {
-        let mut counts = std::collections::HashMap::new();
-        for card in cards {
-            *counts.entry(card.rank).or_insert(0) += 1;
-        }
-        counts
-    }
 }
 
 impl PartialOrd for FullHouseHand {
@@ -258,12 +254,12 @@ pub struct FourOfAKindHand {
 
 impl FourOfAKindHand {
     pub fn new(cards: Vec<Card>) -> Self {
-        let rank_counts = Self::count_ranks(&cards);
-        let quad_rank = *rank_counts
+        let quad_rank = cards
             .iter()
-            .find(|(_, &count)| count == 4)
-            .expect("FourOfAKindHand should have exactly one rank with 4 cards")
-            .0;
+            .copied()
+            .collect::<CardSet>()
+            .rank_with_count(4)
+            .expect("FourOfAKindHand should have exactly one rank with 4 cards");
         let high_card = *cards
             .iter()
             .max()
@@ -274,14 +270,6 @@ impl FourOfAKindHand {
             high_card,
         }
     }
-
-    fn count_ranks(cards: &[Card]) -> std::collections::HashMap<Rank, usize> {
-        let mut counts = std::collections::HashMap::new();
-        for card in cards {
-            *counts.entry(card.rank).or_insert(0) += 1;
-        }
-        counts
-    }
 }
 
 impl PartialOrd for FourOfAKindHand {
@@ -345,34 +333,40 @@ pub enum FiveCardHand {
 }
 
 impl FiveCardHand {
+    #[allow(dead_code)] // Public API, standard rules
     pub fn new(cards: &[Card]) -> Result<Self, HandError> {
+        Self::new_with_rules(cards, &RuleSet::standard())
+    }
+
+    /// Classify five cards, using the rule set to decide which straights are legal
+    pub fn new_with_rules(cards: &[Card], rules: &RuleSet) -> Result<Self, HandError> {
         if cards.len() != 5 {
             return Err(HandError::InvalidHandSize);
         }
 
-        let mut cards = cards.to_owned();
-        cards.sort();
-        Self::classify_hand(cards)
+        let set = CardSet::try_from_cards(cards)?;
+        Self::classify_hand(set, rules)
     }
 
-    fn classify_hand(cards: Vec<Card>) -> Result<Self, HandError> {
-        let is_flush = cards.iter().all(|card| card.suit == cards[0].suit);
-        let is_straight = Self::is_straight(&cards);
+    /// Classify five distinct cards from their rank and suit counts
+    pub(crate) fn classify_hand(set: CardSet, rules: &RuleSet) -> Result<Self, HandError> {
+        let cards = set.to_vec();
+        let is_flush = set.is_single_suit();
+        let profile = set.rank_profile();
+        let is_straight = profile == [5, 0, 0, 0] && {
+            let ranks: Vec<Rank> = cards.iter().map(|c| c.rank).collect();
+            rules.straight_tier(&ranks).is_some()
+        };
 
         if is_straight && is_flush {
             return Ok(FiveCardHand::StraightFlush(StraightFlushHand::new(cards)));
         }
 
-        // Check for four of a kind
-        let rank_counts = Self::count_ranks(&cards);
-        if rank_counts.values().any(|&count| count == 4) {
+        if profile == [1, 0, 0, 1] {
             return Ok(FiveCardHand::FourOfAKind(FourOfAKindHand::new(cards)));
         }
 
-        // Check for full house
-        let mut counts: Vec<_> = rank_counts.values().collect();
-        counts.sort();
-        if counts == vec![&2, &3] {
+        if profile == [0, 1, 1, 0] {
             return Ok(FiveCardHand::FullHouse(FullHouseHand::new(cards)));
         }
 
@@ -387,49 +381,71 @@ impl FiveCardHand {
         Err(HandError::InvalidHandType)
     }
 
-    fn is_straight(cards: &[Card]) -> bool {
-        // Extract and sort ranks
-        let mut ranks: Vec<Rank> = cards.iter().map(|c| c.rank).collect();
-        ranks.sort();
-
-        // Special case 1: Ace-low straight (A-2-3-4-5)
-        // Ace acts as value 1, Two acts as value 2
-        if ranks == vec![Rank::Three, Rank::Four, Rank::Five, Rank::Ace, Rank::Two] {
-            return true;
-        }
-
-        // Special case 2: Ace-high straight (10-J-Q-K-A)
-        // Ace acts as value 14 (after King)
-        if ranks == vec![Rank::Ten, Rank::Jack, Rank::Queen, Rank::King, Rank::Ace] {
-            return true;
-        }
-
-        // Normal consecutive straights: 3-4-5-6-7 through 9-10-J-Q-K
-        // These should be consecutive in rank value and not contain Ace or Two
-        // (because Ace and Two have special positioning rules)
-        if ranks.contains(&Rank::Ace) || ranks.contains(&Rank::Two) {
-            return false; // Any other combination with Ace or Two is invalid
-        }
-
-        // Check if remaining ranks are consecutive
-        for i in 1..ranks.len() {
-            let prev_rank_value = ranks[i - 1] as u8;
-            let curr_rank_value = ranks[i] as u8;
+    /// (tier, deciding rank, deciding suit) used to order straights under a rule set
+    fn straight_key(cards: &[Card], rules: &RuleSet) -> (u8, Rank, u8) {
+        let ranks: Vec<Rank> = cards.iter().map(|c| c.rank).collect();
+        let (tier, rank) = rules
+            .straight_tier(&ranks)
+            .unwrap_or_else(|| (2, rules.highest_card(cards).rank));
+        let suit = cards
+            .iter()
+            .find(|c| c.rank == rank)
+            .map(|c| rules.suit_value(c.suit))
+            .unwrap_or(0);
+        (tier, rank, suit)
+    }
 
-            if curr_rank_value != prev_rank_value + 1 {
-                return false;
+    fn compare_flush_cards(a: &[Card], b: &[Card], rules: &RuleSet) -> std::cmp::Ordering {
+        let suit_cmp = rules
+            .suit_value(a[0].suit)
+            .cmp(&rules.suit_value(b[0].suit));
+
+        match rules.flush_order {
+            FlushOrder::SuitFirst => suit_cmp
+                .then_with(|| rules.compare_cards(&rules.highest_card(a), &rules.highest_card(b))),
+            FlushOrder::HighCardFirst => {
+                let mut a_ranks: Vec<Rank> = a.iter().map(|c| c.rank).collect();
+                let mut b_ranks: Vec<Rank> = b.iter().map(|c| c.rank).collect();
+                a_ranks.sort_by(|x, y| y.cmp(x));
+                b_ranks.sort_by(|x, y| y.cmp(x));
+                a_ranks.cmp(&b_ranks).then(suit_cmp)
             }
         }
-
-        true
     }
 
-    fn count_ranks(cards: &[Card]) -> std::collections::HashMap<Rank, usize> {
-        let mut counts = std::collections::HashMap::new();
-        for card in cards {
-            *counts.entry(card.rank).or_insert(0) += 1;
+    /// Compare two five-card hands under a rule set
+    pub fn cmp_with_rules(&self, other: &Self, rules: &RuleSet) -> std::cmp::Ordering {
+        match self.hand_type_value().cmp(&other.hand_type_value()) {
+            std::cmp::Ordering::Equal => match (self, other) {
+                (FiveCardHand::Straight(a), FiveCardHand::Straight(b)) => {
+                    Self::straight_key(&a.cards, rules).cmp(&Self::straight_key(&b.cards, rules))
+                }
+                (FiveCardHand::Flush(a), FiveCardHand::Flush(b)) => {
+                    Self::compare_flush_cards(&a.cards, &b.cards, rules)
+                }
+                (FiveCardHand::FullHouse(a), FiveCardHand::FullHouse(b)) => a.cmp(b),
+                (FiveCardHand::FourOfAKind(a), FiveCardHand::FourOfAKind(b)) => a.cmp(b),
+                (FiveCardHand::StraightFlush(a), FiveCardHand::StraightFlush(b)) => {
+                    let suit_cmp = rules.suit_value(a.suit).cmp(&rules.suit_value(b.suit));
+                    let straight_cmp = Self::straight_key(&a.cards, rules)
+                        .cmp(&Self::straight_key(&b.cards, rules));
+                    match rules.flush_order {
+                        FlushOrder::SuitFirst => suit_cmp.then(straight_cmp),
+                        FlushOrder::HighCardFirst => straight_cmp.then(suit_cmp),
+                    }
+                }
+                _ => unreachable!("Same hand type value but different variants"),
+            },
+            other => other,
         }
-        counts
+    }
+
+    /// Four of a kind or straight flush
+    pub fn is_bomb(&self) -> bool {
+        matches!(
+            self,
+            FiveCardHand::FourOfAKind(_) | FiveCardHand::StraightFlush(_)
+        )
     }
 
     pub fn hand_type_value(&self) -> u8 {
@@ -451,20 +467,7 @@ impl PartialOrd for FiveCardHand {
 
 impl Ord for FiveCardHand {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        match self.hand_type_value().cmp(&other.hand_type_value()) {
-            std::cmp::Ordering::Equal => {
-                // Same hand type - delegate to specific type comparison
-                match (self, other) {
-                    (FiveCardHand::Straight(a), FiveCardHand::Straight(b)) => a.cmp(b),
-                    (FiveCardHand::Flush(a), FiveCardHand::Flush(b)) => a.cmp(b),
-                    (FiveCardHand::FullHouse(a), FiveCardHand::FullHouse(b)) => a.cmp(b),
-                    (FiveCardHand::FourOfAKind(a), FiveCardHand::FourOfAKind(b)) => a.cmp(b),
-                    (FiveCardHand::StraightFlush(a), FiveCardHand::StraightFlush(b)) => a.cmp(b),
-                    _ => unreachable!("Same hand type value but different variants"),
-                }
-            }
-            other => other,
-        }
+        self.cmp_with_rules(other, &RuleSet::standard())
     }
 }
 
@@ -478,28 +481,68 @@ pub enum Hand {
 }
 
 impl Hand {
-    /// Create a hand from a vector of cards
+    /// Create a hand from a vector of cards (standard rules)
     pub fn from_cards(cards: &[Card]) -> Result<Self, HandError> {
+        Self::from_cards_with_rules(cards, &RuleSet::standard())
+    }
+
+    /// Create a hand from a vector of cards under a specific rule set
+    pub fn from_cards_with_rules(cards: &[Card], rules: &RuleSet) -> Result<Self, HandError> {
+        CardSet::try_from_cards(cards)?;
         match cards.len() {
             0 => Ok(Hand::Pass),
             1 => Ok(Hand::Single(SingleHand::new(cards[0]))),
             2 => Ok(Hand::Pair(PairHand::new(cards[0], cards[1])?)),
             3 => Ok(Hand::Triple(TripleHand::new(cards[0], cards[1], cards[2])?)),
-            5 => Ok(Hand::Five(FiveCardHand::new(cards)?)),
+            5 => Ok(Hand::Five(FiveCardHand::new_with_rules(cards, rules)?)),
             _ => Err(HandError::InvalidHandSize),
         }
     }
 
-    /// Check if this hand can beat another hand according to Big Two rules
-    /// Only hands of the same type can be compared (except all 5-card combos can compete)
+    /// Check if this hand can beat another hand according to Big Two rules (standard rules)
+    /// Under the standard rules only hands of the same size compare (all 5-card combos compete);
+    /// see `can_beat_with_rules` for bombs and other cross-type plays
     pub fn can_beat(&self, other: &Hand) -> bool {
+        self.can_beat_with_rules(other, &RuleSet::standard())
+    }
+
+    /// Check if this hand can beat another hand under a specific rule set
+    ///
+    /// Hands of the same size compare as usual. A five-card hand may also beat a smaller one:
+    /// any bomb when `bombs` is on, and four of a kind against a lone Two when
+    /// `quad_beats_single_two` is on. Every other pairing of types cannot beat.
+    pub fn can_beat_with_rules(&self, other: &Hand, rules: &RuleSet) -> bool {
+        // Pairs and triples: rank first, then the highest suit in the set
+        let set_key = |rank: Rank, cards: &[Card]| {
+            let high_suit = cards
+                .iter()
+                .map(|c| rules.suit_value(c.suit))
+                .max()
+                .unwrap_or(0);
+            (rank, high_suit)
+        };
+
         match (self, other) {
             (Hand::Pass, _) => true,
             (_, Hand::Pass) => false,
-            (Hand::Single(a), Hand::Single(b)) => a > b,
-            (Hand::Pair(a), Hand::Pair(b)) => a > b,
-            (Hand::Triple(a), Hand::Triple(b)) => a > b,
-            (Hand::Five(a), Hand::Five(b)) => a > b,
+            (Hand::Single(a), Hand::Single(b)) => {
+                rules.compare_cards(&a.card, &b.card) == std::cmp::Ordering::Greater
+            }
+            (Hand::Pair(a), Hand::Pair(b)) => set_key(a.rank, &a.cards) > set_key(b.rank, &b.cards),
+            (Hand::Triple(a), Hand::Triple(b)) => {
+                set_key(a.rank, &a.cards) > set_key(b.rank, &b.cards)
+            }
+            (Hand::Five(a), Hand::Five(b)) => {
+                a.cmp_with_rules(b, rules) == std::cmp::Ordering::Greater
+            }
+            (Hand::Five(FiveCardHand::FourOfAKind(_)), Hand::Single(b))
+                if rules.quad_beats_single_two && b.card.rank == Rank::Two =>
+            {
+                true
+            }
+            (Hand::Five(a), Hand::Single(_) | Hand::Pair(_) | Hand::Triple(_)) => {
+                rules.bombs && a.is_bomb()
+            }
             _ => false, // Cannot compare across different hand types
         }
     }
@@ -572,6 +615,7 @@ pub fn compare_played_cards(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::shared::test_utils::cards;
     use rstest::rstest;
 
     #[test]
@@ -1011,4 +1055,64 @@ mod tests {
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), expected);
     }
+
+    fn hand(codes: &str, rules: &RuleSet) -> Result<Hand, HandError> {
+        Hand::from_cards_with_rules(&cards(codes), rules)
+    }
+
+    #[rstest]
+    #[case::standard_wheel(RuleSet::standard(), "AH 2S 3D 4C 5H", true)]
+    #[case::standard_two_low(RuleSet::standard(), "2S 3D 4C 5H 6H", false)]
+    #[case::taiwanese_two_low(RuleSet::taiwanese(), "2S 3D 4C 5H 6H", true)]
+    #[case::pusoy_wheel(RuleSet::pusoy_dos(), "AH 2S 3D 4C 5H", true)]
+    #[case::hong_kong_wheel(RuleSet::hong_kong(), "AH 2S 3D 4C 5H", false)]
+    #[case::hong_kong_ace_high(RuleSet::hong_kong(), "TH JS QD KC AH", true)]
+    fn test_straight_legality_by_rule_set(
+        #[case] rules: RuleSet,
+        #[case] cards: &str,
+        #[case] legal: bool,
+    ) {
+        assert_eq!(hand(cards, &rules).is_ok(), legal);
+    }
+
+    #[rstest]
+    // Standard: A-2-3-4-5 sits above 10-J-Q-K-A
+    #[case(RuleSet::standard(), "AH 2S 3D 4C 5H", "TH JS QD KC AD", true)]
+    // Pusoy Dos: A-2-3-4-5 is the lowest straight
+    #[case(RuleSet::pusoy_dos(), "AH 2S 3D 4C 5H", "3H 4S 5D 6C 7D", false)]
+    #[case(RuleSet::pusoy_dos(), "3H 4S 5D 6C 7D", "AH 2S 3D 4C 5H", true)]
+    // Taiwanese: 2-3-4-5-6 beats A-2-3-4-5
+    #[case(RuleSet::taiwanese(), "2D 3D 4C 5H 6H", "AH 2S 3S 4C 5H", true)]
+    // Suit-first flushes: any spade flush beats a heart flush
+    #[case(RuleSet::standard(), "3S 5S 7S 9S JS", "4H 6H 8H TH AH", true)]
+    // High-card-first flushes: the ace-high heart flush wins
+    #[case(RuleSet::taiwanese(), "3S 5S 7S 9S JS", "4H 6H 8H TH AH", false)]
+    #[case(RuleSet::taiwanese(), "4H 6H 8H TH AH", "3S 5S 7S 9S JS", true)]
+    // Pusoy Dos suit order: diamonds are the highest suit
+    #[case(RuleSet::pusoy_dos(), "3D", "3S", true)]
+    #[case(RuleSet::standard(), "3D", "3S", false)]
+    #[case(RuleSet::pusoy_dos(), "KD KC", "KS KH", true)]
+    // Bombs: quads and straight flushes beat singles, pairs and triples
+    #[case(RuleSet::standard(), "5H 5S 5D 5C 3D", "2S", false)]
+    #[case(RuleSet::standard().with_bombs(), "5H 5S 5D 5C 3D", "2S", true)]
+    #[case(RuleSet::standard().with_bombs(), "3H 4H 5H 6H 7H", "2S 2H", true)]
+    #[case(RuleSet::standard().with_bombs(), "5H 5S 5D 5C 3D", "AS AH AD", true)]
+    #[case(RuleSet::standard().with_bombs(), "3H 4H 5H 6D 7H", "4S", false)]
+    #[case(RuleSet::standard().with_bombs(), "KH KS KD 3C 3D", "4S", false)]
+    // Bombs are still five-card hands when compared with each other
+    #[case(RuleSet::standard().with_bombs(), "5H 5S 5D 5C 3D", "3H 4H 5H 6H 7H", false)]
+    // Quad over a lone Two only
+    #[case(RuleSet { quad_beats_single_two: true, ..RuleSet::standard() }, "5H 5S 5D 5C 3D", "2S", true)]
+    #[case(RuleSet { quad_beats_single_two: true, ..RuleSet::standard() }, "5H 5S 5D 5C 3D", "AS", false)]
+    #[case(RuleSet { quad_beats_single_two: true, ..RuleSet::standard() }, "3H 4H 5H 6H 7H", "2S", false)]
+    fn test_can_beat_by_rule_set(
+        #[case] rules: RuleSet,
+        #[case] played: &str,
+        #[case] current: &str,
+        #[case] expected: bool,
+    ) {
+        let played = hand(played, &rules).unwrap();
+        let current = hand(current, &rules).unwrap();
+        assert_eq!(played.can_beat_with_rules(&current, &rules), expected);
+    }
 }
@@ -1,5 +1,13 @@
+pub mod analysis;
 pub mod basic;
+pub mod card_set;
 pub mod hands;
+pub mod parse;
+pub mod rules;
 
-pub use basic::{Card, Rank, Suit};
+pub use analysis::{partition_hand, HandPartition};
+pub use basic::{Card, CardParseError, Rank, Suit};
+pub use card_set::CardSet;
 pub use hands::{Hand, HandError, SingleHand};
+pub use parse::{parse_play, PlayParseError};
+pub use rules::RuleSet;
@@ -0,0 +1,202 @@
+// Reading plays typed by people: card codes in any of the spellings `Card::from_string` accepts,
+// or a short description such as "pair of 7s" that is resolved against the player's hand.
+use super::basic::{Card, CardParseError, Rank};
+use super::rules::RuleSet;
+
+/// Written instead of cards to pass
+const PASS: &str = "pass";
+
+/// Why a typed play could not be read
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum PlayParseError {
+    #[error("Invalid card '{token}': {error}")]
+    InvalidCard {
+        token: String,
+        error: CardParseError,
+    },
+    #[error("Unknown rank: '{0}'")]
+    UnknownRank(String),
+    #[error("Asked for {wanted} of rank {rank} but only {held} held")]
+    NotEnoughCards {
+        rank: Rank,
+        wanted: usize,
+        held: usize,
+    },
+}
+
+impl PlayParseError {
+    /// Stable, machine-readable code for clients (one per variant)
+    pub fn code(&self) -> &'static str {
+        match self {
+            PlayParseError::InvalidCard { .. } => "INVALID_CARD",
+            PlayParseError::UnknownRank(_) => "UNKNOWN_RANK",
+            PlayParseError::NotEnoughCards { .. } => "NOT_ENOUGH_CARDS",
+        }
+    }
+}
+
+/// Read a typed play into the cards it names
+///
+/// Accepts `pass` (or nothing), card codes separated by spaces or commas (`3D 3C 3S`,
+/// `10♦, J♦`), or `single`, `pair` or `triple` of a rank (`pair of 7s`, `triple of kings`).
+/// A description picks the player's lowest cards of that rank under `rules`.
+pub fn parse_play(text: &str, hand: &[Card], rules: &RuleSet) -> Result<Vec<Card>, PlayParseError> {
+    let text = text.trim().to_lowercase();
+    if text.is_empty() || text == PASS {
+        return Ok(Vec::new());
+    }
+
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let wanted = match words[0] {
+        "single" => Some(1),
+        "pair" => Some(2),
+        "triple" | "trips" => Some(3),
+        _ => None,
+    };
+    if let Some(wanted) = wanted {
+        let rank_word = match &words[1..] {
+            ["of", rank] | [rank] => *rank,
+            rest => return Err(PlayParseError::UnknownRank(rest.join(" "))),
+        };
+        let rank = parse_rank_word(rank_word)?;
+        return lowest_of_rank(hand, rank, wanted, rules);
+    }
+
+    text.split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|token| !token.is_empty())
+        .map(|token| {
+            Card::from_string(token).map_err(|error| PlayParseError::InvalidCard {
+                token: token.to_string(),
+                error,
+            })
+        })
+        .collect()
+}
+
+/// A rank as a code or an English name, singular or plural: `7`, `7s`, `tens`, `sixes`, `k`
+fn parse_rank_word(word: &str) -> Result<Rank, PlayParseError> {
+    let singular = [
+        Some(word),
+        word.strip_suffix("es"),
+        word.strip_suffix("'s"),
+        word.strip_suffix('s'),
+    ];
+
+    singular
+        .into_iter()
+        .flatten()
+        .find_map(|candidate| {
+            Rank::try_from(candidate).ok().or(match candidate {
+                "three" => Some(Rank::Three),
+                "four" => Some(Rank::Four),
+                "five" => Some(Rank::Five),
+                "six" => Some(Rank::Six),
+                "seven" => Some(Rank::Seven),
+                "eight" => Some(Rank::Eight),
+                "nine" => Some(Rank::Nine),
+                "ten" => Some(Rank::Ten),
+                "jack" => Some(Rank::Jack),
+                "queen" => Some(Rank::Queen),
+                "king" => Some(Rank::King),
+                "ace" => Some(Rank::Ace),
+                "two" | "deuce" => Some(Rank::Two),
+                _ => None,
+            })
+        })
+        .ok_or_else(|| PlayParseError::UnknownRank(word.to_string()))
+}
+
+fn lowest_of_rank(
+    hand: &[Card],
+    rank: Rank,
+    wanted: usize,
+    rules: &RuleSet,
+) -> Result<Vec<Card>, PlayParseError> {
+    let mut held: Vec<Card> = hand.iter().filter(|c| c.rank == rank).copied().collect();
+    if held.len() < wanted {
+        return Err(PlayParseError::NotEnoughCards {
+            rank,
+            wanted,
+            held: held.len(),
+        });
+    }
+
+    held.sort_by(|a, b| rules.compare_cards(a, b));
+    held.truncate(wanted);
+    Ok(held)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::test_utils::cards;
+
+    #[test]
+    fn test_card_codes() {
+        let rules = RuleSet::standard();
+        assert_eq!(
+            parse_play("3D 3c, 10♠", &[], &rules).unwrap(),
+            cards("3D 3C TS")
+        );
+        assert_eq!(parse_play("  PASS ", &[], &rules).unwrap(), vec![]);
+        assert_eq!(parse_play("", &[], &rules).unwrap(), vec![]);
+        assert_eq!(
+            parse_play("3D 3Z", &[], &rules),
+            Err(PlayParseError::InvalidCard {
+                token: "3z".to_string(),
+                error: CardParseError::UnknownSuit("z".to_string()),
+            })
+        );
+    }
+
+    #[test]
+    fn test_descriptions_resolve_against_the_hand() {
+        let hand = cards("3D 7S 7D 7H KC KD 2S");
+        let rules = RuleSet::standard();
+
+        assert_eq!(
+            parse_play("pair of 7s", &hand, &rules).unwrap(),
+            cards("7D 7H")
+        );
+        assert_eq!(
+            parse_play("Triple of sevens", &hand, &rules).unwrap(),
+            cards("7D 7H 7S")
+        );
+        assert_eq!(
+            parse_play("pair kings", &hand, &rules).unwrap(),
+            cards("KD KC")
+        );
+        assert_eq!(parse_play("single 2", &hand, &rules).unwrap(), cards("2S"));
+
+        // Lowest suits under the room's rules, here Pusoy Dos where diamonds rank highest
+        assert_eq!(
+            parse_play("pair of 7s", &hand, &RuleSet::pusoy_dos()).unwrap(),
+            cards("7S 7H")
+        );
+    }
+
+    #[test]
+    fn test_description_errors() {
+        let hand = cards("3D 7S KC");
+        let rules = RuleSet::standard();
+
+        assert_eq!(
+            parse_play("pair of 7s", &hand, &rules),
+            Err(PlayParseError::NotEnoughCards {
+                rank: Rank::Seven,
+                wanted: 2,
+                held: 1,
+            })
+        );
+        assert_eq!(
+            parse_play("pair of jokers", &hand, &rules),
+            Err(PlayParseError::UnknownRank("jokers".to_string()))
+        );
+        assert_eq!(
+            parse_play("pair of big 7s", &hand, &rules)
+                .unwrap_err()
+                .code(),
+            "UNKNOWN_RANK"
+        );
+    }
+}
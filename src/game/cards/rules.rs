@@ -0,0 +1,248 @@
+use std::cmp::Ordering;
+
+use serde::{Deserialize, Serialize};
+
+use super::basic::{Card, Rank, Suit};
+
+/// Where a straight containing an Ace or Two sits among the other straights
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StraightRule {
+    /// The combination is not a straight
+    Illegal,
+    /// Legal and ranks below 3-4-5-6-7
+    Lowest,
+    /// Legal and ranks above 10-J-Q-K-A
+    Highest,
+}
+
+/// How two flushes (and two straight flushes) are compared
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FlushOrder {
+    /// Compare suits first, then the highest card
+    SuitFirst,
+    /// Compare card ranks from the top down, then the suit
+    HighCardFirst,
+}
+
+/// Ordering rules for a single game, covering the common Big Two variants
+///
+/// Fields left out when deserializing keep their standard value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RuleSet {
+    /// Suits from lowest to highest
+    pub suit_order: [Suit; 4],
+    /// A-2-3-4-5
+    pub ace_low_straight: StraightRule,
+    /// 2-3-4-5-6
+    pub two_low_straight: StraightRule,
+    pub flush_order: FlushOrder,
+    /// Four of a kind and straight flushes beat any single, pair or triple
+    pub bombs: bool,
+    /// Four of a kind beats a lone Two, even when bombs are off
+    pub quad_beats_single_two: bool,
+}
+
+impl Default for RuleSet {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+impl RuleSet {
+    /// The rules this server has always used: ♦ < ♣ < ♥ < ♠, A-2-3-4-5 is the top straight
+    pub fn standard() -> Self {
+        Self {
+            suit_order: [Suit::Diamonds, Suit::Clubs, Suit::Hearts, Suit::Spades],
+            ace_low_straight: StraightRule::Highest,
+            two_low_straight: StraightRule::Illegal,
+            flush_order: FlushOrder::SuitFirst,
+            bombs: false,
+            quad_beats_single_two: false,
+        }
+    }
+
+    /// Pusoy Dos: ♣ < ♠ < ♥ < ♦, A-2-3-4-5 is the bottom straight, flushes by high card
+    #[allow(dead_code)] // Public API for rule selection
+    pub fn pusoy_dos() -> Self {
+        Self {
+            suit_order: [Suit::Clubs, Suit::Spades, Suit::Hearts, Suit::Diamonds],
+            ace_low_straight: StraightRule::Lowest,
+            two_low_straight: StraightRule::Illegal,
+            flush_order: FlushOrder::HighCardFirst,
+            bombs: false,
+            quad_beats_single_two: false,
+        }
+    }
+
+    /// Taiwanese: 2-3-4-5-6 is the top straight with A-2-3-4-5 just below it
+    #[allow(dead_code)] // Public API for rule selection
+    pub fn taiwanese() -> Self {
+        Self {
+            suit_order: [Suit::Diamonds, Suit::Clubs, Suit::Hearts, Suit::Spades],
+            ace_low_straight: StraightRule::Highest,
+            two_low_straight: StraightRule::Highest,
+            flush_order: FlushOrder::HighCardFirst,
+            bombs: false,
+            quad_beats_single_two: false,
+        }
+    }
+
+    /// Hong Kong: straights may not wrap through the Two at all
+    #[allow(dead_code)] // Public API for rule selection
+    pub fn hong_kong() -> Self {
+        Self {
+            suit_order: [Suit::Diamonds, Suit::Clubs, Suit::Hearts, Suit::Spades],
+            ace_low_straight: StraightRule::Illegal,
+            two_low_straight: StraightRule::Illegal,
+            flush_order: FlushOrder::SuitFirst,
+            bombs: false,
+            quad_beats_single_two: false,
+        }
+    }
+
+    /// The same rules with bombs enabled
+    #[allow(dead_code)] // Public API for rule selection
+    pub fn with_bombs(mut self) -> Self {
+        self.bombs = true;
+        self
+    }
+
+    /// Whether `suit_order` lists each of the four suits exactly once
+    pub fn has_valid_suit_order(&self) -> bool {
+        [Suit::Diamonds, Suit::Clubs, Suit::Hearts, Suit::Spades]
+            .iter()
+            .all(|suit| self.suit_order.contains(suit))
+    }
+
+    /// Position of a suit in this rule set (0 = lowest)
+    ///
+    /// A suit missing from a malformed `suit_order` keeps its standard position.
+    pub fn suit_value(&self, suit: Suit) -> u8 {
+        self.suit_order
+            .iter()
+            .position(|s| *s == suit)
+            .map_or(suit as u8, |position| position as u8)
+    }
+
+    /// Compare two cards by rank, then by this rule set's suit order
+    pub fn compare_cards(&self, a: &Card, b: &Card) -> Ordering {
+        a.rank
+            .cmp(&b.rank)
+            .then_with(|| self.suit_value(a.suit).cmp(&self.suit_value(b.suit)))
+    }
+
+    /// Highest card of a non-empty set under this rule set
+    pub fn highest_card(&self, cards: &[Card]) -> Card {
+        *cards
+            .iter()
+            .max_by(|a, b| self.compare_cards(a, b))
+            .expect("highest_card requires at least one card")
+    }
+
+    /// Strength of five ranks as a straight, or None if they are not a straight
+    ///
+    /// Returns (tier, deciding card rank). Ordinary straights are tier 2; the
+    /// A-2-3-4-5 and 2-3-4-5-6 straights are moved below or above them per the rules.
+    pub(crate) fn straight_tier(&self, ranks: &[Rank]) -> Option<(u8, Rank)> {
+        let mut ranks = ranks.to_vec();
+        ranks.sort();
+
+        if ranks == [Rank::Three, Rank::Four, Rank::Five, Rank::Ace, Rank::Two] {
+            return match self.ace_low_straight {
+                StraightRule::Illegal => None,
+                StraightRule::Lowest => Some((0, Rank::Five)),
+                StraightRule::Highest => Some((3, Rank::Two)),
+            };
+        }
+
+        if ranks == [Rank::Three, Rank::Four, Rank::Five, Rank::Six, Rank::Two] {
+            return match self.two_low_straight {
+                StraightRule::Illegal => None,
+                StraightRule::Lowest => Some((1, Rank::Six)),
+                StraightRule::Highest => Some((4, Rank::Two)),
+            };
+        }
+
+        // 10-J-Q-K-A is the highest ordinary straight; nothing else may contain a Two
+        if ranks.contains(&Rank::Two) {
+            return None;
+        }
+
+        let consecutive = ranks.windows(2).all(|w| w[1] as u8 == w[0] as u8 + 1);
+        consecutive.then_some((2, ranks[4]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_suit_value_follows_rule_set() {
+        let standard = RuleSet::standard();
+        assert!(standard.suit_value(Suit::Spades) > standard.suit_value(Suit::Diamonds));
+
+        let pusoy = RuleSet::pusoy_dos();
+        assert!(pusoy.suit_value(Suit::Diamonds) > pusoy.suit_value(Suit::Spades));
+        assert_eq!(pusoy.suit_value(Suit::Clubs), 0);
+    }
+
+    #[test]
+    fn test_suit_order_must_be_a_permutation() {
+        assert!(RuleSet::standard().has_valid_suit_order());
+        assert!(RuleSet::pusoy_dos().has_valid_suit_order());
+
+        let repeated = RuleSet {
+            suit_order: [Suit::Spades; 4],
+            ..RuleSet::standard()
+        };
+        assert!(!repeated.has_valid_suit_order());
+        // Still usable without panicking: missing suits keep their standard position
+        assert_eq!(repeated.suit_value(Suit::Spades), 0);
+        assert_eq!(repeated.suit_value(Suit::Hearts), 2);
+    }
+
+    #[test]
+    fn test_compare_cards_rank_before_suit() {
+        let pusoy = RuleSet::pusoy_dos();
+        let four_clubs = Card::new(Rank::Four, Suit::Clubs);
+        let three_diamonds = Card::new(Rank::Three, Suit::Diamonds);
+        let three_spades = Card::new(Rank::Three, Suit::Spades);
+
+        assert_eq!(
+            pusoy.compare_cards(&four_clubs, &three_diamonds),
+            Ordering::Greater
+        );
+        assert_eq!(
+            pusoy.compare_cards(&three_diamonds, &three_spades),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn test_straight_tiers() {
+        let wheel = [Rank::Ace, Rank::Two, Rank::Three, Rank::Four, Rank::Five];
+        let two_low = [Rank::Two, Rank::Three, Rank::Four, Rank::Five, Rank::Six];
+        let ace_high = [Rank::Ten, Rank::Jack, Rank::Queen, Rank::King, Rank::Ace];
+        let wrap = [Rank::Jack, Rank::Queen, Rank::King, Rank::Ace, Rank::Two];
+
+        let standard = RuleSet::standard();
+        assert_eq!(standard.straight_tier(&wheel), Some((3, Rank::Two)));
+        assert_eq!(standard.straight_tier(&two_low), None);
+        assert_eq!(standard.straight_tier(&ace_high), Some((2, Rank::Ace)));
+        assert_eq!(standard.straight_tier(&wrap), None);
+
+        let taiwanese = RuleSet::taiwanese();
+        assert!(taiwanese.straight_tier(&two_low) > taiwanese.straight_tier(&wheel));
+
+        let pusoy = RuleSet::pusoy_dos();
+        assert_eq!(pusoy.straight_tier(&wheel), Some((0, Rank::Five)));
+
+        let hong_kong = RuleSet::hong_kong();
+        assert_eq!(hong_kong.straight_tier(&wheel), None);
+        assert_eq!(hong_kong.straight_tier(&two_low), None);
+    }
+}
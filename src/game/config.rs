@@ -0,0 +1,75 @@
+// Per-game configuration chosen before the deal: card ordering rules plus optional house rules.
+use serde::{Deserialize, Serialize};
+
+use crate::game::cards::{Rank, RuleSet};
+
+/// When a player may take back the move they just made
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TakeBackRule {
+    #[default]
+    Off,
+    /// Any time before the next player acts
+    UntilNextMove,
+    /// Before the next player acts, and only if every other player agrees
+    UnanimousConsent,
+}
+
+/// Who may make the opening play of a game
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OpeningRule {
+    /// The holder of the opening card (the lowest card dealt, normally the 3♦) leads, and the
+    /// opening hand must contain it; `must_include_three_of_diamonds` is accepted as an old name
+    #[default]
+    #[serde(alias = "must_include_three_of_diamonds")]
+    MustIncludeLowestCard,
+    /// The holder of the opening card leads with any hand
+    HolderLeads,
+}
+
+/// Optional turn rules; the default matches the rules this server has always used
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HouseRules {
+    /// A player who passes may not play again until the table clears
+    pub pass_lockout: bool,
+    /// When the next player has one card left, any single played must be your highest card
+    pub highest_single_against_last_card: bool,
+    pub opening: OpeningRule,
+    /// Keep playing after the first player goes out until every place is decided
+    pub finishing_order: bool,
+    /// A dragon or all four Twos wins as soon as the cards are dealt
+    pub instant_wins: bool,
+    /// A player whose highest card is at most this rank may ask for a redeal before play starts
+    pub misdeal_max_rank: Option<Rank>,
+}
+
+/// Largest deal seed, so seeds survive a round trip through a JavaScript number
+pub const MAX_SEED: u64 = (1 << 53) - 1;
+
+/// Everything that can be chosen per game
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameConfig {
+    pub rules: RuleSet,
+    pub house_rules: HouseRules,
+    pub take_back: TakeBackRule,
+    /// Deal from this seed instead of a random one; random seeds never exceed MAX_SEED
+    pub seed: Option<u64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_opening_rule_accepts_its_old_name() {
+        let rules: HouseRules =
+            serde_json::from_str(r#"{"opening": "must_include_three_of_diamonds"}"#).unwrap();
+        assert_eq!(rules.opening, OpeningRule::MustIncludeLowestCard);
+
+        let json = serde_json::to_value(HouseRules::default()).unwrap();
+        assert_eq!(json["opening"], "must_include_lowest_card");
+    }
+}
@@ -1,8 +1,16 @@
 // Game history is a list of moves and a list of players (we can derive which player acted based on the history of moves), also has game ID
 
 // The game structure will be passed around to different handlers that can update the state of the game
-use crate::game::cards::{Card, Hand, HandError, Rank, Suit};
+use crate::game::cards::{
+    analysis::partition_hand, Card, CardSet, Hand, HandError, HandPartition, Rank, RuleSet, Suit,
+};
+use crate::game::config::{GameConfig, HouseRules, OpeningRule, TakeBackRule, MAX_SEED};
+use crate::game::deal::{self, InstantWin};
+use crate::game::moves::LegalMoves;
+use crate::game::view::PlayerView;
 use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -12,20 +20,120 @@ pub struct Player {
     pub cards: Vec<Card>,
 }
 
+/// One action in a game's history
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MoveRecord {
+    pub player_uuid: String,
+    /// The hand played, or `Hand::Pass`
+    pub hand: Hand,
+    /// Position of this action in the game, starting at 0
+    pub turn: usize,
+    /// Whether the table was clear, i.e. this action led a new round
+    pub table_clear: bool,
+    pub played_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Table state from just before the latest action, kept so that action can be taken back
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TakeBack {
+    player_uuid: String,
+    /// The player's hand before they acted
+    cards: Vec<Card>,
+    current_turn: usize,
+    consecutive_passes: usize,
+    last_play_by_player: std::collections::HashMap<String, Vec<Card>>,
+    finishing_order: Vec<String>,
+    /// Answers so far when the take-back needs every other player's consent
+    #[serde(default)]
+    votes: Option<TakeBackVotes>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TakeBackVotes {
+    voters: Vec<String>,
+    approvals: Vec<String>,
+}
+
+/// What happened when a player asked to take back their latest action
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TakeBackRequest {
+    /// The action was undone; these cards went back to the player's hand
+    TakenBack(Vec<Card>),
+    /// Every listed player must approve first
+    AwaitingVotes(Vec<String>),
+}
+
+/// What a vote on a pending take-back led to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TakeBackVote {
+    /// Still waiting on other voters
+    Waiting,
+    /// A voter said no and the request was dropped
+    Declined { player: String },
+    /// The last approval came in and the action was undone
+    TakenBack { player: String, cards: Vec<Card> },
+}
+
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum GameError {
     #[error("Invalid player")]
     InvalidPlayerTurn,
     #[error("Invalid played cards")]
     InvalidPlayedCards,
-    #[error("Cannot pass - must play cards (3 consecutive passes)")]
+    #[error("Cannot pass - must play cards (everyone else passed)")]
     CannotPass,
     #[error("Player does not own card: {0}")]
     CardNotOwned(Card),
-    #[error("Hand construction error")]
+    #[error("Hand construction error: {0}")]
     HandError(HandError),
     #[error("First turn must include the 3 of diamonds")]
     FirstTurnMustIncludeThreeOfDiamonds,
+    #[error("Player has passed and is locked out until the table clears")]
+    LockedOutUntilTableClears,
+    #[error("Next player has one card left - a single must be your highest card")]
+    MustPlayHighestSingle,
+    #[error("First turn must include the lowest dealt card: {0}")]
+    FirstTurnMustIncludeLowestCard(Card),
+    #[error("Big Two needs 2 to 4 players, got {0}")]
+    UnsupportedPlayerCount(usize),
+    #[error("No hand holds a card to open the game with")]
+    NoOpeningCard,
+    #[error("Redeal not allowed - only a hand with no high cards may ask, before anyone plays")]
+    RedealNotAllowed,
+    #[error("Nothing to take back - only your own latest action, before anyone else acts")]
+    NothingToTakeBack,
+    #[error("This game does not allow take-backs")]
+    TakeBackDisabled,
+    #[error("Every other player must agree to the take-back first")]
+    TakeBackNeedsConsent,
+    #[error("Another player declined the take-back")]
+    TakeBackDeclined,
+}
+
+impl GameError {
+    /// Stable, machine-readable code for clients (one per variant)
+    pub fn code(&self) -> &'static str {
+        match self {
+            GameError::InvalidPlayerTurn => "NOT_YOUR_TURN",
+            GameError::InvalidPlayedCards => "CANNOT_BEAT_TABLE",
+            GameError::CannotPass => "CANNOT_PASS",
+            GameError::CardNotOwned(_) => "CARD_NOT_OWNED",
+            GameError::HandError(_) => "INVALID_HAND",
+            GameError::FirstTurnMustIncludeThreeOfDiamonds => {
+                "FIRST_TURN_REQUIRES_THREE_OF_DIAMONDS"
+            }
+            GameError::LockedOutUntilTableClears => "LOCKED_OUT",
+            GameError::MustPlayHighestSingle => "MUST_PLAY_HIGHEST_SINGLE",
+            GameError::FirstTurnMustIncludeLowestCard(_) => "FIRST_TURN_REQUIRES_LOWEST_CARD",
+            GameError::UnsupportedPlayerCount(_) => "UNSUPPORTED_PLAYER_COUNT",
+            GameError::NoOpeningCard => "NO_OPENING_CARD",
+            GameError::RedealNotAllowed => "REDEAL_NOT_ALLOWED",
+            GameError::NothingToTakeBack => "NOTHING_TO_TAKE_BACK",
+            GameError::TakeBackDisabled => "TAKE_BACK_DISABLED",
+            GameError::TakeBackNeedsConsent => "TAKE_BACK_NEEDS_CONSENT",
+            GameError::TakeBackDeclined => "TAKE_BACK_DECLINED",
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -37,6 +145,36 @@ pub struct Game {
     played_hands: Vec<Hand>,
     starting_hands: std::collections::HashMap<String, Vec<Card>>, // Player name -> starting cards
     last_play_by_player: std::collections::HashMap<String, Vec<Card>>, // Player UUID -> last played cards
+    #[serde(default)]
+    rules: RuleSet,
+    #[serde(default)]
+    house_rules: HouseRules,
+    /// Cards nobody was dealt (two-player games)
+    #[serde(default)]
+    dead_pile: Vec<Card>,
+    /// Seed the deck was shuffled with; None for hand-picked deals
+    #[serde(default)]
+    deal_seed: Option<u64>,
+    /// Every action taken so far, in order
+    #[serde(default)]
+    moves: Vec<MoveRecord>,
+    /// Player chosen to lead instead of the opening card holder (e.g. the previous winner)
+    #[serde(default)]
+    leader: Option<String>,
+    /// UUIDs of players who have emptied their hand, first place first
+    #[serde(default)]
+    finishing_order: Vec<String>,
+    /// Set when the game was won by a dealt hand rather than by play
+    #[serde(default)]
+    instant_win: Option<InstantWin>,
+    /// State before the latest action; cleared once it is taken back
+    #[serde(default)]
+    take_back: Option<TakeBack>,
+    #[serde(default)]
+    take_back_rule: TakeBackRule,
+    /// Player UUIDs in the order the seats were dealt, before the opener was moved to the front
+    #[serde(default)]
+    deal_order: Vec<String>,
 }
 
 impl Game {
@@ -56,32 +194,167 @@ impl Game {
             played_hands,
             starting_hands,
             last_play_by_player: std::collections::HashMap::new(),
+            rules: RuleSet::default(),
+            house_rules: HouseRules::default(),
+            dead_pile: Vec::new(),
+            deal_seed: None,
+            moves: Vec::new(),
+            leader: None,
+            finishing_order: Vec::new(),
+            instant_win: None,
+            take_back: None,
+            take_back_rule: TakeBackRule::default(),
+            deal_order: Vec::new(),
+        }
+    }
+
+    /// Play this game under a different rule set
+    ///
+    /// The suit order decides the opening card, so before anyone acts the holder of the
+    /// new opening card is seated first, unless a leader has already been chosen.
+    pub fn with_rules(mut self, rules: RuleSet) -> Self {
+        self.rules = rules;
+        if self.played_hands.is_empty() && self.leader.is_none() {
+            if let Some(opener) = Self::opening_card(&self.players, &rules)
+                .and_then(|card| self.players.iter().position(|p| p.cards.contains(&card)))
+            {
+                self.players.rotate_left(opener);
+                self.current_turn = 0;
+            }
+        }
+        self
+    }
+
+    /// Play this game with optional house rules
+    pub fn with_house_rules(mut self, house_rules: HouseRules) -> Self {
+        self.house_rules = house_rules;
+        self
+    }
+
+    /// Choose when players may take back their latest action
+    pub fn with_take_back_rule(mut self, rule: TakeBackRule) -> Self {
+        self.take_back_rule = rule;
+        self
+    }
+
+    /// Let a specific player lead with any hand; ignored if they are not seated
+    pub fn with_leader(mut self, player_uuid: &str) -> Self {
+        if !self.played_hands.is_empty() {
+            return self;
+        }
+        if let Some(index) = self.players.iter().position(|p| p.uuid == player_uuid) {
+            // Keep the seating order, just start the rotation at the leader
+            self.players.rotate_left(index);
+            self.current_turn = 0;
+            self.leader = Some(player_uuid.to_string());
         }
+        self
+    }
+
+    /// Record the seed a hand-picked deal came from; every card not dealt becomes the dead pile
+    pub(crate) fn with_deal_seed(mut self, seed: u64) -> Self {
+        let dealt: CardSet = self
+            .starting_hands
+            .values()
+            .flat_map(|hand| hand.iter().copied())
+            .collect();
+        self.dead_pile = Card::all_cards()
+            .into_iter()
+            .filter(|card| !dealt.contains(*card))
+            .collect();
+        self.dead_pile.sort();
+        self.deal_seed = Some(seed);
+        self
     }
 
     /// Standard constructor: creates a new game with UUIDs and names
+    ///
+    /// Four players get 13 cards each. Three players get 17 each, with the leftover
+    /// card going to whoever holds the 3♦. Two players get 13 each and the rest is dead.
+    #[allow(dead_code)] // Public API for callers without a GameConfig
     pub fn new_game(id: String, player_data: &[(String, String)]) -> Result<Self, GameError> {
-        // Big Two requires exactly 4 players
-        if player_data.len() != 4 {
-            return Err(GameError::InvalidPlayedCards);
+        Self::new_game_with_seed(id, player_data, Self::random_seed())
+    }
+
+    /// A random deal seed that JSON clients can read back exactly
+    fn random_seed() -> u64 {
+        rand::rng().random_range(0..=MAX_SEED)
+    }
+
+    /// Deal from a fixed seed; the same seed and players always produce the same deal
+    pub fn new_game_with_seed(
+        id: String,
+        player_data: &[(String, String)],
+        seed: u64,
+    ) -> Result<Self, GameError> {
+        Self::deal(id, player_data, seed, &RuleSet::default())
+    }
+
+    /// Shuffle from `seed` and deal, keeping the opening card under `rules` in play
+    fn deal(
+        id: String,
+        player_data: &[(String, String)],
+        seed: u64,
+        rules: &RuleSet,
+    ) -> Result<Self, GameError> {
+        if !(2..=4).contains(&player_data.len()) {
+            return Err(GameError::UnsupportedPlayerCount(player_data.len()));
         }
 
-        // Randomly deal the 52 cards to the players
+        // Shuffle the 52 cards from the seed and deal them to the players
         let mut cards = Card::all_cards();
-        cards.shuffle(&mut rand::rng());
+        cards.shuffle(&mut ChaCha8Rng::seed_from_u64(seed));
+
+        let lowest_three = Card::new(Rank::Three, rules.suit_order[0]);
+        let hand_size = if player_data.len() == 3 { 17 } else { 13 };
+
+        // Keep the lowest 3 out of the three-player leftover so it always has a holder
+        if player_data.len() == 3 && cards.last() == Some(&lowest_three) {
+            let last = cards.len() - 1;
+            cards.swap(0, last);
+        }
 
         // Create player data with cards
-        let player_cards: Vec<(String, String, Vec<Card>)> = player_data
+        let mut player_cards: Vec<(String, String, Vec<Card>)> = player_data
             .iter()
             .map(|(name, uuid)| {
-                let mut player_cards: Vec<Card> = cards.drain(0..13).collect();
-                player_cards.sort();
+                let player_cards: Vec<Card> = cards.drain(0..hand_size).collect();
                 (name.clone(), uuid.clone(), player_cards) // (name, uuid, cards)
             })
             .collect();
 
+        if player_data.len() == 3 {
+            if let Some(holder) = player_cards
+                .iter_mut()
+                .find(|(_, _, hand)| hand.contains(&lowest_three))
+            {
+                holder.2.append(&mut cards);
+            }
+        }
+
+        for (_, _, hand) in player_cards.iter_mut() {
+            hand.sort();
+        }
+
         // Delegate to the more general constructor
-        Self::new_game_with_cards(id, player_cards)
+        let mut game = Self::new_game_with_cards(id, player_cards)?;
+        cards.sort();
+        game.dead_pile = cards;
+        game.deal_seed = Some(seed);
+        Ok(game)
+    }
+
+    /// Deal a game set up by `config`: rules, house rules and seed
+    pub fn new_game_with_config(
+        id: String,
+        player_data: &[(String, String)],
+        config: &GameConfig,
+    ) -> Result<Self, GameError> {
+        let seed = config.seed.unwrap_or_else(Self::random_seed);
+        Ok(Self::deal(id, player_data, seed, &config.rules)?
+            .with_rules(config.rules)
+            .with_house_rules(config.house_rules)
+            .with_take_back_rule(config.take_back))
     }
 
     /// Testing/specialized constructor: creates a game with predetermined cards (no player count restriction)
@@ -102,19 +375,112 @@ impl Game {
             starting_hands.insert(uuid.clone(), cards.clone()); // Key by UUID for internal use
         }
 
-        // Find who has 3D (they go first)
+        // Whoever holds the opening card (normally the 3♦) goes first
+        let opening_card =
+            Self::opening_card(&players, &RuleSet::default()).ok_or(GameError::NoOpeningCard)?;
         let first_player_index = players
             .iter()
-            .position(|p| p.cards.contains(&Card::new(Rank::Three, Suit::Diamonds)))
-            .ok_or(GameError::InvalidPlayedCards)?;
+            .position(|p| p.cards.contains(&opening_card))
+            .ok_or(GameError::NoOpeningCard)?;
 
-        // Rotate players so the one with 3D is first
+        // Rotate players so the opening card holder is first
+        let deal_order = players.iter().map(|p| p.uuid.clone()).collect();
         players.rotate_left(first_player_index);
 
-        Ok(Self::new(id, players, 0, 0, vec![], starting_hands))
+        let mut game = Self::new(id, players, 0, 0, vec![], starting_hands);
+        game.deal_order = deal_order;
+        Ok(game)
+    }
+
+    /// The lowest card dealt under `rules`: the 3 of the lowest suit (3♦ normally, 3♣ in
+    /// Pusoy Dos) unless nobody was dealt it
+    fn opening_card(players: &[Player], rules: &RuleSet) -> Option<Card> {
+        players
+            .iter()
+            .flat_map(|p| p.cards.iter())
+            .min_by(|a, b| rules.compare_cards(a, b))
+            .copied()
+    }
+
+    /// Passes in a row after which the last player to play leads again
+    ///
+    /// In finishing-order mode only players still holding cards count. If the last hand
+    /// went out with its player, all of them must pass and the next seat leads instead.
+    fn passes_to_clear_table(&self) -> usize {
+        if !self.house_rules.finishing_order {
+            return self.players.len().saturating_sub(1).max(1);
+        }
+
+        let still_playing = self.players.iter().filter(|p| !p.cards.is_empty()).count();
+        let last_player_out = self
+            .last_non_pass_player_uuid()
+            .is_some_and(|uuid| self.finishing_order.contains(&uuid));
+
+        if last_player_out {
+            still_playing.max(1)
+        } else {
+            still_playing.saturating_sub(1).max(1)
+        }
+    }
+
+    /// Seat that acts after `index`, skipping players who have gone out in finishing-order mode
+    fn next_seat(&self, index: usize) -> usize {
+        let next = (index + 1) % self.players.len();
+        if !self.house_rules.finishing_order {
+            return next;
+        }
+
+        (0..self.players.len())
+            .map(|step| (next + step) % self.players.len())
+            .find(|&i| !self.players[i].cards.is_empty())
+            .unwrap_or(next)
+    }
+
+    /// End the game on the spot if someone was dealt an instant win (house rule, before any play)
+    ///
+    /// The first holder in turn order wins; their hand is laid down, leaving them no cards.
+    pub fn claim_instant_win(&mut self) -> Option<InstantWin> {
+        if !self.house_rules.instant_wins || !self.played_hands.is_empty() || self.is_over() {
+            return None;
+        }
+
+        let seats = self.players.len();
+        let (index, kind) = (0..seats)
+            .map(|step| (self.current_turn + step) % seats)
+            .find_map(|i| InstantWin::detect(&self.players[i].cards).map(|kind| (i, kind)))?;
+
+        let player = &mut self.players[index];
+        player.cards.clear();
+        self.finishing_order.push(player.uuid.clone());
+        self.instant_win = Some(kind);
+        Some(kind)
+    }
+
+    /// Check that a player may ask for a redeal under the misdeal house rule
+    pub fn validate_redeal(&self, player_uuid: &str) -> Result<(), GameError> {
+        let max_rank = self
+            .house_rules
+            .misdeal_max_rank
+            .ok_or(GameError::RedealNotAllowed)?;
+        if !self.played_hands.is_empty() || self.is_over() {
+            return Err(GameError::RedealNotAllowed);
+        }
+
+        let player = self
+            .players
+            .iter()
+            .find(|p| p.uuid == player_uuid)
+            .ok_or(GameError::RedealNotAllowed)?;
+        if !deal::is_misdeal(&player.cards, max_rank) {
+            return Err(GameError::RedealNotAllowed);
+        }
+        Ok(())
     }
 
     /// Play cards by player UUID
+    ///
+    /// Returns true once the game is over: when the first player goes out, or in
+    /// finishing-order mode when every place has been decided.
     pub fn play_cards(&mut self, player_uuid: &str, cards: &[Card]) -> Result<bool, GameError> {
         self.validate_player_turn(player_uuid)?;
 
@@ -125,6 +491,17 @@ impl Game {
         }
     }
 
+    /// Check whether a move (empty cards = pass) is legal without applying it
+    pub fn validate_move(&self, player_uuid: &str, cards: &[Card]) -> Result<(), GameError> {
+        self.validate_player_turn(player_uuid)?;
+
+        if cards.is_empty() {
+            self.validate_pass()
+        } else {
+            self.validate_hand(cards).map(|_| ())
+        }
+    }
+
     fn validate_player_turn(&self, player_uuid: &str) -> Result<(), GameError> {
         let player = &self.players[self.current_turn];
         if player.uuid != player_uuid {
@@ -133,13 +510,20 @@ impl Game {
         Ok(())
     }
 
-    fn handle_pass(&mut self) -> Result<bool, GameError> {
-        if self.consecutive_passes >= 3 {
+    fn validate_pass(&self) -> Result<(), GameError> {
+        if self.consecutive_passes >= self.passes_to_clear_table() {
             return Err(GameError::CannotPass);
         }
+        Ok(())
+    }
+
+    fn handle_pass(&mut self) -> Result<bool, GameError> {
+        self.validate_pass()?;
+        self.save_take_back();
 
         // Track the pass for this player (empty vec indicates pass)
         let player_uuid = self.players[self.current_turn].uuid.clone();
+        self.record_move(player_uuid.clone(), Hand::Pass);
         self.last_play_by_player.insert(player_uuid, vec![]);
 
         self.consecutive_passes += 1;
@@ -148,26 +532,62 @@ impl Game {
         Ok(false)
     }
 
-    fn handle_card_play(&mut self, cards: &[Card]) -> Result<bool, GameError> {
-        let new_hand = Hand::from_cards(cards).map_err(GameError::HandError)?;
+    /// Run every check for the current player playing `cards`, returning the parsed hand
+    fn validate_hand(&self, cards: &[Card]) -> Result<Hand, GameError> {
+        self.validate_not_locked_out()?;
+
+        let new_hand =
+            Hand::from_cards_with_rules(cards, &self.rules).map_err(GameError::HandError)?;
 
         self.validate_card_play(&new_hand, cards)?;
         self.validate_card_ownership(cards)?;
         self.validate_first_turn(cards)?;
+        self.validate_single_against_last_card(cards)?;
 
-        let player_won = self.execute_card_play(cards, new_hand);
+        Ok(new_hand)
+    }
+
+    fn handle_card_play(&mut self, cards: &[Card]) -> Result<bool, GameError> {
+        let new_hand = self.validate_hand(cards)?;
+        self.save_take_back();
+
+        let went_out = self.execute_card_play(cards, new_hand);
+        if went_out {
+            self.record_finish();
+        }
         self.advance_turn();
 
-        Ok(player_won)
+        Ok(went_out && self.is_over())
+    }
+
+    /// Add the current player to the finishing order, and the last player left once only one remains
+    fn record_finish(&mut self) {
+        self.finishing_order
+            .push(self.players[self.current_turn].uuid.clone());
+
+        if self.house_rules.finishing_order {
+            let mut still_playing = self.players.iter().filter(|p| !p.cards.is_empty());
+            if let (Some(last), None) = (still_playing.next(), still_playing.next()) {
+                let uuid = last.uuid.clone();
+                self.finishing_order.push(uuid);
+            }
+        }
+    }
+
+    fn validate_not_locked_out(&self) -> Result<(), GameError> {
+        if self.is_locked_out(&self.players[self.current_turn].uuid) {
+            return Err(GameError::LockedOutUntilTableClears);
+        }
+        Ok(())
     }
 
     fn validate_card_play(&self, new_hand: &Hand, _cards: &[Card]) -> Result<(), GameError> {
-        // Skip validation if 3 consecutive passes have occurred (table is clear)
-        if self.consecutive_passes < 3 && !self.played_hands.is_empty() {
+        // Skip validation if everyone else has passed (table is clear)
+        if self.consecutive_passes < self.passes_to_clear_table() && !self.played_hands.is_empty() {
             if let Some(last_non_pass_hand) =
                 self.played_hands.iter().rev().find(|h| **h != Hand::Pass)
             {
-                if !new_hand.can_beat(last_non_pass_hand) {
+                if !new_hand.can_beat_with_rules(last_non_pass_hand, &self.rules) {
                     return Err(GameError::InvalidPlayedCards);
                 }
             }
@@ -176,44 +596,70 @@ impl Game {
     }
 
     fn validate_card_ownership(&self, cards: &[Card]) -> Result<(), GameError> {
-        let current_player = &self.players[self.current_turn];
-        for card in cards {
-            if !current_player.cards.contains(card) {
-                return Err(GameError::CardNotOwned(*card));
-            }
+        let hand: CardSet = self.players[self.current_turn]
+            .cards
+            .iter()
+            .copied()
+            .collect();
+        match cards.iter().find(|card| !hand.contains(**card)) {
+            Some(card) => Err(GameError::CardNotOwned(*card)),
+            None => Ok(()),
         }
-        Ok(())
     }
 
     fn validate_first_turn(&self, cards: &[Card]) -> Result<(), GameError> {
+        if self.house_rules.opening == OpeningRule::HolderLeads || self.leader.is_some() {
+            return Ok(());
+        }
+
         if self.played_hands.is_empty() {
-            let three_of_diamonds = Card::new(Rank::Three, Suit::Diamonds);
-            if !cards.contains(&three_of_diamonds) {
-                return Err(GameError::FirstTurnMustIncludeThreeOfDiamonds);
+            let Some(opening_card) = Self::opening_card(&self.players, &self.rules) else {
+                return Ok(());
+            };
+            if !cards.contains(&opening_card) {
+                if opening_card == Card::new(Rank::Three, Suit::Diamonds) {
+                    return Err(GameError::FirstTurnMustIncludeThreeOfDiamonds);
+                }
+                return Err(GameError::FirstTurnMustIncludeLowestCard(opening_card));
             }
         }
         Ok(())
     }
 
+    fn validate_single_against_last_card(&self, cards: &[Card]) -> Result<(), GameError> {
+        if !self.house_rules.highest_single_against_last_card || cards.len() != 1 {
+            return Ok(());
+        }
+
+        let next_player = &self.players[self.next_seat(self.current_turn)];
+        if next_player.cards.len() != 1 {
+            return Ok(());
+        }
+
+        let current_player = &self.players[self.current_turn];
+        if cards[0] != self.rules.highest_card(&current_player.cards) {
+            return Err(GameError::MustPlayHighestSingle);
+        }
+        Ok(())
+    }
+
     fn execute_card_play(&mut self, cards: &[Card], new_hand: Hand) -> bool {
         // Track the cards played for this player
         let player_uuid = self.players[self.current_turn].uuid.clone();
+        self.record_move(player_uuid.clone(), new_hand.clone());
 
-        // Clear all last plays when table is cleared (3 consecutive passes followed by a play)
-        // This happens when consecutive_passes == 3, which means a new round is starting
-        if self.consecutive_passes == 3 {
+        // Clear all last plays when table is cleared (everyone else passed, followed by a play)
+        // This happens when consecutive_passes reaches the threshold, which means a new round is starting
+        if self.consecutive_passes >= self.passes_to_clear_table() {
             self.last_play_by_player.clear();
         }
 
         self.last_play_by_player.insert(player_uuid, cards.to_vec());
 
         // Remove played cards from the player's hand
+        let played: CardSet = cards.iter().copied().collect();
         let current_player = &mut self.players[self.current_turn];
-        for card in cards {
-            if let Some(pos) = current_player.cards.iter().position(|c| c == card) {
-                current_player.cards.remove(pos);
-            }
-        }
+        current_player.cards.retain(|card| !played.contains(*card));
 
         self.consecutive_passes = 0;
         self.played_hands.push(new_hand);
@@ -222,14 +668,242 @@ impl Game {
         current_player.cards.is_empty()
     }
 
+    /// Remember the table as it is before the current player acts
+    fn save_take_back(&mut self) {
+        let player = &self.players[self.current_turn];
+        self.take_back = Some(TakeBack {
+            player_uuid: player.uuid.clone(),
+            cards: player.cards.clone(),
+            current_turn: self.current_turn,
+            consecutive_passes: self.consecutive_passes,
+            last_play_by_player: self.last_play_by_player.clone(),
+            finishing_order: self.finishing_order.clone(),
+            votes: None,
+        });
+    }
+
+    /// Whether `player_uuid` took the latest action and nobody has acted since
+    pub fn can_take_back(&self, player_uuid: &str) -> bool {
+        !self.is_over()
+            && self
+                .take_back
+                .as_ref()
+                .is_some_and(|t| t.player_uuid == player_uuid)
+    }
+
+    /// Ask to take back the latest action: undone at once, or put to the other players
+    /// when the game's rule needs their consent
+    pub fn request_take_back(&mut self, player_uuid: &str) -> Result<TakeBackRequest, GameError> {
+        if self.take_back_rule != TakeBackRule::UnanimousConsent {
+            return self.take_back(player_uuid).map(TakeBackRequest::TakenBack);
+        }
+        if !self.can_take_back(player_uuid) {
+            return Err(GameError::NothingToTakeBack);
+        }
+
+        let voters: Vec<String> = self
+            .players
+            .iter()
+            .map(|p| p.uuid.clone())
+            .filter(|uuid| uuid != player_uuid)
+            .collect();
+        if let Some(snapshot) = self.take_back.as_mut() {
+            snapshot.votes = Some(TakeBackVotes {
+                voters: voters.clone(),
+                approvals: Vec::new(),
+            });
+        }
+        Ok(TakeBackRequest::AwaitingVotes(voters))
+    }
+
+    /// Answer a pending take-back request; None if `voter` has nothing to answer
+    pub fn vote_take_back(&mut self, voter: &str, approve: bool) -> Option<TakeBackVote> {
+        let snapshot = self.take_back.as_mut()?;
+        let votes = snapshot.votes.as_mut()?;
+        if !votes.voters.iter().any(|uuid| uuid == voter) {
+            return None;
+        }
+
+        let player = snapshot.player_uuid.clone();
+        if !approve {
+            snapshot.votes = None;
+            return Some(TakeBackVote::Declined { player });
+        }
+        if !votes.approvals.iter().any(|uuid| uuid == voter) {
+            votes.approvals.push(voter.to_string());
+        }
+        if votes.approvals.len() < votes.voters.len() {
+            return Some(TakeBackVote::Waiting);
+        }
+
+        let cards = self.take_back(&player).ok()?;
+        Some(TakeBackVote::TakenBack { player, cards })
+    }
+
+    /// Undo the latest action, returning the cards it put on the table (empty for a pass)
+    ///
+    /// Only the player who acted may take it back, only before the next player acts, and only
+    /// as the game's take-back rule allows.
+    pub fn take_back(&mut self, player_uuid: &str) -> Result<Vec<Card>, GameError> {
+        if self.take_back_rule == TakeBackRule::Off {
+            return Err(GameError::TakeBackDisabled);
+        }
+        if !self.can_take_back(player_uuid) {
+            return Err(GameError::NothingToTakeBack);
+        }
+        let consented = |snapshot: &TakeBack| {
+            snapshot
+                .votes
+                .as_ref()
+                .is_some_and(|v| v.approvals.len() == v.voters.len())
+        };
+        if self.take_back_rule == TakeBackRule::UnanimousConsent
+            && !self.take_back.as_ref().is_some_and(consented)
+        {
+            return Err(GameError::TakeBackNeedsConsent);
+        }
+        let Some(snapshot) = self.take_back.take() else {
+            return Err(GameError::NothingToTakeBack);
+        };
+
+        let hand = self.played_hands.pop().unwrap_or(Hand::Pass);
+        self.moves.pop();
+
+        self.players[snapshot.current_turn].cards = snapshot.cards;
+        self.current_turn = snapshot.current_turn;
+        self.consecutive_passes = snapshot.consecutive_passes;
+        self.last_play_by_player = snapshot.last_play_by_player;
+        self.finishing_order = snapshot.finishing_order;
+
+        Ok(hand.to_cards())
+    }
+
+    /// Append an action to the move log; call before the table state changes
+    fn record_move(&mut self, player_uuid: String, hand: Hand) {
+        self.moves.push(MoveRecord {
+            player_uuid,
+            hand,
+            turn: self.played_hands.len(),
+            table_clear: self.is_table_clear(),
+            played_at: chrono::Utc::now(),
+        });
+    }
+
     fn advance_turn(&mut self) {
-        self.current_turn = (self.current_turn + 1) % self.players.len();
+        self.current_turn = self.next_seat(self.current_turn);
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Ordering rules this game is played under
+    pub fn rules(&self) -> &RuleSet {
+        &self.rules
+    }
+
+    /// Optional turn rules this game is played with
+    pub fn house_rules(&self) -> &HouseRules {
+        &self.house_rules
+    }
+
+    /// When players may take back their latest action
+    pub fn take_back_rule(&self) -> TakeBackRule {
+        self.take_back_rule
+    }
+
+    /// Whether a player has passed this round and may not play until the table clears
+    pub fn is_locked_out(&self, player_uuid: &str) -> bool {
+        self.house_rules.pass_lockout
+            && !self.is_table_clear()
+            && self
+                .last_play_by_player
+                .get(player_uuid)
+                .is_some_and(|cards| cards.is_empty())
     }
 
     pub fn players(&self) -> &Vec<Player> {
         &self.players
     }
 
+    /// Build the redacted view of this game for a single player
+    pub fn view_for(&self, player_uuid: &str) -> Option<PlayerView> {
+        PlayerView::from_game(self, player_uuid)
+    }
+
+    /// Every legal play for a player, grouped by hand type (empty when it is not their turn)
+    pub fn legal_moves(&self, player_uuid: &str) -> LegalMoves {
+        LegalMoves::from_game(self, player_uuid)
+    }
+
+    /// The best `limit` ways to split a player's hand into plays under this game's rules
+    pub fn hand_partitions(&self, player_uuid: &str, limit: usize) -> Vec<HandPartition> {
+        self.players
+            .iter()
+            .find(|p| p.uuid == player_uuid)
+            .map(|p| partition_hand(&p.cards, &self.rules, limit))
+            .unwrap_or_default()
+    }
+
+    /// UUID of the first player to empty their hand, if any
+    pub fn winner(&self) -> Option<String> {
+        self.finishing_order.first().cloned().or_else(|| {
+            self.players
+                .iter()
+                .find(|p| p.cards.is_empty())
+                .map(|p| p.uuid.clone())
+        })
+    }
+
+    /// The cards the winner went out with (their whole dealt hand for an instant win)
+    pub fn winning_hand(&self) -> Option<Vec<Card>> {
+        let winner = self.winner()?;
+        if self.instant_win.is_some() {
+            return self.starting_hands.get(&winner).cloned();
+        }
+        self.moves
+            .iter()
+            .rev()
+            .find(|m| m.player_uuid == winner && m.hand != Hand::Pass)
+            .map(|m| m.hand.to_cards())
+    }
+
+    /// Players in the order they went out, first place first
+    ///
+    /// Only the winner is listed unless the game is played in finishing-order mode.
+    pub fn finishing_order(&self) -> &[String] {
+        &self.finishing_order
+    }
+
+    /// The dealt hand that won the game, if it was won without play
+    #[allow(dead_code)] // Public API for future use
+    pub fn instant_win(&self) -> Option<InstantWin> {
+        self.instant_win
+    }
+
+    /// Player who was chosen to lead instead of the opening card holder
+    pub fn leader(&self) -> Option<&str> {
+        self.leader.as_deref()
+    }
+
+    /// Whether the game has ended
+    pub fn is_over(&self) -> bool {
+        if self.instant_win.is_some() {
+            return true;
+        }
+        if self.house_rules.finishing_order {
+            !self.players.is_empty() && self.finishing_order.len() == self.players.len()
+        } else {
+            self.winner().is_some()
+        }
+    }
+
+    /// Whether the current player may lead any hand (nothing to beat on the table)
+    pub fn is_table_clear(&self) -> bool {
+        self.consecutive_passes >= self.passes_to_clear_table()
+            || self.played_hands.iter().all(|h| *h == Hand::Pass)
+    }
+
     /// Get current player uuid
     pub fn current_player_turn(&self) -> String {
         self.players[self.current_turn].uuid.clone()
@@ -245,6 +919,7 @@ impl Game {
         self.consecutive_passes
     }
 
+    #[allow(dead_code)] // Public API for future use
     pub fn last_played_cards(&self) -> Vec<Card> {
         if let Some(last_hand) = self.played_hands.last() {
             last_hand.to_cards()
@@ -270,6 +945,16 @@ impl Game {
             return None;
         }
 
+        // The move log is exact; it only lags behind for games built from raw played hands
+        if self.moves.len() == self.played_hands.len() {
+            return self
+                .moves
+                .iter()
+                .rev()
+                .find(|m| m.hand != Hand::Pass)
+                .map(|m| m.player_uuid.clone());
+        }
+
         let num_players = self.players.len();
         if num_players == 0 {
             return None;
@@ -287,10 +972,40 @@ impl Game {
         self.played_hands.last()
     }
 
+    /// Every action taken so far, with who took it and when
+    #[allow(dead_code)] // Public API for replays and analysis
+    pub fn moves(&self) -> &[MoveRecord] {
+        &self.moves
+    }
+
     pub fn played_hands(&self) -> &[Hand] {
         &self.played_hands
     }
 
+    /// Seed the deal was shuffled with, if the server dealt it
+    pub fn deal_seed(&self) -> Option<u64> {
+        self.deal_seed
+    }
+
+    /// Cards that were not dealt to anyone (only non-empty in two-player games)
+    #[allow(dead_code)] // Public API for future use
+    pub fn dead_pile(&self) -> &[Card] {
+        &self.dead_pile
+    }
+
+    /// Players in the order their seats were dealt, which a seed deals the same hands to
+    ///
+    /// Games stored before the deal order was kept fall back to the turn order.
+    pub fn players_in_deal_order(&self) -> Vec<&Player> {
+        if self.deal_order.is_empty() {
+            return self.players.iter().collect();
+        }
+        self.deal_order
+            .iter()
+            .filter_map(|uuid| self.players.iter().find(|p| &p.uuid == uuid))
+            .collect()
+    }
+
     #[allow(dead_code)] // Public API for accessing initial card distribution
     pub fn starting_hands(&self) -> &std::collections::HashMap<String, Vec<Card>> {
         &self.starting_hands
@@ -302,7 +1017,7 @@ impl Game {
     /// - Empty vec (`[]`) indicates the player passed on their last turn
     /// - Non-empty vec contains the actual cards played
     /// - Players who haven't acted yet won't have entries in the map
-    /// - All entries are cleared when the table resets (after 3 consecutive passes followed by a play)
+    /// - All entries are cleared when the table resets (everyone else passed, followed by a play)
     pub fn last_plays_by_player(&self) -> &std::collections::HashMap<String, Vec<Card>> {
         &self.last_play_by_player
     }
@@ -310,7 +1025,11 @@ impl Game {
 
 #[cfg(test)]
 mod tests {
-    use super::{Card, Game, GameError, Hand, Player, Rank, Suit};
+    use super::{
+        Card, Game, GameConfig, GameError, Hand, HandError, Player, Rank, Suit, TakeBackRequest,
+        TakeBackRule, TakeBackVote, MAX_SEED,
+    };
+    use crate::shared::test_utils::cards;
 
     #[test]
     fn test_new_game() {
@@ -454,6 +1173,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::unnecessary_unwrap)]
     fn test_win_detection_multiple_cards() {
         let players = vec![
             Player {
@@ -801,6 +1521,34 @@ mod tests {
         assert!(matches!(result.unwrap_err(), GameError::HandError(_)));
     }
 
+    #[test]
+    fn test_duplicate_card_cannot_make_a_pair() {
+        let three = Card::new(Rank::Three, Suit::Diamonds);
+        let mut game = Game::new_game_with_cards(
+            "test".to_string(),
+            vec![
+                (
+                    "Alice".to_string(),
+                    "alice".to_string(),
+                    vec![three, Card::new(Rank::Four, Suit::Hearts)],
+                ),
+                (
+                    "Bob".to_string(),
+                    "bob".to_string(),
+                    vec![Card::new(Rank::Five, Suit::Clubs)],
+                ),
+            ],
+        )
+        .unwrap();
+
+        let result = game.play_cards("alice", &[three, three]);
+        assert!(matches!(
+            result.unwrap_err(),
+            GameError::HandError(HandError::DuplicateCard(card)) if card == three
+        ));
+        assert_eq!(game.players()[0].cards.len(), 2);
+    }
+
     #[test]
     fn test_table_clear_after_three_passes() {
         let players = vec![
@@ -1334,18 +2082,844 @@ mod tests {
         assert_eq!(last_plays.get("bob-uuid"), Some(&vec![])); // Empty vec for pass
         assert_eq!(last_plays.get("charlie-uuid"), Some(&vec![])); // Empty vec for pass
 
-        // Alice plays again (after 2 passes, she can play again)
+        // Alice leads again (with three players, two passes clear the table)
         let result4 = game.play_cards("alice-uuid", &[Card::new(Rank::Four, Suit::Hearts)]);
         assert!(result4.is_ok());
 
-        // Alice's last play should be updated, Bob and Charlie's passes should remain
+        // The table was cleared, so only Alice's new play remains
         let last_plays = game.last_plays_by_player();
-        assert_eq!(last_plays.len(), 3);
+        assert_eq!(last_plays.len(), 1);
         assert_eq!(
             last_plays.get("alice-uuid"),
             Some(&vec![Card::new(Rank::Four, Suit::Hearts)])
         );
-        assert_eq!(last_plays.get("bob-uuid"), Some(&vec![])); // Still empty for pass
-        assert_eq!(last_plays.get("charlie-uuid"), Some(&vec![])); // Still empty for pass
+    }
+
+    #[test]
+    fn test_game_uses_its_rule_set() {
+        use crate::game::cards::RuleSet;
+
+        let players = vec![
+            Player {
+                name: "Alice".to_string(),
+                uuid: "alice-uuid".to_string(),
+                cards: vec![
+                    Card::new(Rank::Three, Suit::Spades),
+                    Card::new(Rank::Four, Suit::Clubs),
+                ],
+            },
+            Player {
+                name: "Bob".to_string(),
+                uuid: "bob-uuid".to_string(),
+                cards: vec![
+                    Card::new(Rank::Three, Suit::Diamonds),
+                    Card::new(Rank::Four, Suit::Hearts),
+                ],
+            },
+        ];
+        let played = vec![Hand::from_cards(&[Card::new(Rank::Three, Suit::Hearts)]).unwrap()];
+
+        // Standard rules: spades outrank hearts
+        let mut standard = Game::new(
+            "1".to_string(),
+            players.clone(),
+            0,
+            0,
+            played.clone(),
+            std::collections::HashMap::new(),
+        );
+        assert!(standard
+            .play_cards("alice-uuid", &[Card::new(Rank::Three, Suit::Spades)])
+            .is_ok());
+
+        // Pusoy Dos: spades are below hearts
+        let mut pusoy = Game::new(
+            "2".to_string(),
+            players,
+            0,
+            0,
+            played,
+            std::collections::HashMap::new(),
+        )
+        .with_rules(RuleSet::pusoy_dos());
+        assert!(matches!(
+            pusoy.play_cards("alice-uuid", &[Card::new(Rank::Three, Suit::Spades)]),
+            Err(GameError::InvalidPlayedCards)
+        ));
+    }
+
+    #[test]
+    fn test_opener_must_play_lowest_dealt_card_when_nobody_holds_three_of_diamonds() {
+        let players = [
+            ("Alice", "alice-uuid", "9H KS"),
+            ("Bob", "bob-uuid", "3C 8D"),
+            ("Carol", "carol-uuid", "3H 5S"),
+            ("Dave", "dave-uuid", "4D 2S"),
+        ];
+        let mut game = Game::new_game_with_cards(
+            "no-3d".to_string(),
+            players
+                .iter()
+                .map(|(name, uuid, hand)| (name.to_string(), uuid.to_string(), cards(hand)))
+                .collect(),
+        )
+        .unwrap();
+
+        // 3♣ is the lowest card dealt, so its holder leads and must include it
+        assert_eq!(game.current_player_turn(), "bob-uuid");
+        let error = game.play_cards("bob-uuid", &cards("8D")).unwrap_err();
+        assert!(matches!(
+            error,
+            GameError::FirstTurnMustIncludeLowestCard(card) if card == Card::new(Rank::Three, Suit::Clubs)
+        ));
+        assert_eq!(error.code(), "FIRST_TURN_REQUIRES_LOWEST_CARD");
+        game.play_cards("bob-uuid", &cards("3C")).unwrap();
+    }
+
+    #[test]
+    fn test_pusoy_dos_opens_with_the_three_of_clubs() {
+        use crate::game::cards::RuleSet;
+
+        let mut game = Game::new_game_with_cards(
+            "pusoy".to_string(),
+            vec![
+                (
+                    "Alice".to_string(),
+                    "alice-uuid".to_string(),
+                    vec![
+                        Card::new(Rank::Three, Suit::Diamonds),
+                        Card::new(Rank::Nine, Suit::Hearts),
+                    ],
+                ),
+                (
+                    "Bob".to_string(),
+                    "bob-uuid".to_string(),
+                    vec![
+                        Card::new(Rank::Three, Suit::Clubs),
+                        Card::new(Rank::King, Suit::Hearts),
+                    ],
+                ),
+            ],
+        )
+        .unwrap();
+        assert_eq!(game.current_player_turn(), "alice-uuid");
+
+        // Clubs are the lowest suit, so the 3♣ holder leads and must play it
+        game = game.with_rules(RuleSet::pusoy_dos());
+        assert_eq!(game.current_player_turn(), "bob-uuid");
+        assert!(matches!(
+            game.play_cards("bob-uuid", &[Card::new(Rank::King, Suit::Hearts)]),
+            Err(GameError::FirstTurnMustIncludeLowestCard(card)) if card == Card::new(Rank::Three, Suit::Clubs)
+        ));
+        game.play_cards("bob-uuid", &[Card::new(Rank::Three, Suit::Clubs)])
+            .unwrap();
+    }
+
+    #[test]
+    fn test_new_game_with_cards_needs_an_opening_card() {
+        let result = Game::new_game_with_cards(
+            "empty".to_string(),
+            vec![
+                ("Alice".to_string(), "alice".to_string(), vec![]),
+                ("Bob".to_string(), "bob".to_string(), vec![]),
+            ],
+        );
+        let error = result.unwrap_err();
+        assert!(matches!(error, GameError::NoOpeningCard));
+        assert_eq!(error.code(), "NO_OPENING_CARD");
+    }
+
+    #[test]
+    fn test_dealt_pusoy_dos_game_opens_with_the_three_of_clubs() {
+        use crate::game::cards::RuleSet;
+        use crate::game::config::GameConfig;
+
+        let players: Vec<(String, String)> = ["a", "b", "c"]
+            .iter()
+            .map(|uuid| (uuid.to_string(), uuid.to_string()))
+            .collect();
+        let three_of_clubs = Card::new(Rank::Three, Suit::Clubs);
+        for seed in 0..50 {
+            let config = GameConfig {
+                rules: RuleSet::pusoy_dos(),
+                seed: Some(seed),
+                ..GameConfig::default()
+            };
+            let game = Game::new_game_with_config("pusoy".to_string(), &players, &config).unwrap();
+            let opener = game.current_player();
+            assert!(opener.cards.contains(&three_of_clubs), "seed {}", seed);
+        }
+    }
+
+    fn house_rules_game(house_rules: crate::game::config::HouseRules) -> Game {
+        Game::new_game_with_cards(
+            "house".to_string(),
+            vec![
+                (
+                    "Alice".to_string(),
+                    "alice-uuid".to_string(),
+                    vec![
+                        Card::new(Rank::Three, Suit::Diamonds),
+                        Card::new(Rank::Four, Suit::Clubs),
+                        Card::new(Rank::Nine, Suit::Clubs),
+                        Card::new(Rank::King, Suit::Spades),
+                    ],
+                ),
+                (
+                    "Bob".to_string(),
+                    "bob-uuid".to_string(),
+                    vec![
+                        Card::new(Rank::Five, Suit::Clubs),
+                        Card::new(Rank::Seven, Suit::Hearts),
+                    ],
+                ),
+                (
+                    "Charlie".to_string(),
+                    "charlie-uuid".to_string(),
+                    vec![
+                        Card::new(Rank::Six, Suit::Clubs),
+                        Card::new(Rank::Eight, Suit::Hearts),
+                    ],
+                ),
+                (
+                    "David".to_string(),
+                    "david-uuid".to_string(),
+                    vec![Card::new(Rank::Ace, Suit::Hearts)],
+                ),
+            ],
+        )
+        .unwrap()
+        .with_house_rules(house_rules)
+    }
+
+    #[test]
+    fn test_pass_lockout_until_table_clears() {
+        use crate::game::config::HouseRules;
+
+        let mut game = house_rules_game(HouseRules {
+            pass_lockout: true,
+            ..HouseRules::default()
+        });
+
+        game.play_cards("alice-uuid", &[Card::new(Rank::Three, Suit::Diamonds)])
+            .unwrap();
+        game.play_cards("bob-uuid", &[]).unwrap();
+        game.play_cards("charlie-uuid", &[Card::new(Rank::Six, Suit::Clubs)])
+            .unwrap();
+        game.play_cards("david-uuid", &[]).unwrap();
+        game.play_cards("alice-uuid", &[Card::new(Rank::Nine, Suit::Clubs)])
+            .unwrap();
+
+        // Bob passed earlier in this round and may not come back in
+        assert!(game.is_locked_out("bob-uuid"));
+        assert!(matches!(
+            game.play_cards("bob-uuid", &[Card::new(Rank::Seven, Suit::Hearts)]),
+            Err(GameError::LockedOutUntilTableClears)
+        ));
+        assert_eq!(GameError::LockedOutUntilTableClears.code(), "LOCKED_OUT");
+
+        // Passing is still allowed, and the lockout lifts once the table clears
+        game.play_cards("bob-uuid", &[]).unwrap();
+        game.play_cards("charlie-uuid", &[]).unwrap();
+        game.play_cards("david-uuid", &[]).unwrap();
+        assert!(game.is_table_clear());
+        assert!(!game.is_locked_out("bob-uuid"));
+    }
+
+    #[test]
+    fn test_pass_without_lockout_can_play_again() {
+        let mut game = house_rules_game(crate::game::config::HouseRules::default());
+
+        game.play_cards("alice-uuid", &[Card::new(Rank::Three, Suit::Diamonds)])
+            .unwrap();
+        game.play_cards("bob-uuid", &[]).unwrap();
+        game.play_cards("charlie-uuid", &[Card::new(Rank::Six, Suit::Clubs)])
+            .unwrap();
+        game.play_cards("david-uuid", &[]).unwrap();
+        game.play_cards("alice-uuid", &[Card::new(Rank::Nine, Suit::Clubs)])
+            .unwrap();
+
+        assert!(!game.is_locked_out("bob-uuid"));
+        assert!(game
+            .validate_move("bob-uuid", &[Card::new(Rank::Seven, Suit::Hearts)])
+            .is_err()); // 7 does not beat 9, but not because of a lockout
+        assert!(game.validate_move("bob-uuid", &[]).is_ok());
+    }
+
+    #[test]
+    fn test_highest_single_against_last_card() {
+        use crate::game::config::HouseRules;
+
+        let mut game = house_rules_game(HouseRules {
+            highest_single_against_last_card: true,
+            ..HouseRules::default()
+        });
+        game.play_cards("alice-uuid", &[Card::new(Rank::Three, Suit::Diamonds)])
+            .unwrap();
+        game.play_cards("bob-uuid", &[]).unwrap();
+
+        // David (next) has a single card left, so Charlie must play their highest single
+        assert!(matches!(
+            game.validate_move("charlie-uuid", &[Card::new(Rank::Six, Suit::Clubs)]),
+            Err(GameError::MustPlayHighestSingle)
+        ));
+        game.play_cards("charlie-uuid", &[Card::new(Rank::Eight, Suit::Hearts)])
+            .unwrap();
+    }
+
+    #[test]
+    fn test_opening_rule_holder_leads() {
+        use crate::game::config::{HouseRules, OpeningRule};
+
+        let strict = house_rules_game(HouseRules::default());
+        assert!(matches!(
+            strict.validate_move("alice-uuid", &[Card::new(Rank::Four, Suit::Clubs)]),
+            Err(GameError::FirstTurnMustIncludeThreeOfDiamonds)
+        ));
+
+        let mut relaxed = house_rules_game(HouseRules {
+            opening: OpeningRule::HolderLeads,
+            ..HouseRules::default()
+        });
+        assert_eq!(relaxed.current_player_turn(), "alice-uuid");
+        relaxed
+            .play_cards("alice-uuid", &[Card::new(Rank::Four, Suit::Clubs)])
+            .unwrap();
+    }
+
+    fn players(count: usize) -> Vec<(String, String)> {
+        ["Alice", "Bob", "Charlie", "David", "Eve"]
+            .iter()
+            .take(count)
+            .map(|name| (name.to_string(), format!("{}-uuid", name.to_lowercase())))
+            .collect()
+    }
+
+    #[test]
+    fn test_new_game_three_players() {
+        let game = Game::new_game("3p".to_string(), &players(3)).unwrap();
+
+        let three_of_diamonds = Card::new(Rank::Three, Suit::Diamonds);
+        let leader = game.current_player();
+        assert!(leader.cards.contains(&three_of_diamonds));
+        assert_eq!(leader.cards.len(), 18);
+        for player in game.players().iter().skip(1) {
+            assert_eq!(player.cards.len(), 17);
+        }
+        assert!(game.dead_pile().is_empty());
+    }
+
+    #[test]
+    fn test_new_game_two_players() {
+        let game = Game::new_game("2p".to_string(), &players(2)).unwrap();
+
+        assert!(game.players().iter().all(|p| p.cards.len() == 13));
+        assert_eq!(game.dead_pile().len(), 26);
+
+        let mut all_cards: Vec<Card> = game
+            .players()
+            .iter()
+            .flat_map(|p| p.cards.clone())
+            .chain(game.dead_pile().iter().copied())
+            .collect();
+        all_cards.sort();
+        let mut deck = Card::all_cards();
+        deck.sort();
+        assert_eq!(all_cards, deck);
+
+        // The leader holds the lowest dealt card, which the opening play must include
+        let leader = game.current_player();
+        let lowest = game
+            .players()
+            .iter()
+            .flat_map(|p| p.cards.iter())
+            .min()
+            .unwrap();
+        assert!(leader.cards.contains(lowest));
+    }
+
+    #[rstest::rstest]
+    #[case(1)]
+    #[case(5)]
+    fn test_new_game_rejects_player_count(#[case] count: usize) {
+        let result = Game::new_game("bad".to_string(), &players(count));
+        assert!(matches!(result, Err(GameError::UnsupportedPlayerCount(n)) if n == count));
+    }
+
+    #[test]
+    fn test_opening_without_three_of_diamonds_requires_lowest_card() {
+        let mut game = Game::new_game_with_cards(
+            "2p".to_string(),
+            vec![
+                (
+                    "Alice".to_string(),
+                    "alice-uuid".to_string(),
+                    vec![
+                        Card::new(Rank::Five, Suit::Spades),
+                        Card::new(Rank::Nine, Suit::Hearts),
+                    ],
+                ),
+                (
+                    "Bob".to_string(),
+                    "bob-uuid".to_string(),
+                    vec![
+                        Card::new(Rank::Four, Suit::Clubs),
+                        Card::new(Rank::King, Suit::Hearts),
+                    ],
+                ),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(game.current_player_turn(), "bob-uuid");
+        assert!(matches!(
+            game.play_cards("bob-uuid", &[Card::new(Rank::King, Suit::Hearts)]),
+            Err(GameError::FirstTurnMustIncludeLowestCard(card)) if card == Card::new(Rank::Four, Suit::Clubs)
+        ));
+        game.play_cards("bob-uuid", &[Card::new(Rank::Four, Suit::Clubs)])
+            .unwrap();
+    }
+
+    #[test]
+    fn test_table_clears_after_one_pass_with_two_players() {
+        let mut game = Game::new_game_with_cards(
+            "2p".to_string(),
+            vec![
+                (
+                    "Alice".to_string(),
+                    "alice-uuid".to_string(),
+                    vec![
+                        Card::new(Rank::Three, Suit::Diamonds),
+                        Card::new(Rank::Four, Suit::Hearts),
+                    ],
+                ),
+                (
+                    "Bob".to_string(),
+                    "bob-uuid".to_string(),
+                    vec![
+                        Card::new(Rank::Five, Suit::Clubs),
+                        Card::new(Rank::King, Suit::Hearts),
+                    ],
+                ),
+            ],
+        )
+        .unwrap();
+
+        game.play_cards("alice-uuid", &[Card::new(Rank::Three, Suit::Diamonds)])
+            .unwrap();
+        game.play_cards("bob-uuid", &[]).unwrap();
+
+        // Alice leads again and may not pass on a clear table
+        assert!(game.is_table_clear());
+        assert!(matches!(
+            game.play_cards("alice-uuid", &[]),
+            Err(GameError::CannotPass)
+        ));
+        assert_eq!(
+            game.last_non_pass_player_uuid().as_deref(),
+            Some("alice-uuid")
+        );
+    }
+
+    #[test]
+    fn test_same_seed_same_deal() {
+        let first = Game::new_game_with_seed("a".to_string(), &players(4), 42).unwrap();
+        let second = Game::new_game_with_seed("b".to_string(), &players(4), 42).unwrap();
+        let other = Game::new_game_with_seed("c".to_string(), &players(4), 43).unwrap();
+
+        assert_eq!(first.deal_seed(), Some(42));
+        assert_eq!(first.starting_hands(), second.starting_hands());
+        assert_eq!(first.current_player_turn(), second.current_player_turn());
+        assert_ne!(first.starting_hands(), other.starting_hands());
+    }
+
+    #[test]
+    fn test_seeded_deal_is_pinned() {
+        // The shuffle must not change between platforms or library versions, or old seeds stop
+        // replaying their deals
+        let game = Game::new_game_with_seed("a".to_string(), &players(4), 42).unwrap();
+        let alice: Vec<String> = game.starting_hands()["alice-uuid"]
+            .iter()
+            .map(Card::to_string)
+            .collect();
+        assert_eq!(alice.join(" "), "3H 4C 4H 5H 6D 6H 8C 9C TS KH AD 2H 2S");
+    }
+
+    #[test]
+    fn test_random_seeds_fit_in_a_javascript_number() {
+        for _ in 0..100 {
+            let game =
+                Game::new_game_with_config("js".to_string(), &players(4), &GameConfig::default())
+                    .unwrap();
+            assert!(game.deal_seed().unwrap() <= MAX_SEED);
+        }
+    }
+
+    #[test]
+    fn test_deal_seed_only_for_dealt_games() {
+        let dealt = Game::new_game("dealt".to_string(), &players(4)).unwrap();
+        assert!(dealt.deal_seed().is_some());
+
+        let picked = Game::new_game_with_cards(
+            "picked".to_string(),
+            vec![(
+                "Alice".to_string(),
+                "alice-uuid".to_string(),
+                vec![Card::new(Rank::Three, Suit::Diamonds)],
+            )],
+        )
+        .unwrap();
+        assert!(picked.deal_seed().is_none());
+    }
+
+    #[test]
+    fn test_move_log_records_every_action() {
+        let mut game = Game::new_game_with_cards(
+            "log".to_string(),
+            vec![
+                (
+                    "Alice".to_string(),
+                    "alice-uuid".to_string(),
+                    vec![
+                        Card::new(Rank::Three, Suit::Diamonds),
+                        Card::new(Rank::Four, Suit::Hearts),
+                    ],
+                ),
+                (
+                    "Bob".to_string(),
+                    "bob-uuid".to_string(),
+                    vec![
+                        Card::new(Rank::Five, Suit::Clubs),
+                        Card::new(Rank::King, Suit::Hearts),
+                    ],
+                ),
+            ],
+        )
+        .unwrap();
+
+        game.play_cards("alice-uuid", &[Card::new(Rank::Three, Suit::Diamonds)])
+            .unwrap();
+        game.play_cards("bob-uuid", &[]).unwrap();
+        game.play_cards("alice-uuid", &[Card::new(Rank::Four, Suit::Hearts)])
+            .unwrap();
+
+        // Rejected moves are not recorded
+        assert!(game.play_cards("alice-uuid", &[]).is_err());
+
+        let moves = game.moves();
+        assert_eq!(moves.len(), 3);
+        assert_eq!(
+            moves
+                .iter()
+                .map(|m| (m.player_uuid.as_str(), m.turn, m.table_clear))
+                .collect::<Vec<_>>(),
+            vec![
+                ("alice-uuid", 0, true),
+                ("bob-uuid", 1, false),
+                ("alice-uuid", 2, true),
+            ]
+        );
+        assert_eq!(moves[1].hand, Hand::Pass);
+        assert_eq!(
+            moves[2].hand,
+            Hand::from_cards(&[Card::new(Rank::Four, Suit::Hearts)]).unwrap()
+        );
+        assert!(moves[0].played_at <= moves[2].played_at);
+        assert_eq!(
+            game.last_non_pass_player_uuid().as_deref(),
+            Some("alice-uuid")
+        );
+    }
+
+    #[test]
+    fn test_with_leader_keeps_seating_and_skips_opening_card() {
+        let mut game = Game::new_game_with_seed("lead".to_string(), &players(4), 3)
+            .unwrap()
+            .with_leader("charlie-uuid");
+
+        assert_eq!(game.current_player_turn(), "charlie-uuid");
+        let seats: Vec<&str> = game.players().iter().map(|p| p.uuid.as_str()).collect();
+        let charlie = seats.iter().position(|s| *s == "charlie-uuid").unwrap();
+        assert_eq!(charlie, 0);
+        assert_eq!(seats.len(), 4);
+
+        // Charlie may open with any card, whether or not they hold the 3♦
+        let card = *game
+            .current_player()
+            .cards
+            .iter()
+            .find(|c| **c != Card::new(Rank::Three, Suit::Diamonds))
+            .unwrap();
+        game.play_cards("charlie-uuid", &[card]).unwrap();
+
+        // Choosing the rule set afterwards does not hand the lead back to the opening card
+        let game = Game::new_game_with_seed("lead".to_string(), &players(4), 3)
+            .unwrap()
+            .with_leader("charlie-uuid")
+            .with_rules(crate::game::cards::RuleSet::pusoy_dos());
+        assert_eq!(game.current_player_turn(), "charlie-uuid");
+    }
+
+    #[test]
+    fn test_finishing_order_plays_out_every_place() {
+        let mut game = Game::new_game_with_cards(
+            "places".to_string(),
+            vec![
+                ("Alice".to_string(), "alice".to_string(), cards("3D 8C")),
+                ("Bob".to_string(), "bob".to_string(), cards("4D 2S")),
+                ("Carol".to_string(), "carol".to_string(), cards("5D 6D")),
+                ("Dave".to_string(), "dave".to_string(), cards("7D 9D")),
+            ],
+        )
+        .unwrap()
+        .with_house_rules(crate::game::config::HouseRules {
+            finishing_order: true,
+            ..Default::default()
+        });
+
+        game.play_cards("alice", &cards("3D")).unwrap();
+        game.play_cards("bob", &cards("2S")).unwrap();
+        for player in ["carol", "dave", "alice"] {
+            game.play_cards(player, &[]).unwrap();
+        }
+
+        // Bob goes out first, but the game carries on
+        assert!(!game.play_cards("bob", &cards("4D")).unwrap());
+        assert_eq!(game.winner().as_deref(), Some("bob"));
+        assert!(!game.is_over());
+
+        // Everyone left has to pass before the seat after Bob leads
+        game.play_cards("carol", &[]).unwrap();
+        game.play_cards("dave", &[]).unwrap();
+        assert!(!game.is_table_clear());
+        game.play_cards("alice", &[]).unwrap();
+        assert!(game.is_table_clear());
+        assert_eq!(game.current_player_turn(), "carol");
+
+        game.play_cards("carol", &cards("5D")).unwrap();
+        game.play_cards("dave", &cards("7D")).unwrap();
+        assert!(!game.play_cards("alice", &cards("8C")).unwrap());
+
+        // Bob and Alice are skipped from now on
+        assert_eq!(game.current_player_turn(), "carol");
+        game.play_cards("carol", &[]).unwrap();
+        game.play_cards("dave", &[]).unwrap();
+        assert_eq!(game.current_player_turn(), "carol");
+
+        // Carol takes third, which leaves Dave last
+        assert!(game.play_cards("carol", &cards("6D")).unwrap());
+        assert!(game.is_over());
+        assert_eq!(game.finishing_order(), ["bob", "alice", "carol", "dave"]);
+        assert_eq!(game.winner().as_deref(), Some("bob"));
+        assert_eq!(game.winning_hand(), Some(cards("4D")));
+    }
+
+    fn dealt_game(house_rules: crate::game::config::HouseRules, alice: &str, bob: &str) -> Game {
+        Game::new_game_with_cards(
+            "deal".to_string(),
+            vec![
+                ("Alice".to_string(), "alice".to_string(), cards(alice)),
+                ("Bob".to_string(), "bob".to_string(), cards(bob)),
+            ],
+        )
+        .unwrap()
+        .with_house_rules(house_rules)
+    }
+
+    #[test]
+    fn test_claim_instant_win() {
+        let dragon = "3D 4C 5H 6S 7D 8C 9H TS JD QC KH AS 2D";
+        let rules = crate::game::config::HouseRules {
+            instant_wins: true,
+            ..Default::default()
+        };
+
+        // Off by default
+        let mut game = dealt_game(Default::default(), "3C", dragon);
+        assert_eq!(game.claim_instant_win(), None);
+
+        let mut game = dealt_game(rules, "3C", dragon);
+        assert_eq!(
+            game.claim_instant_win(),
+            Some(crate::game::deal::InstantWin::Dragon)
+        );
+        assert!(game.is_over());
+        assert_eq!(game.winner().as_deref(), Some("bob"));
+        assert_eq!(game.winning_hand().unwrap().len(), 13);
+        assert!(game
+            .players()
+            .iter()
+            .any(|p| p.uuid == "bob" && p.cards.is_empty()));
+
+        // Two dragons: bob opens with the 3D, so he claims first though alice sits first
+        let mut game = dealt_game(rules, "3C 4D 5D 6D 7C 8D 9D TD JC QD KD AD 2C", dragon);
+        assert_eq!(game.current_player_turn(), "bob");
+        game.claim_instant_win();
+        assert_eq!(game.winner().as_deref(), Some("bob"));
+
+        // Too late once play has started
+        let mut game = dealt_game(rules, "3D", "2D 2C 2H 2S");
+        game.play_cards("alice", &[Card::new(Rank::Three, Suit::Diamonds)])
+            .unwrap();
+        assert_eq!(game.claim_instant_win(), None);
+    }
+
+    #[test]
+    fn test_validate_redeal() {
+        let rules = crate::game::config::HouseRules {
+            misdeal_max_rank: Some(Rank::Ten),
+            ..Default::default()
+        };
+
+        let game = dealt_game(Default::default(), "3D 4C", "5H TS");
+        assert!(matches!(
+            game.validate_redeal("bob"),
+            Err(GameError::RedealNotAllowed)
+        ));
+
+        let mut game = dealt_game(rules, "3D JC", "5H TS");
+        assert!(game.validate_redeal("bob").is_ok());
+        assert!(game.validate_redeal("alice").is_err());
+        assert!(game.validate_redeal("nobody").is_err());
+
+        game.play_cards("alice", &[Card::new(Rank::Three, Suit::Diamonds)])
+            .unwrap();
+        assert!(game.validate_redeal("bob").is_err());
+    }
+
+    #[test]
+    fn test_take_back_play_and_pass() {
+        let three = Card::new(Rank::Three, Suit::Diamonds);
+        let mut game = dealt_game(Default::default(), "3D 5H 9C", "4C 8D");
+        game.play_cards("alice", &[three]).unwrap();
+        assert!(matches!(
+            game.take_back("alice"),
+            Err(GameError::TakeBackDisabled)
+        ));
+
+        let mut game = dealt_game(Default::default(), "3D 5H 9C", "4C 8D")
+            .with_take_back_rule(TakeBackRule::UntilNextMove);
+        assert!(!game.can_take_back("alice"));
+
+        game.play_cards("alice", &[three]).unwrap();
+        assert!(game.can_take_back("alice"));
+        assert!(matches!(
+            game.take_back("bob"),
+            Err(GameError::NothingToTakeBack)
+        ));
+
+        assert_eq!(game.take_back("alice").unwrap(), vec![three]);
+        assert_eq!(game.current_player_turn(), "alice");
+        assert!(game.players()[0].cards.contains(&three));
+        assert!(game.played_hands().is_empty());
+        assert!(game.moves().is_empty());
+        // Only one step back
+        assert!(!game.can_take_back("alice"));
+
+        game.play_cards("alice", &[three]).unwrap();
+        game.play_cards("bob", &[]).unwrap();
+        assert_eq!(game.consecutive_passes(), 1);
+        assert!(!game.can_take_back("alice"));
+
+        assert!(game.take_back("bob").unwrap().is_empty());
+        assert_eq!(game.consecutive_passes(), 0);
+        assert_eq!(game.current_player_turn(), "bob");
+        assert_eq!(game.played_hands().len(), 1);
+    }
+
+    #[test]
+    fn test_take_back_restores_finishing_order() {
+        let mut game = Game::new_game_with_cards(
+            "undo".to_string(),
+            vec![
+                ("Alice".to_string(), "alice".to_string(), cards("3D 8C")),
+                ("Bob".to_string(), "bob".to_string(), cards("4D")),
+                ("Carol".to_string(), "carol".to_string(), cards("5D 6D")),
+            ],
+        )
+        .unwrap()
+        .with_house_rules(crate::game::config::HouseRules {
+            finishing_order: true,
+            ..Default::default()
+        })
+        .with_take_back_rule(TakeBackRule::UntilNextMove);
+
+        game.play_cards("alice", &cards("3D")).unwrap();
+        game.play_cards("bob", &cards("4D")).unwrap();
+        assert_eq!(game.finishing_order(), ["bob".to_string()]);
+
+        game.take_back("bob").unwrap();
+        assert!(game.finishing_order().is_empty());
+        assert_eq!(game.current_player_turn(), "bob");
+        assert_eq!(game.players()[1].cards, cards("4D"));
+    }
+
+    #[test]
+    fn test_no_take_back_once_the_game_is_over() {
+        let mut game = dealt_game(Default::default(), "3D", "4C")
+            .with_take_back_rule(TakeBackRule::UntilNextMove);
+        assert!(game
+            .play_cards("alice", &[Card::new(Rank::Three, Suit::Diamonds)])
+            .unwrap());
+        assert!(!game.can_take_back("alice"));
+        assert!(game.take_back("alice").is_err());
+    }
+
+    #[test]
+    fn test_take_back_by_consent() {
+        let three = Card::new(Rank::Three, Suit::Diamonds);
+        let mut game = Game::new_game_with_cards(
+            "consent".to_string(),
+            vec![
+                ("Alice".to_string(), "alice".to_string(), cards("3D 8C")),
+                ("Bob".to_string(), "bob".to_string(), cards("4D 9C")),
+                ("Carol".to_string(), "carol".to_string(), cards("5D 6D")),
+            ],
+        )
+        .unwrap()
+        .with_take_back_rule(TakeBackRule::UnanimousConsent);
+        game.play_cards("alice", &[three]).unwrap();
+
+        // Nobody has agreed yet
+        assert!(matches!(
+            game.take_back("alice"),
+            Err(GameError::TakeBackNeedsConsent)
+        ));
+        assert_eq!(game.vote_take_back("bob", true), None);
+
+        // One refusal drops the request
+        assert_eq!(
+            game.request_take_back("alice").unwrap(),
+            TakeBackRequest::AwaitingVotes(vec!["bob".to_string(), "carol".to_string()])
+        );
+        assert_eq!(game.vote_take_back("alice", true), None);
+        assert_eq!(
+            game.vote_take_back("bob", false),
+            Some(TakeBackVote::Declined {
+                player: "alice".to_string()
+            })
+        );
+        assert_eq!(game.vote_take_back("carol", true), None);
+
+        // Everyone else agreeing undoes the play
+        game.request_take_back("alice").unwrap();
+        assert_eq!(
+            game.vote_take_back("bob", true),
+            Some(TakeBackVote::Waiting)
+        );
+        assert_eq!(
+            game.vote_take_back("carol", true),
+            Some(TakeBackVote::TakenBack {
+                player: "alice".to_string(),
+                cards: vec![three],
+            })
+        );
+        assert!(game.played_hands().is_empty());
+
+        // A request goes stale once the next player acts
+        game.play_cards("alice", &[three]).unwrap();
+        game.request_take_back("alice").unwrap();
+        game.play_cards("bob", &cards("4D")).unwrap();
+        assert_eq!(game.vote_take_back("carol", true), None);
     }
 }
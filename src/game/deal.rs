@@ -0,0 +1,60 @@
+// Checks run on freshly dealt hands, before anyone has played: hands that win on the spot
+// and hands weak enough that their holder may ask for a redeal.
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+
+use crate::game::cards::{Card, Rank};
+
+/// A dealt hand that wins the game without being played
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InstantWin {
+    /// One card of every rank, 3 through 2
+    Dragon,
+    /// All four Twos
+    FourTwos,
+}
+
+impl InstantWin {
+    /// The instant win in a hand, if any; a dragon takes precedence over four Twos
+    pub fn detect(cards: &[Card]) -> Option<Self> {
+        if Rank::iter().all(|rank| cards.iter().any(|c| c.rank == rank)) {
+            return Some(Self::Dragon);
+        }
+        if cards.iter().filter(|c| c.rank == Rank::Two).count() == 4 {
+            return Some(Self::FourTwos);
+        }
+        None
+    }
+}
+
+/// Whether a hand is weak enough to ask for a redeal: no card ranked above `max_rank`
+pub fn is_misdeal(cards: &[Card], max_rank: Rank) -> bool {
+    cards.iter().all(|c| c.rank <= max_rank)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::test_utils::cards;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("3D 4C 5H 6S 7D 8C 9H TS JD QC KH AS 2D", Some(InstantWin::Dragon))]
+    #[case("2D 2C 2H 2S 3D 4D 5D 6D 7D 8D 9D TD JD", Some(InstantWin::FourTwos))]
+    #[case("3D 4C 5H 6S 7D 8C 9H TS JD QC KH AS AD", None)]
+    #[case("2D 2C 2H 3D 4D 5D 6D 7D 8D 9D TD JD QD", None)]
+    fn test_detect_instant_win(#[case] hand: &str, #[case] expected: Option<InstantWin>) {
+        assert_eq!(InstantWin::detect(&cards(hand)), expected);
+    }
+
+    #[test]
+    fn test_misdeal_threshold() {
+        let weak = cards("3D 4C 5H 6S 7D 8C 9H TS TD 3C 4H 5S 6D");
+        assert!(is_misdeal(&weak, Rank::Ten));
+        assert!(!is_misdeal(&weak, Rank::Nine));
+
+        let with_jack = cards("3D 4C 5H 6S 7D 8C 9H TS JD 3C 4H 5S 6D");
+        assert!(!is_misdeal(&with_jack, Rank::Ten));
+    }
+}
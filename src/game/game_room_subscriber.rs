@@ -1,13 +1,47 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use tracing::info;
+use tracing::{info, warn};
 
 use crate::{
     event::{EventBus, RoomEvent, RoomEventError, RoomEventHandler},
-    game::{cards::Card, service::GameService},
+    game::{
+        cards::{parse_play, Card, PlayParseError},
+        config::GameConfig,
+        core::{Game, GameError, TakeBackRequest, TakeBackVote},
+        service::GameService,
+    },
+    shared::AppError,
 };
 
+/// A refusal that can be reported back to the player whose request it was
+trait Rejection: std::fmt::Display + Sync {
+    /// Machine-readable code sent along with the message
+    fn rejection_code(&self) -> &'static str;
+}
+
+impl Rejection for AppError {
+    fn rejection_code(&self) -> &'static str {
+        match self {
+            AppError::GameError(game_error) => game_error.code(),
+            AppError::NotFound(_) => "GAME_NOT_FOUND",
+            _ => "INTERNAL_ERROR",
+        }
+    }
+}
+
+impl Rejection for GameError {
+    fn rejection_code(&self) -> &'static str {
+        self.code()
+    }
+}
+
+impl Rejection for PlayParseError {
+    fn rejection_code(&self) -> &'static str {
+        self.code()
+    }
+}
+
 pub struct GameEventRoomSubscriber {
     game_service: Arc<GameService>,
     event_bus: EventBus,
@@ -27,13 +61,27 @@ impl RoomEventHandler for GameEventRoomSubscriber {
         );
 
         match event {
-            RoomEvent::CreateGame { players } => {
-                self.handle_create_game(room_id, &players).await?;
+            RoomEvent::CreateGame { players, config } => {
+                self.handle_create_game(room_id, &players, config).await?;
             }
             RoomEvent::TryPlayMove { player, cards } => {
                 self.handle_player_played_move(room_id, &player, &cards)
                     .await?;
             }
+            RoomEvent::TryPlayText { player, text } => {
+                self.handle_player_typed_move(room_id, &player, &text)
+                    .await?;
+            }
+            RoomEvent::RedealRequested { player } => {
+                self.handle_redeal_requested(room_id, &player).await?;
+            }
+            RoomEvent::TryTakeBack { player } => {
+                self.handle_try_take_back(room_id, &player).await?;
+            }
+            RoomEvent::TakeBackVote { player, approve } => {
+                self.handle_take_back_vote(room_id, &player, approve)
+                    .await?;
+            }
             RoomEvent::GameWon {
                 winner,
                 winning_hand,
@@ -60,26 +108,275 @@ impl GameEventRoomSubscriber {
         }
     }
 
+    /// Tell a player why their request was refused
+    async fn reject(&self, room_id: &str, player: &str, error: &impl Rejection) {
+        self.event_bus
+            .emit_to_room(
+                room_id,
+                RoomEvent::RequestRejected {
+                    player: player.to_string(),
+                    code: error.rejection_code().to_string(),
+                    message: error.to_string(),
+                },
+            )
+            .await;
+    }
+
     async fn handle_create_game(
         &self,
         room_id: &str,
         players: &[String],
+        config: GameConfig,
     ) -> Result<(), RoomEventError> {
         info!(room_id = %room_id, "Starting Game");
 
         let game = self
             .game_service
-            .create_game(room_id, players)
+            .create_game(room_id, players, config)
             .await
             .map_err(|e| RoomEventError::HandlerError(format!("Failed to create game: {}", e)))?;
 
-        let game_message = RoomEvent::StartGame { game: game.clone() };
+        self.start_dealt_game(room_id, &game).await;
 
+        Ok(())
+    }
+
+    /// Announce a freshly dealt game, unless a dealt hand wins it outright
+    async fn start_dealt_game(&self, room_id: &str, game: &Game) {
+        // Settle an instant win first so every view of the deal already shows the result
+        let claimed = self
+            .game_service
+            .claim_instant_win(room_id)
+            .await
+            .unwrap_or_else(|e| {
+                warn!(room_id = %room_id, error = %e, "Failed to check the deal for an instant win");
+                None
+            });
+        let game = claimed.as_ref().map_or(game, |(game, _)| game);
+
+        // Players see the deal before learning it was won outright
+        let game_message = RoomEvent::StartGame {
+            players: game.players().iter().map(|p| p.uuid.clone()).collect(),
+            current_turn: game.current_player_turn(),
+        };
         self.event_bus.emit_to_room(room_id, game_message).await;
 
+        let Some((game, kind)) = claimed else {
+            return;
+        };
+        let winner = game
+            .winner()
+            .expect("a claimed instant win always has a winner");
+        let hand = game.winning_hand().unwrap_or_default();
+        info!(room_id = %room_id, winner = %winner, kind = ?kind, "Instant win dealt");
+
+        self.event_bus
+            .emit_to_room(
+                room_id,
+                RoomEvent::InstantWin {
+                    player: winner.clone(),
+                    kind,
+                    hand: hand.clone(),
+                },
+            )
+            .await;
+        self.event_bus
+            .emit_to_room(
+                room_id,
+                RoomEvent::GameWon {
+                    winner,
+                    winning_hand: hand,
+                },
+            )
+            .await;
+    }
+
+    async fn handle_redeal_requested(
+        &self,
+        room_id: &str,
+        player_uuid: &str,
+    ) -> Result<(), RoomEventError> {
+        info!(room_id = %room_id, player_uuid = %player_uuid, "Player requested a redeal");
+
+        let game = match self.game_service.redeal(room_id, player_uuid).await {
+            Ok(game) => game,
+            Err(e) => {
+                self.reject(room_id, player_uuid, &e).await;
+
+                return Err(RoomEventError::HandlerError(format!(
+                    "Failed to redeal: {}",
+                    e
+                )));
+            }
+        };
+
+        self.event_bus
+            .emit_to_room(
+                room_id,
+                RoomEvent::Misdeal {
+                    player: player_uuid.to_string(),
+                },
+            )
+            .await;
+        self.start_dealt_game(room_id, &game).await;
+
+        Ok(())
+    }
+
+    async fn handle_try_take_back(
+        &self,
+        room_id: &str,
+        player_uuid: &str,
+    ) -> Result<(), RoomEventError> {
+        info!(room_id = %room_id, player_uuid = %player_uuid, "Take-back requested");
+
+        let (game, request) = match self
+            .game_service
+            .request_take_back(room_id, player_uuid)
+            .await
+        {
+            Ok(result) => result,
+            Err(e) => {
+                self.reject(room_id, player_uuid, &e).await;
+
+                return Err(RoomEventError::HandlerError(format!(
+                    "Failed to take back: {}",
+                    e
+                )));
+            }
+        };
+
+        match request {
+            TakeBackRequest::TakenBack(cards) => {
+                self.announce_take_back(room_id, &game, player_uuid, cards)
+                    .await;
+            }
+            TakeBackRequest::AwaitingVotes(voters) => {
+                self.event_bus
+                    .emit_to_room(
+                        room_id,
+                        RoomEvent::TakeBackRequested {
+                            player: player_uuid.to_string(),
+                            voters,
+                        },
+                    )
+                    .await;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn handle_take_back_vote(
+        &self,
+        room_id: &str,
+        voter: &str,
+        approve: bool,
+    ) -> Result<(), RoomEventError> {
+        info!(room_id = %room_id, voter = %voter, approve = approve, "Take-back vote");
+
+        let vote = match self
+            .game_service
+            .vote_take_back(room_id, voter, approve)
+            .await
+        {
+            Ok(vote) => vote,
+            Err(e) => {
+                self.reject(room_id, voter, &e).await;
+
+                return Err(RoomEventError::HandlerError(format!(
+                    "Failed to vote: {}",
+                    e
+                )));
+            }
+        };
+
+        // A stale or stray vote has nothing left to decide
+        match vote {
+            None | Some((_, TakeBackVote::Waiting)) => {}
+            Some((_, TakeBackVote::Declined { player })) => {
+                self.reject(room_id, &player, &GameError::TakeBackDeclined)
+                    .await;
+            }
+            Some((game, TakeBackVote::TakenBack { player, cards })) => {
+                self.announce_take_back(room_id, &game, &player, cards)
+                    .await;
+            }
+        }
+
         Ok(())
     }
 
+    async fn announce_take_back(
+        &self,
+        room_id: &str,
+        game: &Game,
+        player_uuid: &str,
+        cards: Vec<Card>,
+    ) {
+        self.event_bus
+            .emit_to_room(
+                room_id,
+                RoomEvent::MoveTakenBack {
+                    player: player_uuid.to_string(),
+                    cards,
+                },
+            )
+            .await;
+        self.event_bus
+            .emit_to_room(
+                room_id,
+                RoomEvent::TurnChanged {
+                    player: game.current_player_turn(),
+                },
+            )
+            .await;
+    }
+
+    /// Read a typed move against the player's hand, then play it like any other move
+    async fn handle_player_typed_move(
+        &self,
+        room_id: &str,
+        player_uuid: &str,
+        text: &str,
+    ) -> Result<(), RoomEventError> {
+        let game = self.game_service.get_game(room_id).await.and_then(|game| {
+            game.ok_or_else(|| AppError::NotFound(format!("Game not found for room: {}", room_id)))
+        });
+        let game = match game {
+            Ok(game) => game,
+            Err(e) => {
+                self.reject(room_id, player_uuid, &e).await;
+
+                return Err(RoomEventError::HandlerError(format!(
+                    "Failed to read move: {}",
+                    e
+                )));
+            }
+        };
+        let hand = game
+            .players()
+            .iter()
+            .find(|p| p.uuid == player_uuid)
+            .map(|p| p.cards.clone())
+            .unwrap_or_default();
+
+        match parse_play(text, &hand, game.rules()) {
+            Ok(cards) => {
+                self.handle_player_played_move(room_id, player_uuid, &cards)
+                    .await
+            }
+            Err(e) => {
+                self.reject(room_id, player_uuid, &e).await;
+
+                Err(RoomEventError::HandlerError(format!(
+                    "Failed to read move: {}",
+                    e
+                )))
+            }
+        }
+    }
+
     async fn handle_player_played_move(
         &self,
         room_id: &str,
@@ -89,13 +386,46 @@ impl GameEventRoomSubscriber {
         info!(room_id = %room_id, player_uuid = %player_uuid, cards = ?cards, "Player played move");
 
         // Execute the move using GameService
-        let move_result = self
+        let move_result = match self
             .game_service
             .try_play_move(room_id, player_uuid, cards)
             .await
-            .map_err(|e| RoomEventError::HandlerError(format!("Failed to play move: {}", e)))?;
+        {
+            Ok(move_result) => move_result,
+            Err(e) => {
+                // Let the originating player know why their move was refused
+                self.reject(room_id, player_uuid, &e).await;
+
+                return Err(RoomEventError::HandlerError(format!(
+                    "Failed to play move: {}",
+                    e
+                )));
+            }
+        };
+
+        let winner = move_result.game.winner();
+
+        // The winning move is announced by GameWon itself; any other move is played out first
+        if !(move_result.player_won && winner.as_deref() == Some(player_uuid)) {
+            self.event_bus
+                .emit_to_room(
+                    room_id,
+                    RoomEvent::MovePlayed {
+                        player: player_uuid.to_string(),
+                        cards: cards.to_vec(),
+                        remaining_cards: move_result
+                            .game
+                            .players()
+                            .iter()
+                            .find(|p| p.uuid == player_uuid)
+                            .map(|p| p.cards.len())
+                            .unwrap_or(0),
+                    },
+                )
+                .await;
+        }
 
-        // If player won, emit GameWon event and return
+        // If the game is over, emit GameWon event and return
         if move_result.player_won {
             let winning_hand = move_result
                 .winning_hand
@@ -104,7 +434,7 @@ impl GameEventRoomSubscriber {
                 .emit_to_room(
                     room_id,
                     RoomEvent::GameWon {
-                        winner: player_uuid.to_string(),
+                        winner: winner.unwrap_or_else(|| player_uuid.to_string()),
                         winning_hand,
                     },
                 )
@@ -112,18 +442,6 @@ impl GameEventRoomSubscriber {
             return Ok(());
         }
 
-        // Emit move played event
-        self.event_bus
-            .emit_to_room(
-                room_id,
-                RoomEvent::MovePlayed {
-                    player: player_uuid.to_string(),
-                    cards: cards.to_vec(),
-                    game: move_result.game.clone(),
-                },
-            )
-            .await;
-
         // Emit turn changed event with the new current player
         self.event_bus
             .emit_to_room(
@@ -145,6 +463,23 @@ impl GameEventRoomSubscriber {
     ) -> Result<(), RoomEventError> {
         info!(room_id = %room_id, winner = %winner, "Game won");
 
+        // The game was added to the match when it ended; announce the result if that was the last
+        if let Some(current_match) = self
+            .game_service
+            .get_match(room_id)
+            .await
+            .filter(|m| m.is_over())
+        {
+            self.event_bus
+                .emit_to_room(
+                    room_id,
+                    RoomEvent::MatchOver {
+                        standings: current_match.standings(),
+                    },
+                )
+                .await;
+        }
+
         Ok(())
     }
 }
@@ -155,7 +490,7 @@ mod tests {
     use crate::game::{
         cards::{Card, Rank, Suit},
         core::{Game, Player},
-        GameService,
+        GameRepository, GameService, HouseRules, InMemoryGameRepository,
     };
     use crate::user::{mapping_service::InMemoryPlayerMappingService, PlayerMappingService};
 
@@ -251,11 +586,13 @@ mod tests {
                 .unwrap();
         }
 
-        let result = subscriber.handle_create_game("test_room", &players).await;
+        let result = subscriber
+            .handle_create_game("test_room", &players, GameConfig::default())
+            .await;
         assert!(result.is_ok());
 
         // Verify game was created
-        let game = game_service.get_game("test_room").await;
+        let game = game_service.get_game("test_room").await.unwrap();
         assert!(game.is_some());
 
         let game = game.unwrap();
@@ -289,12 +626,12 @@ mod tests {
         }
 
         game_service
-            .create_game("test_room", &players)
+            .create_game("test_room", &players, GameConfig::default())
             .await
             .unwrap();
 
         // Get the game to find who has 3D
-        let game = game_service.get_game("test_room").await.unwrap();
+        let game = game_service.get_game("test_room").await.unwrap().unwrap();
         let player_with_3d = game
             .players()
             .iter()
@@ -354,12 +691,12 @@ mod tests {
         }
 
         game_service
-            .create_game("test_room", &players)
+            .create_game("test_room", &players, GameConfig::default())
             .await
             .unwrap();
 
         // Get the game to see who should play first
-        let game = game_service.get_game("test_room").await.unwrap();
+        let game = game_service.get_game("test_room").await.unwrap().unwrap();
         let current_player = game.current_player_turn();
 
         // Find a different player (not current)
@@ -382,4 +719,200 @@ mod tests {
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("Invalid player"));
     }
+
+    #[tokio::test]
+    async fn test_handle_player_played_move_emits_rejection() {
+        let player_mapping = Arc::new(InMemoryPlayerMappingService::new());
+        let game_service = Arc::new(GameService::new(player_mapping));
+        let event_bus = EventBus::new();
+        let mut receiver = event_bus.subscribe_to_room("test_room").await;
+        let subscriber = GameEventRoomSubscriber::new(game_service.clone(), event_bus);
+
+        game_service
+            .create_game_with_cards(
+                "test_room",
+                vec![
+                    (
+                        "Alice".to_string(),
+                        "alice-uuid".to_string(),
+                        vec![
+                            Card::new(Rank::Three, Suit::Diamonds),
+                            Card::new(Rank::Four, Suit::Hearts),
+                        ],
+                    ),
+                    (
+                        "Bob".to_string(),
+                        "bob-uuid".to_string(),
+                        vec![Card::new(Rank::Six, Suit::Clubs)],
+                    ),
+                ],
+            )
+            .await
+            .unwrap();
+
+        let result = subscriber
+            .handle_player_played_move(
+                "test_room",
+                "alice-uuid",
+                &[Card::new(Rank::Four, Suit::Hearts)],
+            )
+            .await;
+        assert!(result.is_err());
+
+        match receiver.recv().await.unwrap() {
+            RoomEvent::RequestRejected { player, code, .. } => {
+                assert_eq!(player, "alice-uuid");
+                assert_eq!(code, "FIRST_TURN_REQUIRES_THREE_OF_DIAMONDS");
+            }
+            other => panic!("Expected RequestRejected, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_take_back_vote_without_a_game_tells_the_voter() {
+        let player_mapping = Arc::new(InMemoryPlayerMappingService::new());
+        let game_service = Arc::new(GameService::new(player_mapping));
+        let event_bus = EventBus::new();
+        let mut receiver = event_bus.subscribe_to_room("nonexistent_room").await;
+        let subscriber = GameEventRoomSubscriber::new(game_service, event_bus);
+
+        let result = subscriber
+            .handle_take_back_vote("nonexistent_room", "bob-uuid", true)
+            .await;
+        assert!(result.is_err());
+        match receiver.recv().await.unwrap() {
+            RoomEvent::RequestRejected { player, code, .. } => {
+                assert_eq!(player, "bob-uuid");
+                assert_eq!(code, "GAME_NOT_FOUND");
+            }
+            other => panic!("Expected RequestRejected, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handle_player_typed_move() {
+        let player_mapping = Arc::new(InMemoryPlayerMappingService::new());
+        let game_service = Arc::new(GameService::new(player_mapping));
+        let event_bus = EventBus::new();
+        let mut receiver = event_bus.subscribe_to_room("test_room").await;
+        let subscriber = GameEventRoomSubscriber::new(game_service.clone(), event_bus);
+
+        game_service
+            .create_game_with_cards(
+                "test_room",
+                vec![
+                    (
+                        "Alice".to_string(),
+                        "alice-uuid".to_string(),
+                        vec![
+                            Card::new(Rank::Three, Suit::Diamonds),
+                            Card::new(Rank::Three, Suit::Spades),
+                            Card::new(Rank::Four, Suit::Hearts),
+                        ],
+                    ),
+                    (
+                        "Bob".to_string(),
+                        "bob-uuid".to_string(),
+                        vec![Card::new(Rank::Six, Suit::Clubs)],
+                    ),
+                ],
+            )
+            .await
+            .unwrap();
+
+        let result = subscriber
+            .handle_player_typed_move("test_room", "alice-uuid", "pair of jokers")
+            .await;
+        assert!(result.is_err());
+        match receiver.recv().await.unwrap() {
+            RoomEvent::RequestRejected { code, .. } => assert_eq!(code, "UNKNOWN_RANK"),
+            other => panic!("Expected RequestRejected, got {:?}", other),
+        }
+
+        subscriber
+            .handle_player_typed_move("test_room", "alice-uuid", "pair of 3s")
+            .await
+            .unwrap();
+        match receiver.recv().await.unwrap() {
+            RoomEvent::MovePlayed { cards, .. } => assert_eq!(
+                cards,
+                vec![
+                    Card::new(Rank::Three, Suit::Diamonds),
+                    Card::new(Rank::Three, Suit::Spades),
+                ]
+            ),
+            other => panic!("Expected MovePlayed, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handle_player_typed_move_game_not_found() {
+        let player_mapping = Arc::new(InMemoryPlayerMappingService::new());
+        let game_service = Arc::new(GameService::new(player_mapping));
+        let event_bus = EventBus::new();
+        let mut receiver = event_bus.subscribe_to_room("nonexistent_room").await;
+        let subscriber = GameEventRoomSubscriber::new(game_service, event_bus);
+
+        let result = subscriber
+            .handle_player_typed_move("nonexistent_room", "alice-uuid", "pair of 3s")
+            .await;
+        assert!(result.is_err());
+        match receiver.recv().await.unwrap() {
+            RoomEvent::RequestRejected { player, code, .. } => {
+                assert_eq!(player, "alice-uuid");
+                assert_eq!(code, "GAME_NOT_FOUND");
+            }
+            other => panic!("Expected RequestRejected, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_instant_win_is_announced_after_the_deal() {
+        let player_mapping = Arc::new(InMemoryPlayerMappingService::new());
+        let repository = Arc::new(InMemoryGameRepository::new());
+        let game_service = Arc::new(GameService::with_repository(
+            player_mapping,
+            repository.clone(),
+        ));
+        let event_bus = EventBus::new();
+        let mut receiver = event_bus.subscribe_to_room("test_room").await;
+        let subscriber = GameEventRoomSubscriber::new(game_service, event_bus);
+
+        let game = Game::new_game_with_cards(
+            "test_room".to_string(),
+            vec![
+                (
+                    "Alice".to_string(),
+                    "alice-uuid".to_string(),
+                    crate::shared::test_utils::cards("4C 5D"),
+                ),
+                (
+                    "Bob".to_string(),
+                    "bob-uuid".to_string(),
+                    crate::shared::test_utils::cards("3D 2D 2C 2H 2S"),
+                ),
+            ],
+        )
+        .unwrap()
+        .with_house_rules(HouseRules {
+            instant_wins: true,
+            ..Default::default()
+        });
+        repository.save_game("test_room", &game).await.unwrap();
+
+        subscriber.start_dealt_game("test_room", &game).await;
+
+        assert!(matches!(
+            receiver.recv().await.unwrap(),
+            RoomEvent::StartGame { .. }
+        ));
+        match receiver.recv().await.unwrap() {
+            RoomEvent::InstantWin { player, .. } => assert_eq!(player, "bob-uuid"),
+            other => panic!("Expected InstantWin, got {:?}", other),
+        }
+        match receiver.recv().await.unwrap() {
+            RoomEvent::GameWon { winner, .. } => assert_eq!(winner, "bob-uuid"),
+            other => panic!("Expected GameWon, got {:?}", other),
+        }
+    }
 }
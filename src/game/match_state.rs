@@ -0,0 +1,203 @@
+// A match is a series of games in one room, scored by a MatchScorer (at runtime the same
+// GameScorer as StatsService), that ends once a player reaches the point target or a fixed
+// number of games has been played.
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::game::core::Game;
+use crate::shared::AppError;
+
+/// When a match ends
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MatchTarget {
+    /// Ends once any player has at least this many penalty points
+    Points(i32),
+    /// Ends after this many games
+    Games(u32),
+}
+
+impl MatchTarget {
+    pub fn validate(&self) -> Result<(), String> {
+        match *self {
+            Self::Points(points) if points <= 0 => {
+                Err(format!("points must be above 0, got {}", points))
+            }
+            Self::Games(0) => Err("games must be above 0".to_string()),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// One player's position in a match; fewer points is better
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MatchStanding {
+    pub player: String,
+    pub points: i32,
+    pub games_won: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Match {
+    target: MatchTarget,
+    games_played: u32,
+    points: HashMap<String, i32>,
+    wins: HashMap<String, u32>,
+    last_winner: Option<String>,
+}
+
+impl Match {
+    pub fn new(target: MatchTarget) -> Self {
+        Self {
+            target,
+            games_played: 0,
+            points: HashMap::new(),
+            wins: HashMap::new(),
+            last_winner: None,
+        }
+    }
+
+    /// Add one finished game: the winner and every player's score for that game
+    pub fn record_game(&mut self, winner: &str, scores: &HashMap<String, i32>) {
+        for (player, score) in scores {
+            *self.points.entry(player.clone()).or_insert(0) += score;
+        }
+        *self.wins.entry(winner.to_string()).or_insert(0) += 1;
+        self.games_played += 1;
+        self.last_winner = Some(winner.to_string());
+    }
+
+    pub fn is_over(&self) -> bool {
+        match self.target {
+            MatchTarget::Points(limit) => self.points.values().any(|p| *p >= limit),
+            MatchTarget::Games(count) => self.games_played >= count,
+        }
+    }
+
+    /// Players ordered best first: fewest points, then most wins
+    pub fn standings(&self) -> Vec<MatchStanding> {
+        let mut standings: Vec<MatchStanding> = self
+            .points
+            .iter()
+            .map(|(player, points)| MatchStanding {
+                player: player.clone(),
+                points: *points,
+                games_won: self.wins.get(player).copied().unwrap_or(0),
+            })
+            .collect();
+
+        standings.sort_by(|a, b| {
+            a.points
+                .cmp(&b.points)
+                .then(b.games_won.cmp(&a.games_won))
+                .then_with(|| a.player.cmp(&b.player))
+        });
+        standings
+    }
+
+    pub fn target(&self) -> MatchTarget {
+        self.target
+    }
+
+    pub fn games_played(&self) -> u32 {
+        self.games_played
+    }
+
+    /// Winner of the previous game, who leads the next one
+    pub fn last_winner(&self) -> Option<&str> {
+        self.last_winner.as_deref()
+    }
+}
+
+/// Scores each finished game of a match
+#[async_trait]
+pub trait MatchScorer: Send + Sync {
+    /// Every player's penalty points for a finished game; fewer is better
+    async fn score_game(
+        &self,
+        room_id: &str,
+        game: &Game,
+    ) -> Result<HashMap<String, i32>, AppError>;
+}
+
+/// Scores one point per card left in hand, for services set up without the stats scorer
+pub struct CardsLeftScorer;
+
+#[async_trait]
+impl MatchScorer for CardsLeftScorer {
+    async fn score_game(
+        &self,
+        _room_id: &str,
+        game: &Game,
+    ) -> Result<HashMap<String, i32>, AppError> {
+        Ok(game
+            .players()
+            .iter()
+            .map(|p| (p.uuid.clone(), p.cards.len() as i32))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scores(entries: &[(&str, i32)]) -> HashMap<String, i32> {
+        entries.iter().map(|(p, s)| (p.to_string(), *s)).collect()
+    }
+
+    #[test]
+    fn test_points_target() {
+        let mut m = Match::new(MatchTarget::Points(20));
+
+        m.record_game("alice", &scores(&[("alice", 0), ("bob", 12), ("carol", 5)]));
+        assert!(!m.is_over());
+        assert_eq!(m.last_winner(), Some("alice"));
+
+        m.record_game("carol", &scores(&[("alice", 3), ("bob", 9), ("carol", 0)]));
+        assert!(m.is_over());
+        assert_eq!(m.games_played(), 2);
+        assert_eq!(m.last_winner(), Some("carol"));
+
+        let order: Vec<(String, i32)> = m
+            .standings()
+            .into_iter()
+            .map(|s| (s.player, s.points))
+            .collect();
+        assert_eq!(
+            order,
+            vec![
+                ("alice".to_string(), 3),
+                ("carol".to_string(), 5),
+                ("bob".to_string(), 21),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_rejects_empty_targets() {
+        assert!(MatchTarget::Points(50).validate().is_ok());
+        assert!(MatchTarget::Games(5).validate().is_ok());
+        assert!(MatchTarget::Points(0).validate().is_err());
+        assert!(MatchTarget::Points(-10).validate().is_err());
+        assert!(MatchTarget::Games(0).validate().is_err());
+    }
+
+    #[test]
+    fn test_games_target_and_tie_break() {
+        let mut m = Match::new(MatchTarget::Games(2));
+
+        m.record_game("bob", &scores(&[("alice", 4), ("bob", 0)]));
+        assert!(!m.is_over());
+        m.record_game("alice", &scores(&[("alice", 0), ("bob", 4)]));
+        assert!(m.is_over());
+
+        // Level on points and wins, so the order falls back to the player UUID
+        let standings = m.standings();
+        assert_eq!(standings[0].points, standings[1].points);
+        assert_eq!(standings[0].player, "alice");
+        assert_eq!(standings[0].games_won, 1);
+    }
+}
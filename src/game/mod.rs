@@ -1,16 +1,52 @@
 // Public API
+pub use cards::Card;
+#[allow(unused_imports)] // Public API for hand analysis and bots
+pub use cards::CardSet;
+pub use cards::PlayParseError;
+#[allow(unused_imports)] // Public API for rule selection
+pub use cards::RuleSet;
 #[allow(unused_imports)] // Used by integration tests
 pub use cards::SingleHand;
-pub use cards::{Card, Hand, Rank, Suit};
-pub use core::Game;
+#[allow(unused_imports)] // Public API for hints and bots
+pub use cards::{partition_hand, HandPartition};
+#[allow(unused_imports)] // Used by integration tests
+pub use cards::{Hand, Rank, Suit};
+#[allow(unused_imports)] // Public API for rule selection
+pub use config::{GameConfig, HouseRules, OpeningRule, TakeBackRule, MAX_SEED};
+#[allow(unused_imports)] // Public API for replays and analysis
+pub use core::MoveRecord;
 #[allow(unused_imports)] // Used by integration tests
 pub use core::Player;
+pub use core::{Game, GameError};
+pub use deal::InstantWin;
 pub use game_room_subscriber::GameEventRoomSubscriber;
-pub use service::GameService;
+#[allow(unused_imports)] // Public API for scoring matches
+pub use match_state::{Match, MatchScorer, MatchStanding, MatchTarget};
+#[allow(unused_imports)] // Public API for hints and bots
+pub use moves::LegalMoves;
+#[allow(unused_imports)] // Public API for bug reports and shared hands
+pub use notation::{read_game, write_game, NotationError};
+#[allow(unused_imports)] // Public API for choosing a storage backend
+pub use repository::{
+    GameRepository, InMemoryGameRepository, InMemoryMatchRepository, MatchRepository,
+    PostgresGameRepository, PostgresMatchRepository,
+};
+pub use service::{GameService, TurnClock};
+pub use turn_timer_room_subscriber::TurnTimerRoomSubscriber;
+pub use view::PlayerView;
+#[allow(unused_imports)] // Used by integration tests
+pub use view::SeatView;
 
 // Internal modules
 mod cards;
+mod config;
 mod core;
+mod deal;
 mod game_room_subscriber;
+mod match_state;
+mod moves;
+mod notation;
 mod repository;
 mod service;
+mod turn_timer_room_subscriber;
+mod view;
@@ -0,0 +1,169 @@
+// Legal move generation. Candidates are enumerated as CardSets (pairs and triples only within a
+// rank, five-card hands only if they classify) and every one is checked with Game::validate_move,
+// so the result always agrees with what play_cards would accept, house rules and bombs included.
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+
+use crate::game::{
+    cards::{hands::FiveCardHand, CardSet, Hand, Rank},
+    core::Game,
+};
+
+/// Every legal play for one player, grouped by hand type
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LegalMoves {
+    pub singles: Vec<Hand>,
+    pub pairs: Vec<Hand>,
+    pub triples: Vec<Hand>,
+    /// Straights, flushes, full houses, four of a kinds and straight flushes
+    pub five_card_hands: Vec<Hand>,
+    /// Whether passing is allowed
+    pub can_pass: bool,
+}
+
+impl LegalMoves {
+    /// Enumerate the legal plays for `player_uuid`; empty when it is not their turn
+    pub(crate) fn from_game(game: &Game, player_uuid: &str) -> Self {
+        let Some(player) = game.players().iter().find(|p| p.uuid == player_uuid) else {
+            return Self::default();
+        };
+        let hand: CardSet = player.cards.iter().copied().collect();
+
+        let legal_hands = |candidates: Vec<CardSet>| -> Vec<Hand> {
+            candidates
+                .into_iter()
+                .filter_map(|set| {
+                    let cards = set.to_vec();
+                    game.validate_move(player_uuid, &cards).ok()?;
+                    Hand::from_cards_with_rules(&cards, game.rules()).ok()
+                })
+                .collect()
+        };
+        let same_rank = |size: usize| -> Vec<CardSet> {
+            Rank::iter()
+                .flat_map(|rank| hand.of_rank(rank).subsets(size))
+                .collect()
+        };
+        let five_card_candidates = hand
+            .subsets(5)
+            .into_iter()
+            .filter(|set| FiveCardHand::classify_hand(*set, game.rules()).is_ok())
+            .collect();
+
+        Self {
+            singles: legal_hands(hand.subsets(1)),
+            pairs: legal_hands(same_rank(2)),
+            triples: legal_hands(same_rank(3)),
+            five_card_hands: legal_hands(five_card_candidates),
+            can_pass: game.validate_move(player_uuid, &[]).is_ok(),
+        }
+    }
+
+    /// All legal hands, smallest hand type first
+    pub fn hands(&self) -> impl Iterator<Item = &Hand> {
+        self.singles
+            .iter()
+            .chain(self.pairs.iter())
+            .chain(self.triples.iter())
+            .chain(self.five_card_hands.iter())
+    }
+
+    /// Whether no cards can be played (the player can only pass, if anything)
+    #[allow(dead_code)] // Public API for hints and bots
+    pub fn is_empty(&self) -> bool {
+        self.hands().next().is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::cards::{Card, RuleSet, Suit};
+    use crate::shared::test_utils::cards;
+
+    fn game(alice: &str, bob: &str) -> Game {
+        Game::new_game_with_cards(
+            "moves".to_string(),
+            vec![
+                ("Alice".to_string(), "alice".to_string(), cards(alice)),
+                ("Bob".to_string(), "bob".to_string(), cards(bob)),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_duplicate_cards_are_not_a_pair() {
+        let game = game("3D 4D 9S", "8C 9C");
+        let mut copy = game.clone();
+        assert!(matches!(
+            copy.play_cards("alice", &cards("3D 3D")),
+            Err(crate::game::GameError::HandError(
+                crate::game::cards::HandError::DuplicateCard(_)
+            ))
+        ));
+        assert!(game.legal_moves("alice").pairs.is_empty());
+    }
+
+    #[test]
+    fn test_opening_moves_must_include_three_of_diamonds() {
+        let game = game("3D 3S 4D 5D 6D 7D KS", "8C 9C");
+        let moves = game.legal_moves("alice");
+
+        assert_eq!(moves.singles, vec![Hand::from_cards(&cards("3D")).unwrap()]);
+        assert_eq!(
+            moves.pairs,
+            vec![Hand::from_cards(&cards("3D 3S")).unwrap()]
+        );
+        assert!(moves.triples.is_empty());
+        // 3S-7D is a straight too, but without the 3D it cannot open
+        assert_eq!(
+            moves.five_card_hands,
+            vec![Hand::from_cards(&cards("3D 4D 5D 6D 7D")).unwrap()]
+        );
+        assert!(moves.hands().all(|h| h
+            .to_cards()
+            .contains(&Card::new(Rank::Three, Suit::Diamonds))));
+        // The opening player may still pass, handing the lead to the next seat
+        assert!(moves.can_pass);
+        let mut copy = game.clone();
+        assert!(copy.play_cards("alice", &[]).is_ok());
+    }
+
+    #[test]
+    fn test_moves_beat_the_table_and_match_play_cards() {
+        let mut game = game("3D 9H", "4C 8C 9C JD 2S");
+        game.play_cards("alice", &cards("3D")).unwrap();
+        game.play_cards("bob", &cards("8C")).unwrap();
+
+        let moves = game.legal_moves("alice");
+        assert_eq!(moves.singles, vec![Hand::from_cards(&cards("9H")).unwrap()]);
+        assert!(moves.can_pass);
+
+        for hand in moves.hands() {
+            let mut copy = game.clone();
+            assert!(copy.play_cards("alice", &hand.to_cards()).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_no_moves_when_not_your_turn() {
+        let game = game("3D 9H", "4C 8C");
+        let moves = game.legal_moves("bob");
+        assert!(moves.is_empty());
+        assert!(!moves.can_pass);
+
+        assert_eq!(game.legal_moves("nobody"), LegalMoves::default());
+    }
+
+    #[test]
+    fn test_bombs_show_up_against_singles() {
+        let mut game =
+            game("3D 4C", "5D 5C 5H 5S 6D 7D").with_rules(RuleSet::standard().with_bombs());
+        game.play_cards("alice", &cards("3D")).unwrap();
+
+        let moves = game.legal_moves("bob");
+        assert_eq!(moves.singles.len(), 6);
+        assert_eq!(moves.five_card_hands.len(), 2);
+    }
+}
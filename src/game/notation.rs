@@ -0,0 +1,420 @@
+// Text notation for complete games, in the spirit of chess PGN: a block of tags describing the
+// deal, then one numbered line per action. Importing rebuilds the deal and replays every action
+// through Game::play_cards, so a file that imports cleanly is a game the server would accept.
+//
+//     [Game "room-42"]
+//     [Seed "1234"]
+//     [Seat1 "alice"]
+//     [Name1 "Alice"]
+//     [Hand1 "3D 4C 9H"]
+//     [Seat2 "bob"]
+//     [Name2 "Bob"]
+//     [Hand2 "5S 8D"]
+//     [Result "alice"]
+//
+//     1. alice: 3D
+//     2. bob: pass
+use std::fmt::Write;
+
+use crate::game::{
+    cards::{Card, CardParseError, RuleSet},
+    config::HouseRules,
+    core::{Game, GameError},
+};
+
+/// Written in place of cards for a pass
+const PASS: &str = "pass";
+/// Result of a game that has not finished
+const UNFINISHED: &str = "*";
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum NotationError {
+    #[error("Missing tag: {0}")]
+    MissingTag(String),
+    #[error("Malformed line {line}: {text}")]
+    MalformedLine { line: usize, text: String },
+    #[error("Invalid {tag} tag: {reason}")]
+    InvalidTag { tag: String, reason: String },
+    #[error("Invalid card on line {line}: {error}")]
+    InvalidCard { line: usize, error: CardParseError },
+    #[error("Game could not be dealt: {0}")]
+    Deal(GameError),
+    #[error("Move {number} is illegal: {error}")]
+    IllegalMove { number: usize, error: GameError },
+    #[error("Result is {actual}, but the file says {expected}")]
+    ResultMismatch { expected: String, actual: String },
+}
+
+/// Write a game in the text notation, listing the seats in the order they were dealt
+pub fn write_game(game: &Game) -> String {
+    let mut text = String::new();
+    let mut tag = |name: &str, value: &str| {
+        let _ = writeln!(text, "[{} \"{}\"]", name, escape(value));
+    };
+
+    tag("Game", game.id());
+    if let Some(seed) = game.deal_seed() {
+        tag("Seed", &seed.to_string());
+    }
+    if *game.rules() != RuleSet::standard() {
+        tag("Rules", &serde_json::to_string(game.rules()).unwrap());
+    }
+    if *game.house_rules() != HouseRules::default() {
+        tag(
+            "HouseRules",
+            &serde_json::to_string(game.house_rules()).unwrap(),
+        );
+    }
+    if let Some(leader) = game.leader() {
+        tag("Leader", leader);
+    }
+    for (index, player) in game.players_in_deal_order().into_iter().enumerate() {
+        let seat = index + 1;
+        tag(&format!("Seat{}", seat), &player.uuid);
+        tag(&format!("Name{}", seat), &player.name);
+        if let Some(hand) = game.starting_hands().get(&player.uuid) {
+            tag(&format!("Hand{}", seat), &cards_to_text(hand));
+        }
+    }
+    tag("Result", &result(game));
+
+    text.push('\n');
+    for (index, record) in game.moves().iter().enumerate() {
+        let cards = record.hand.to_cards();
+        let play = if cards.is_empty() {
+            PASS.to_string()
+        } else {
+            cards_to_text(&cards)
+        };
+        let _ = writeln!(text, "{}. {}: {}", index + 1, record.player_uuid, play);
+    }
+    text
+}
+
+/// Rebuild a game from the text notation by replaying every move
+///
+/// Starting hands come from the `Hand` tags; without them the seats are dealt from the
+/// `Seed` tag in the order they are listed. A `Result` tag must match the replayed game.
+pub fn read_game(text: &str) -> Result<Game, NotationError> {
+    let mut tags: Vec<(String, String)> = Vec::new();
+    let mut moves: Vec<(String, Vec<Card>)> = Vec::new();
+
+    for (index, raw) in text.lines().enumerate() {
+        let line_number = index + 1;
+        let line = raw.trim();
+        if line.is_empty() || line.starts_with(';') {
+            continue;
+        }
+        let malformed = || NotationError::MalformedLine {
+            line: line_number,
+            text: line.to_string(),
+        };
+
+        if let Some(inner) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            let (name, value) = inner.split_once(' ').ok_or_else(malformed)?;
+            let value = value
+                .trim()
+                .strip_prefix('"')
+                .and_then(|v| v.strip_suffix('"'))
+                .ok_or_else(malformed)?;
+            tags.push((name.to_string(), unescape(value)));
+            continue;
+        }
+
+        let (_, action) = line.split_once(". ").ok_or_else(malformed)?;
+        let (player, play) = action.split_once(':').ok_or_else(malformed)?;
+        let play = play.trim();
+        let cards = if play == PASS {
+            Vec::new()
+        } else {
+            cards_from_text(play).map_err(|error| NotationError::InvalidCard {
+                line: line_number,
+                error,
+            })?
+        };
+        moves.push((player.trim().to_string(), cards));
+    }
+
+    let tag = |name: &str| {
+        tags.iter()
+            .find(|(tag, _)| tag == name)
+            .map(|(_, value)| value.as_str())
+    };
+    let invalid = |tag: &str, reason: String| NotationError::InvalidTag {
+        tag: tag.to_string(),
+        reason,
+    };
+
+    let id = tag("Game").ok_or_else(|| NotationError::MissingTag("Game".to_string()))?;
+    let seed = tag("Seed")
+        .map(|seed| {
+            seed.parse::<u64>()
+                .map_err(|e| invalid("Seed", e.to_string()))
+        })
+        .transpose()?;
+
+    let mut seats: Vec<(String, String, Option<Vec<Card>>)> = Vec::new();
+    for seat in 1.. {
+        let Some(uuid) = tag(&format!("Seat{}", seat)) else {
+            break;
+        };
+        let name = tag(&format!("Name{}", seat)).unwrap_or(uuid);
+        let hand_tag = format!("Hand{}", seat);
+        let hand = tag(&hand_tag)
+            .map(|hand| cards_from_text(hand).map_err(|e| invalid(&hand_tag, e.to_string())))
+            .transpose()?;
+        seats.push((name.to_string(), uuid.to_string(), hand));
+    }
+    if seats.is_empty() {
+        return Err(NotationError::MissingTag("Seat1".to_string()));
+    }
+
+    let mut game = if seats.iter().all(|(_, _, hand)| hand.is_some()) {
+        let player_data = seats
+            .into_iter()
+            .map(|(name, uuid, hand)| (name, uuid, hand.unwrap_or_default()))
+            .collect();
+        let game =
+            Game::new_game_with_cards(id.to_string(), player_data).map_err(NotationError::Deal)?;
+        match seed {
+            Some(seed) => game.with_deal_seed(seed),
+            None => game,
+        }
+    } else {
+        let seed = seed.ok_or_else(|| NotationError::MissingTag("Seed".to_string()))?;
+        let player_data: Vec<(String, String)> = seats
+            .into_iter()
+            .map(|(name, uuid, _)| (name, uuid))
+            .collect();
+        Game::new_game_with_seed(id.to_string(), &player_data, seed).map_err(NotationError::Deal)?
+    };
+
+    if let Some(rules) = tag("Rules") {
+        let rules: RuleSet =
+            serde_json::from_str(rules).map_err(|e| invalid("Rules", e.to_string()))?;
+        game = game.with_rules(rules);
+    }
+    if let Some(house_rules) = tag("HouseRules") {
+        let house_rules: HouseRules =
+            serde_json::from_str(house_rules).map_err(|e| invalid("HouseRules", e.to_string()))?;
+        game = game.with_house_rules(house_rules);
+    }
+    if let Some(leader) = tag("Leader") {
+        game = game.with_leader(leader);
+    }
+    game.claim_instant_win();
+
+    for (number, (player, cards)) in moves.iter().enumerate() {
+        game.play_cards(player, cards)
+            .map_err(|error| NotationError::IllegalMove {
+                number: number + 1,
+                error,
+            })?;
+    }
+
+    if let Some(expected) = tag("Result") {
+        let actual = result(&game);
+        if expected != actual {
+            return Err(NotationError::ResultMismatch {
+                expected: expected.to_string(),
+                actual,
+            });
+        }
+    }
+
+    Ok(game)
+}
+
+/// Finishing order (or just the winner), or `*` while the game is still going
+fn result(game: &Game) -> String {
+    if !game.is_over() {
+        return UNFINISHED.to_string();
+    }
+    if game.finishing_order().is_empty() {
+        return game.winner().unwrap_or_default();
+    }
+    game.finishing_order().join(" ")
+}
+
+fn cards_to_text(cards: &[Card]) -> String {
+    cards
+        .iter()
+        .map(|card| card.to_string())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn cards_from_text(text: &str) -> Result<Vec<Card>, CardParseError> {
+    text.split_whitespace().map(Card::from_string).collect()
+}
+
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn unescape(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => result.extend(chars.next()),
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::config::GameConfig;
+    use crate::shared::test_utils::cards;
+
+    fn game() -> Game {
+        Game::new_game_with_cards(
+            "room-42".to_string(),
+            vec![
+                ("Alice".to_string(), "alice".to_string(), cards("3D 4C 9H")),
+                ("Bob \"B\"".to_string(), "bob".to_string(), cards("5S 8D")),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_write_game() {
+        let mut game = game();
+        game.play_cards("alice", &cards("3D")).unwrap();
+        game.play_cards("bob", &[]).unwrap();
+
+        let text = write_game(&game);
+        assert!(text.starts_with("[Game \"room-42\"]\n"));
+        assert!(text.contains("[Name2 \"Bob \\\"B\\\"\"]\n"));
+        assert!(text.contains("[Hand1 \"3D 4C 9H\"]\n"));
+        assert!(text.contains("[Result \"*\"]\n"));
+        assert!(text.ends_with("\n1. alice: 3D\n2. bob: pass\n"));
+        assert!(!text.contains("[Rules"));
+    }
+
+    #[test]
+    fn test_round_trip_finished_game() {
+        let mut game = game();
+        game.play_cards("alice", &cards("3D")).unwrap();
+        game.play_cards("bob", &cards("5S")).unwrap();
+        game.play_cards("alice", &cards("9H")).unwrap();
+        game.play_cards("bob", &[]).unwrap();
+        game.play_cards("alice", &cards("4C")).unwrap();
+        assert!(game.is_over());
+
+        let text = write_game(&game);
+        assert!(text.contains("[Result \"alice\"]"));
+
+        let restored = read_game(&text).unwrap();
+        assert_eq!(restored.winner().as_deref(), Some("alice"));
+        assert_eq!(restored.moves().len(), 5);
+        assert_eq!(restored.players()[1].name, "Bob \"B\"");
+        assert_eq!(write_game(&restored), text);
+    }
+
+    #[test]
+    fn test_round_trip_seeded_game_with_rules() {
+        let config = GameConfig {
+            rules: RuleSet::pusoy_dos(),
+            house_rules: HouseRules {
+                pass_lockout: true,
+                ..HouseRules::default()
+            },
+            seed: Some(7),
+            ..GameConfig::default()
+        };
+        let players = [
+            ("Alice".to_string(), "alice".to_string()),
+            ("Bob".to_string(), "bob".to_string()),
+            ("Carol".to_string(), "carol".to_string()),
+        ];
+        let mut game = Game::new_game_with_config("seeded".to_string(), &players, &config).unwrap();
+        let opener = game.current_player_turn();
+        let opening = game.legal_moves(&opener).singles[0].to_cards();
+        game.play_cards(&opener, &opening).unwrap();
+
+        let restored = read_game(&write_game(&game)).unwrap();
+        assert_eq!(restored.deal_seed(), Some(7));
+        assert_eq!(restored.rules(), &RuleSet::pusoy_dos());
+        assert!(restored.house_rules().pass_lockout);
+        assert_eq!(restored.current_player_turn(), game.current_player_turn());
+        assert_eq!(restored.players()[0].cards, game.players()[0].cards);
+    }
+
+    #[test]
+    fn test_seed_alone_deals_the_hands() {
+        let players = [
+            ("Alice".to_string(), "alice".to_string()),
+            ("Bob".to_string(), "bob".to_string()),
+        ];
+        let dealt = Game::new_game_with_seed("s".to_string(), &players, 99).unwrap();
+
+        let text = "[Game \"s\"]\n[Seed \"99\"]\n[Seat1 \"alice\"]\n[Seat2 \"bob\"]\n";
+        let game = read_game(text).unwrap();
+        assert_eq!(game.players()[0].cards, dealt.players()[0].cards);
+        // Without Name tags the seats are named after their UUIDs
+        assert!(game.players().iter().any(|p| p.name == "alice"));
+    }
+
+    #[test]
+    fn test_written_seats_deal_again_from_the_seed() {
+        let players = [
+            ("Alice".to_string(), "alice".to_string()),
+            ("Bob".to_string(), "bob".to_string()),
+            ("Carol".to_string(), "carol".to_string()),
+        ];
+        // Whoever holds the 3D leads, so most seeds move a later seat to the front
+        for seed in 0..8 {
+            let game = Game::new_game_with_seed("s".to_string(), &players, seed).unwrap();
+            let seed_only: String = write_game(&game)
+                .lines()
+                .filter(|line| !line.starts_with("[Hand"))
+                .map(|line| format!("{}\n", line))
+                .collect();
+
+            let restored = read_game(&seed_only).unwrap();
+            for (restored, dealt) in restored.players().iter().zip(game.players()) {
+                assert_eq!(restored.uuid, dealt.uuid);
+                assert_eq!(restored.cards, dealt.cards);
+            }
+        }
+    }
+
+    #[test]
+    fn test_read_errors() {
+        let header = "[Game \"g\"]\n[Seat1 \"alice\"]\n[Hand1 \"3D 4C 9H\"]\n\
+                      [Seat2 \"bob\"]\n[Hand2 \"5S 8D\"]\n";
+
+        assert!(matches!(
+            read_game("[Seat1 \"alice\"]"),
+            Err(NotationError::MissingTag(tag)) if tag == "Game"
+        ));
+        assert!(matches!(
+            read_game("[Game \"g\"]\n[Seat1 \"alice\"]\n"),
+            Err(NotationError::MissingTag(tag)) if tag == "Seed"
+        ));
+        assert!(matches!(
+            read_game(&format!("{}\n1. alice: 3X", header)),
+            Err(NotationError::InvalidCard { line: 7, .. })
+        ));
+        assert!(matches!(
+            read_game(&format!("{}\n1. bob: 5S", header)),
+            Err(NotationError::IllegalMove {
+                number: 1,
+                error: GameError::InvalidPlayerTurn
+            })
+        ));
+        assert!(matches!(
+            read_game(&format!("{}[Result \"bob\"]\n", header)),
+            Err(NotationError::ResultMismatch { .. })
+        ));
+        assert!(matches!(
+            read_game(&format!("{}\nalice plays 3D", header)),
+            Err(NotationError::MalformedLine { line: 7, .. })
+        ));
+    }
+}
@@ -1,51 +1,444 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sqlx::{PgPool, Row};
 use std::collections::HashMap;
-use std::sync::Arc;
 use tokio::sync::RwLock;
+use tracing::{debug, instrument, warn};
 
-use crate::game::core::{Game, GameError};
+use crate::game::core::Game;
+use crate::game::match_state::Match;
+use crate::shared::AppError;
 
-pub struct GameRepository {
+/// Trait for game repository operations, keyed by room ID
+#[async_trait]
+pub trait GameRepository: Send + Sync {
+    /// Store a room's game, replacing any previous one
+    async fn save_game(&self, room_id: &str, game: &Game) -> Result<(), AppError>;
+    async fn get_game(&self, room_id: &str) -> Result<Option<Game>, AppError>;
+    async fn remove_game(&self, room_id: &str) -> Result<Option<Game>, AppError>;
+    /// Every stored game with its room ID, used to pick games back up after a restart
+    ///
+    /// A game that can no longer be read is logged and left out, so one bad row does not
+    /// stop the others from coming back.
+    async fn list_games(&self) -> Result<Vec<(String, Game)>, AppError>;
+}
+
+/// Trait for match repository operations, keyed by room ID
+#[async_trait]
+pub trait MatchRepository: Send + Sync {
+    /// Store a room's match, replacing any previous one
+    async fn save_match(&self, room_id: &str, current: &Match) -> Result<(), AppError>;
+    async fn get_match(&self, room_id: &str) -> Result<Option<Match>, AppError>;
+    async fn remove_match(&self, room_id: &str) -> Result<(), AppError>;
+    /// Add a finished game to the room's match in a single write, if a match is still running
+    ///
+    /// Reading, updating and saving happen as one step, so games recorded at the same time
+    /// cannot overwrite each other. Returns the updated match.
+    async fn record_game(
+        &self,
+        room_id: &str,
+        winner: &str,
+        scores: &HashMap<String, i32>,
+    ) -> Result<Option<Match>, AppError>;
+}
+
+/// Parse a stored game or match back from its JSON
+fn decode<T: serde::de::DeserializeOwned>(room_id: &str, state: &str) -> Result<T, AppError> {
+    serde_json::from_str(state).map_err(|e| {
+        warn!(error = %e, room_id = %room_id, "Stored state could not be deserialized");
+        AppError::DatabaseError(e.to_string())
+    })
+}
+
+/// In-memory implementation of GameRepository; games are lost when the server restarts
+pub struct InMemoryGameRepository {
     /// A mapping from room ID to game
-    games: Arc<RwLock<HashMap<String, Game>>>,
+    games: RwLock<HashMap<String, Game>>,
 }
 
-impl Default for GameRepository {
+impl Default for InMemoryGameRepository {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl GameRepository {
+impl InMemoryGameRepository {
     pub fn new() -> Self {
         Self {
-            games: Arc::new(RwLock::new(HashMap::new())),
+            games: RwLock::new(HashMap::new()),
         }
     }
+}
 
-    pub async fn create_game(
-        &self,
-        room_id: &str,
-        player_data: &[(String, String)],
-    ) -> Result<(), GameError> {
+#[async_trait]
+impl GameRepository for InMemoryGameRepository {
+    async fn save_game(&self, room_id: &str, game: &Game) -> Result<(), AppError> {
         let mut games = self.games.write().await;
-        let game = Game::new_game(room_id.to_string(), player_data)?;
-        games.insert(room_id.to_string(), game);
+        games.insert(room_id.to_string(), game.clone());
         Ok(())
     }
 
-    pub async fn update_game(&self, room_id: &str, game: Game) -> Result<(), GameError> {
+    async fn get_game(&self, room_id: &str) -> Result<Option<Game>, AppError> {
+        let games = self.games.read().await;
+        Ok(games.get(room_id).cloned())
+    }
+
+    async fn remove_game(&self, room_id: &str) -> Result<Option<Game>, AppError> {
         let mut games = self.games.write().await;
-        games.insert(room_id.to_string(), game);
-        Ok(())
+        Ok(games.remove(room_id))
     }
 
-    pub async fn get_game(&self, room_id: &str) -> Option<Game> {
+    async fn list_games(&self) -> Result<Vec<(String, Game)>, AppError> {
         let games = self.games.read().await;
-        games.get(room_id).cloned()
+        Ok(games
+            .iter()
+            .map(|(room_id, game)| (room_id.clone(), game.clone()))
+            .collect())
     }
+}
 
-    pub async fn remove_game(&self, room_id: &str) -> Option<Game> {
-        let mut games = self.games.write().await;
-        games.remove(room_id)
+/// PostgreSQL implementation of game repository
+///
+/// Each game is stored as its serialized JSON, so games in progress survive a restart.
+pub struct PostgresGameRepository {
+    pool: PgPool,
+}
+
+impl PostgresGameRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl GameRepository for PostgresGameRepository {
+    #[instrument(skip(self, game))]
+    async fn save_game(&self, room_id: &str, game: &Game) -> Result<(), AppError> {
+        debug!(room_id = %room_id, "Saving game to database");
+
+        let state = serde_json::to_string(game).map_err(|e| {
+            warn!(error = %e, room_id = %room_id, "Failed to serialize game");
+            AppError::Internal
+        })?;
+
+        sqlx::query(
+            "INSERT INTO games (room_id, state, updated_at) VALUES ($1, $2, $3)
+             ON CONFLICT (room_id) DO UPDATE SET state = EXCLUDED.state, updated_at = EXCLUDED.updated_at",
+        )
+        .bind(room_id)
+        .bind(state)
+        .bind(Utc::now())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            warn!(error = %e, room_id = %room_id, "Failed to save game to database");
+            AppError::DatabaseError(e.to_string())
+        })?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn get_game(&self, room_id: &str) -> Result<Option<Game>, AppError> {
+        debug!(room_id = %room_id, "Fetching game from database");
+
+        let row = sqlx::query("SELECT state FROM games WHERE room_id = $1")
+            .bind(room_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| {
+                warn!(error = %e, room_id = %room_id, "Failed to fetch game from database");
+                AppError::DatabaseError(e.to_string())
+            })?;
+
+        row.map(|row| decode(room_id, row.get("state"))).transpose()
+    }
+
+    #[instrument(skip(self))]
+    async fn remove_game(&self, room_id: &str) -> Result<Option<Game>, AppError> {
+        debug!(room_id = %room_id, "Deleting game from database");
+
+        let row = sqlx::query("DELETE FROM games WHERE room_id = $1 RETURNING state")
+            .bind(room_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| {
+                warn!(error = %e, room_id = %room_id, "Failed to delete game from database");
+                AppError::DatabaseError(e.to_string())
+            })?;
+
+        row.map(|row| decode(room_id, row.get("state"))).transpose()
+    }
+
+    #[instrument(skip(self))]
+    async fn list_games(&self) -> Result<Vec<(String, Game)>, AppError> {
+        debug!("Listing games in database");
+
+        let rows = sqlx::query("SELECT room_id, state FROM games")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| {
+                warn!(error = %e, "Failed to list games in database");
+                AppError::DatabaseError(e.to_string())
+            })?;
+
+        // decode logs each row it has to skip
+        Ok(rows
+            .iter()
+            .filter_map(|row| {
+                let room_id: String = row.get("room_id");
+                let game = decode(&room_id, row.get("state")).ok()?;
+                Some((room_id, game))
+            })
+            .collect())
+    }
+}
+
+/// In-memory implementation of MatchRepository; matches are lost when the server restarts
+pub struct InMemoryMatchRepository {
+    /// A mapping from room ID to the match being played there
+    matches: RwLock<HashMap<String, Match>>,
+}
+
+impl Default for InMemoryMatchRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InMemoryMatchRepository {
+    pub fn new() -> Self {
+        Self {
+            matches: RwLock::new(HashMap::new()),
+        }
+    }
+}
+
+#[async_trait]
+impl MatchRepository for InMemoryMatchRepository {
+    async fn save_match(&self, room_id: &str, current: &Match) -> Result<(), AppError> {
+        let mut matches = self.matches.write().await;
+        matches.insert(room_id.to_string(), current.clone());
+        Ok(())
+    }
+
+    async fn get_match(&self, room_id: &str) -> Result<Option<Match>, AppError> {
+        let matches = self.matches.read().await;
+        Ok(matches.get(room_id).cloned())
+    }
+
+    async fn remove_match(&self, room_id: &str) -> Result<(), AppError> {
+        let mut matches = self.matches.write().await;
+        matches.remove(room_id);
+        Ok(())
+    }
+
+    async fn record_game(
+        &self,
+        room_id: &str,
+        winner: &str,
+        scores: &HashMap<String, i32>,
+    ) -> Result<Option<Match>, AppError> {
+        let mut matches = self.matches.write().await;
+        let Some(current) = matches.get_mut(room_id).filter(|m| !m.is_over()) else {
+            return Ok(None);
+        };
+        current.record_game(winner, scores);
+        Ok(Some(current.clone()))
+    }
+}
+
+/// PostgreSQL implementation of match repository, storing each match as its serialized JSON
+pub struct PostgresMatchRepository {
+    pool: PgPool,
+}
+
+impl PostgresMatchRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl MatchRepository for PostgresMatchRepository {
+    #[instrument(skip(self, current))]
+    async fn save_match(&self, room_id: &str, current: &Match) -> Result<(), AppError> {
+        debug!(room_id = %room_id, "Saving match to database");
+
+        let state = serde_json::to_string(current).map_err(|e| {
+            warn!(error = %e, room_id = %room_id, "Failed to serialize match");
+            AppError::Internal
+        })?;
+
+        sqlx::query(
+            "INSERT INTO matches (room_id, state, updated_at) VALUES ($1, $2, $3)
+             ON CONFLICT (room_id) DO UPDATE SET state = EXCLUDED.state, updated_at = EXCLUDED.updated_at",
+        )
+        .bind(room_id)
+        .bind(state)
+        .bind(Utc::now())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            warn!(error = %e, room_id = %room_id, "Failed to save match to database");
+            AppError::DatabaseError(e.to_string())
+        })?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn get_match(&self, room_id: &str) -> Result<Option<Match>, AppError> {
+        debug!(room_id = %room_id, "Fetching match from database");
+
+        let row = sqlx::query("SELECT state FROM matches WHERE room_id = $1")
+            .bind(room_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| {
+                warn!(error = %e, room_id = %room_id, "Failed to fetch match from database");
+                AppError::DatabaseError(e.to_string())
+            })?;
+
+        row.map(|row| decode(room_id, row.get("state"))).transpose()
+    }
+
+    #[instrument(skip(self))]
+    async fn remove_match(&self, room_id: &str) -> Result<(), AppError> {
+        debug!(room_id = %room_id, "Deleting match from database");
+
+        sqlx::query("DELETE FROM matches WHERE room_id = $1")
+            .bind(room_id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| {
+                warn!(error = %e, room_id = %room_id, "Failed to delete match from database");
+                AppError::DatabaseError(e.to_string())
+            })?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self, scores))]
+    async fn record_game(
+        &self,
+        room_id: &str,
+        winner: &str,
+        scores: &HashMap<String, i32>,
+    ) -> Result<Option<Match>, AppError> {
+        debug!(room_id = %room_id, "Recording game in database match");
+
+        let database_error = |e: sqlx::Error| {
+            warn!(error = %e, room_id = %room_id, "Failed to record game in database match");
+            AppError::DatabaseError(e.to_string())
+        };
+
+        // Lock the row until the updated match is written back
+        let mut tx = self.pool.begin().await.map_err(database_error)?;
+        let row = sqlx::query("SELECT state FROM matches WHERE room_id = $1 FOR UPDATE")
+            .bind(room_id)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(database_error)?;
+        let Some(mut current) = row
+            .map(|row| decode::<Match>(room_id, row.get("state")))
+            .transpose()?
+            .filter(|m| !m.is_over())
+        else {
+            return Ok(None);
+        };
+
+        current.record_game(winner, scores);
+        let state = serde_json::to_string(&current).map_err(|e| {
+            warn!(error = %e, room_id = %room_id, "Failed to serialize match");
+            AppError::Internal
+        })?;
+        sqlx::query("UPDATE matches SET state = $2, updated_at = $3 WHERE room_id = $1")
+            .bind(room_id)
+            .bind(state)
+            .bind(Utc::now())
+            .execute(&mut *tx)
+            .await
+            .map_err(database_error)?;
+        tx.commit().await.map_err(database_error)?;
+
+        Ok(Some(current))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn game() -> Game {
+        Game::new_game_with_seed(
+            "room".to_string(),
+            &[
+                ("Alice".to_string(), "alice".to_string()),
+                ("Bob".to_string(), "bob".to_string()),
+            ],
+            42,
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_save_get_remove() {
+        let repo = InMemoryGameRepository::new();
+        assert!(repo.get_game("room").await.unwrap().is_none());
+
+        let game = game();
+        repo.save_game("room", &game).await.unwrap();
+        let stored = repo.get_game("room").await.unwrap().unwrap();
+        assert_eq!(stored.deal_seed(), game.deal_seed());
+
+        assert!(repo.remove_game("room").await.unwrap().is_some());
+        assert!(repo.remove_game("room").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_record_game_only_updates_a_running_match() {
+        use crate::game::match_state::MatchTarget;
+        let repo = InMemoryMatchRepository::new();
+        let scores: HashMap<String, i32> = [("alice".to_string(), 0), ("bob".to_string(), 3)]
+            .into_iter()
+            .collect();
+        assert!(repo
+            .record_game("room", "alice", &scores)
+            .await
+            .unwrap()
+            .is_none());
+
+        repo.save_match("room", &Match::new(MatchTarget::Games(1)))
+            .await
+            .unwrap();
+        let recorded = repo.record_game("room", "alice", &scores).await.unwrap();
+        assert_eq!(recorded.unwrap().games_played(), 1);
+
+        // The match is over, so later games are not added
+        assert!(repo
+            .record_game("room", "bob", &scores)
+            .await
+            .unwrap()
+            .is_none());
+        let stored = repo.get_match("room").await.unwrap().unwrap();
+        assert_eq!(stored.games_played(), 1);
+        assert_eq!(stored.last_winner(), Some("alice"));
+    }
+
+    #[test]
+    fn test_stored_state_round_trips() {
+        let mut game = game();
+        let opener = game.current_player_turn();
+        let opening = game.legal_moves(&opener).singles[0].to_cards();
+        game.play_cards(&opener, &opening).unwrap();
+
+        let state = serde_json::to_string(&game).unwrap();
+        let restored: Game = decode("room", &state).unwrap();
+        assert_eq!(restored.current_player_turn(), game.current_player_turn());
+        assert_eq!(restored.moves().len(), 1);
+        assert_eq!(restored.players()[0].cards, game.players()[0].cards);
+
+        assert!(decode::<Game>("room", "not json").is_err());
     }
 }
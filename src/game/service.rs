@@ -1,5 +1,20 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use tokio::sync::RwLock;
+use tracing::warn;
+
 use crate::{
-    game::{cards::Card, core::Game, repository::GameRepository},
+    game::{
+        cards::Card,
+        config::GameConfig,
+        core::{Game, TakeBackRequest, TakeBackVote},
+        deal::InstantWin,
+        match_state::{CardsLeftScorer, Match, MatchScorer, MatchTarget},
+        repository::{
+            GameRepository, InMemoryGameRepository, InMemoryMatchRepository, MatchRepository,
+        },
+    },
     shared::AppError,
     user::PlayerMappingService,
 };
@@ -9,39 +24,112 @@ use crate::{
 pub struct MoveResult {
     /// The updated game state after the move
     pub game: Game,
-    /// Whether the player won the game with this move
+    /// Whether this move ended the game (in finishing-order mode the winner may be someone else)
     pub player_won: bool,
-    /// The winning hand cards if the player won (always Some when player_won is true)
+    /// The cards the winner went out with (always Some when player_won is true)
     pub winning_hand: Option<Vec<Card>>,
 }
 
+/// A room's running turn clock
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TurnClock {
+    /// The player who must act
+    pub player: String,
+    /// When they are moved for if they have not acted
+    pub expires_at: DateTime<Utc>,
+}
+
 pub struct GameService {
-    game_repository: GameRepository,
+    game_repository: std::sync::Arc<dyn GameRepository>,
+    /// Room ID -> the match being played there, kept after it ends for the final standings
+    match_repository: std::sync::Arc<dyn MatchRepository>,
     player_mapping: std::sync::Arc<dyn PlayerMappingService>,
+    scorer: std::sync::Arc<dyn MatchScorer>,
+    /// Room ID -> the clock of the player to move, in rooms with a turn time limit
+    turn_clocks: RwLock<HashMap<String, TurnClock>>,
 }
 
 impl GameService {
+    /// Create a service that keeps games in memory
     pub fn new(player_mapping: std::sync::Arc<dyn PlayerMappingService>) -> Self {
+        Self::with_repository(
+            player_mapping,
+            std::sync::Arc::new(InMemoryGameRepository::new()),
+        )
+    }
+
+    /// Create a service backed by a specific game repository (e.g. PostgreSQL)
+    pub fn with_repository(
+        player_mapping: std::sync::Arc<dyn PlayerMappingService>,
+        game_repository: std::sync::Arc<dyn GameRepository>,
+    ) -> Self {
         Self {
-            game_repository: GameRepository::new(),
+            game_repository,
+            match_repository: std::sync::Arc::new(InMemoryMatchRepository::new()),
             player_mapping,
+            scorer: std::sync::Arc::new(CardsLeftScorer),
+            turn_clocks: RwLock::new(HashMap::new()),
         }
     }
 
-    /// Create a new game for the specified room with the given players
+    /// Keep matches in a specific repository (e.g. PostgreSQL); matches stay in memory otherwise
+    pub fn with_match_repository(
+        mut self,
+        match_repository: std::sync::Arc<dyn MatchRepository>,
+    ) -> Self {
+        self.match_repository = match_repository;
+        self
+    }
+
+    /// Score match games with a specific scorer, e.g. the one StatsService records games with;
+    /// otherwise each game scores one point per card left
+    pub fn with_scorer(mut self, scorer: std::sync::Arc<dyn MatchScorer>) -> Self {
+        self.scorer = scorer;
+        self
+    }
+
+    /// Fetch a room's game, or NotFound if there is none
+    async fn load_game(&self, room_id: &str) -> Result<Game, AppError> {
+        self.game_repository
+            .get_game(room_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Game not found for room: {}", room_id)))
+    }
+
+    /// Create a new game for the specified room with the given players and the room's rules
+    ///
+    /// During a match, the winner of the previous game leads.
     pub async fn create_game(
         &self,
         room_id: &str,
         player_uuids: &[String],
+        config: GameConfig,
+    ) -> Result<Game, AppError> {
+        let leader = self
+            .get_match(room_id)
+            .await
+            .and_then(|m| m.last_winner().map(str::to_string));
+
+        self.deal_game(room_id, player_uuids, config, leader.as_deref())
+            .await
+    }
+
+    /// Deal and store a new game, with `leader` (if any) leading with any hand
+    async fn deal_game(
+        &self,
+        room_id: &str,
+        player_uuids: &[String],
+        config: GameConfig,
+        leader: Option<&str>,
     ) -> Result<Game, AppError> {
         // Input validation
         if room_id.trim().is_empty() {
             return Err(AppError::BadRequest("Room ID cannot be empty".to_string()));
         }
 
-        if player_uuids.len() != 4 {
+        if !(2..=4).contains(&player_uuids.len()) {
             return Err(AppError::BadRequest(
-                "Big Two requires exactly 4 players".to_string(),
+                "Big Two requires 2 to 4 players".to_string(),
             ));
         }
 
@@ -76,15 +164,14 @@ impl GameService {
             player_data.push((name, uuid.clone()));
         }
 
-        self.game_repository
-            .create_game(room_id, &player_data)
-            .await
+        let mut game = Game::new_game_with_config(room_id.to_string(), &player_data, &config)
             .map_err(|_e| AppError::Internal)?;
+        if let Some(leader) = leader {
+            game = game.with_leader(leader);
+        }
+        self.game_repository.save_game(room_id, &game).await?;
 
-        self.game_repository
-            .get_game(room_id)
-            .await
-            .ok_or(AppError::Internal)
+        Ok(game)
     }
 
     /// Try to play a move for a player in the specified room
@@ -96,28 +183,22 @@ impl GameService {
         cards: &[Card],
     ) -> Result<MoveResult, AppError> {
         // Get current game
-        let mut game = self
-            .game_repository
-            .get_game(room_id)
-            .await
-            .ok_or_else(|| AppError::NotFound(format!("Game not found for room: {}", room_id)))?;
+        let mut game = self.load_game(room_id).await?;
 
         // Execute the move and check if player won
-        let player_won = game
-            .play_cards(player_uuid, cards)
-            .map_err(|e| AppError::NotFound(format!("Game error: {}", e)))?;
+        let player_won = game.play_cards(player_uuid, cards)?;
 
         let winning_hand = if player_won {
-            Some(game.last_played_cards())
+            game.winning_hand()
         } else {
             None
         };
 
         // Update the game in the repository
-        self.game_repository
-            .update_game(room_id, game.clone())
-            .await
-            .map_err(|_e| AppError::Internal)?;
+        self.game_repository.save_game(room_id, &game).await?;
+        if player_won {
+            self.record_match_game(room_id, &game).await;
+        }
 
         Ok(MoveResult {
             game,
@@ -126,14 +207,165 @@ impl GameService {
         })
     }
 
+    /// End a freshly dealt game if a player holds an instant win, returning the finished game
+    pub async fn claim_instant_win(
+        &self,
+        room_id: &str,
+    ) -> Result<Option<(Game, InstantWin)>, AppError> {
+        let mut game = self.load_game(room_id).await?;
+        let Some(kind) = game.claim_instant_win() else {
+            return Ok(None);
+        };
+
+        self.game_repository.save_game(room_id, &game).await?;
+        Ok(Some((game, kind)))
+    }
+
+    /// Deal a new game for the same table after a player shows a misdealt hand
+    pub async fn redeal(&self, room_id: &str, player_uuid: &str) -> Result<Game, AppError> {
+        let game = self.load_game(room_id).await?;
+        game.validate_redeal(player_uuid)?;
+
+        let player_uuids: Vec<String> = game.players().iter().map(|p| p.uuid.clone()).collect();
+        let config = GameConfig {
+            rules: *game.rules(),
+            house_rules: *game.house_rules(),
+            take_back: game.take_back_rule(),
+            seed: None,
+        };
+        self.deal_game(room_id, &player_uuids, config, game.leader())
+            .await
+    }
+
+    /// Ask to undo a player's latest action under the game's take-back rule
+    pub async fn request_take_back(
+        &self,
+        room_id: &str,
+        player_uuid: &str,
+    ) -> Result<(Game, TakeBackRequest), AppError> {
+        let mut game = self.load_game(room_id).await?;
+        let request = game.request_take_back(player_uuid)?;
+
+        self.game_repository.save_game(room_id, &game).await?;
+        Ok((game, request))
+    }
+
+    /// Record a player's answer to a pending take-back; None if they had nothing to answer
+    pub async fn vote_take_back(
+        &self,
+        room_id: &str,
+        voter: &str,
+        approve: bool,
+    ) -> Result<Option<(Game, TakeBackVote)>, AppError> {
+        let mut game = self.load_game(room_id).await?;
+        let Some(vote) = game.vote_take_back(voter, approve) else {
+            return Ok(None);
+        };
+
+        self.game_repository.save_game(room_id, &game).await?;
+        Ok(Some((game, vote)))
+    }
+
     /// Get the current game state for a room (read-only access)
-    pub async fn get_game(&self, room_id: &str) -> Option<Game> {
+    pub async fn get_game(&self, room_id: &str) -> Result<Option<Game>, AppError> {
         self.game_repository.get_game(room_id).await
     }
 
+    /// Note the clock that just started for the player to move, replacing any earlier one
+    pub async fn start_turn_clock(&self, room_id: &str, clock: TurnClock) {
+        self.turn_clocks
+            .write()
+            .await
+            .insert(room_id.to_string(), clock);
+    }
+
+    /// Forget a room's turn clock once nobody is on the clock
+    pub async fn stop_turn_clock(&self, room_id: &str) {
+        self.turn_clocks.write().await.remove(room_id);
+    }
+
+    /// The clock running in a room, for snapshots sent to reconnecting players
+    pub async fn turn_clock(&self, room_id: &str) -> Option<TurnClock> {
+        self.turn_clocks.read().await.get(room_id).cloned()
+    }
+
     /// Remove a game from the repository (typically after completion)
     pub async fn remove_game(&self, room_id: &str) -> Option<Game> {
-        self.game_repository.remove_game(room_id).await
+        self.stop_turn_clock(room_id).await;
+        self.game_repository
+            .remove_game(room_id)
+            .await
+            .unwrap_or_else(|e| {
+                warn!(room_id = %room_id, error = %e, "Failed to remove game");
+                None
+            })
+    }
+
+    /// Every stored game with its room ID, e.g. to rebuild rooms after a restart
+    pub async fn list_games(&self) -> Result<Vec<(String, Game)>, AppError> {
+        self.game_repository.list_games().await
+    }
+
+    /// Get ready for the next game: drop a finished match, and start one if a target is given
+    ///
+    /// A target is ignored while a match is still in progress.
+    pub async fn prepare_match(&self, room_id: &str, target: Option<MatchTarget>) {
+        let current = self.get_match(room_id).await.filter(|m| !m.is_over());
+        let result = match (current, target) {
+            (Some(_), _) => Ok(()),
+            (None, Some(target)) => {
+                self.match_repository
+                    .save_match(room_id, &Match::new(target))
+                    .await
+            }
+            (None, None) => self.match_repository.remove_match(room_id).await,
+        };
+        if let Err(e) = result {
+            warn!(room_id = %room_id, error = %e, "Failed to prepare match");
+        }
+    }
+
+    /// The match in a room, including one that has just finished
+    pub async fn get_match(&self, room_id: &str) -> Option<Match> {
+        self.match_repository
+            .get_match(room_id)
+            .await
+            .unwrap_or_else(|e| {
+                warn!(room_id = %room_id, error = %e, "Failed to load match");
+                None
+            })
+    }
+
+    /// Forget a room's match (typically when the room is deleted)
+    pub async fn remove_match(&self, room_id: &str) {
+        if let Err(e) = self.match_repository.remove_match(room_id).await {
+            warn!(room_id = %room_id, error = %e, "Failed to remove match");
+        }
+    }
+
+    /// Add a game that has just ended to the room's match, if one is running
+    async fn record_match_game(&self, room_id: &str, game: &Game) {
+        let Some(winner) = game.winner() else {
+            return;
+        };
+        if self.get_match(room_id).await.is_none_or(|m| m.is_over()) {
+            return;
+        }
+
+        let scores = match self.scorer.score_game(room_id, game).await {
+            Ok(scores) => scores,
+            Err(e) => {
+                warn!(room_id = %room_id, error = %e, "Failed to score match game");
+                return;
+            }
+        };
+        if let Err(e) = self
+            .match_repository
+            .record_game(room_id, &winner, &scores)
+            .await
+        {
+            warn!(room_id = %room_id, error = %e, "Failed to record match game");
+        }
     }
 
     /// Create a new game with predetermined card distributions
@@ -146,10 +378,7 @@ impl GameService {
         let game = Game::new_game_with_cards(room_id.to_string(), player_data)
             .map_err(|e| AppError::BadRequest(format!("Invalid game setup: {}", e)))?;
 
-        self.game_repository
-            .update_game(room_id, game.clone())
-            .await
-            .map_err(|_e| AppError::Internal)?;
+        self.game_repository.save_game(room_id, &game).await?;
 
         Ok(game)
     }
@@ -160,8 +389,9 @@ mod tests {
     use super::*;
     use crate::game::{
         cards::{Card, Rank, Suit},
-        core::{Game, Player},
+        core::{Game, GameError, Player},
     };
+    use crate::shared::test_utils::cards;
 
     fn create_test_players() -> Vec<String> {
         vec![
@@ -222,13 +452,367 @@ mod tests {
         }
 
         let service = GameService::new(player_mapping);
-        let result = service.create_game("test_room", &players).await;
+        let result = service
+            .create_game("test_room", &players, GameConfig::default())
+            .await;
 
         assert!(result.is_ok());
         let game = result.unwrap();
         assert_eq!(game.players().len(), 4);
     }
 
+    #[tokio::test]
+    async fn test_create_game_with_seed_is_reproducible() {
+        use crate::user::mapping_service::InMemoryPlayerMappingService;
+        let player_mapping = std::sync::Arc::new(InMemoryPlayerMappingService::new());
+
+        let players = create_test_players();
+        for player in &players {
+            player_mapping
+                .register_player(player.clone(), format!("Player{}", player))
+                .await
+                .unwrap();
+        }
+
+        let service = GameService::new(player_mapping);
+        let config = GameConfig {
+            seed: Some(2024),
+            ..GameConfig::default()
+        };
+        let first = service
+            .create_game("room_a", &players, config)
+            .await
+            .unwrap();
+        let second = service
+            .create_game("room_b", &players, config)
+            .await
+            .unwrap();
+
+        assert_eq!(first.deal_seed(), Some(2024));
+        assert_eq!(first.starting_hands(), second.starting_hands());
+    }
+
+    #[tokio::test]
+    async fn test_redeal_keeps_the_table_and_rules() {
+        use crate::game::config::HouseRules;
+        use crate::user::mapping_service::InMemoryPlayerMappingService;
+        let player_mapping = std::sync::Arc::new(InMemoryPlayerMappingService::new());
+
+        let players = create_test_players();
+        for player in &players {
+            player_mapping
+                .register_player(player.clone(), format!("Player{}", player))
+                .await
+                .unwrap();
+        }
+
+        let service = GameService::new(player_mapping);
+        service
+            .create_game("plain", &players, GameConfig::default())
+            .await
+            .unwrap();
+        assert!(matches!(
+            service.redeal("plain", &players[0]).await,
+            Err(AppError::GameError(GameError::RedealNotAllowed))
+        ));
+
+        // With the threshold at Two every hand qualifies
+        let house_rules = HouseRules {
+            misdeal_max_rank: Some(Rank::Two),
+            ..HouseRules::default()
+        };
+        let config = GameConfig {
+            house_rules,
+            seed: Some(7),
+            ..GameConfig::default()
+        };
+        let first = service.create_game("room", &players, config).await.unwrap();
+
+        let redealt = service.redeal("room", &players[1]).await.unwrap();
+        assert_eq!(redealt.house_rules(), &house_rules);
+        assert_ne!(redealt.deal_seed(), first.deal_seed());
+        assert_eq!(redealt.players().len(), players.len());
+        assert!(service.redeal("missing", &players[1]).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_take_back_restores_the_stored_game() {
+        use crate::game::config::TakeBackRule;
+        let service = GameService::new(std::sync::Arc::new(
+            crate::user::mapping_service::InMemoryPlayerMappingService::new(),
+        ));
+        let deal = || {
+            Game::new_game_with_cards(
+                "room".to_string(),
+                vec![
+                    ("Alice".to_string(), "alice".to_string(), cards("3D 5H 9C")),
+                    ("Bob".to_string(), "bob".to_string(), cards("4C 8D")),
+                ],
+            )
+            .unwrap()
+        };
+
+        // Hand-picked deals allow no take-backs
+        service
+            .game_repository
+            .save_game("room", &deal())
+            .await
+            .unwrap();
+        service
+            .try_play_move("room", "alice", &cards("3D"))
+            .await
+            .unwrap();
+        assert!(matches!(
+            service.request_take_back("room", "alice").await,
+            Err(AppError::GameError(GameError::TakeBackDisabled))
+        ));
+
+        let consent = deal().with_take_back_rule(TakeBackRule::UnanimousConsent);
+        service
+            .game_repository
+            .save_game("room", &consent)
+            .await
+            .unwrap();
+        service
+            .try_play_move("room", "alice", &cards("3D"))
+            .await
+            .unwrap();
+        assert!(matches!(
+            service.request_take_back("room", "bob").await,
+            Err(AppError::GameError(GameError::NothingToTakeBack))
+        ));
+
+        let (_, request) = service.request_take_back("room", "alice").await.unwrap();
+        assert_eq!(
+            request,
+            TakeBackRequest::AwaitingVotes(vec!["bob".to_string()])
+        );
+        assert!(service
+            .vote_take_back("room", "alice", true)
+            .await
+            .unwrap()
+            .is_none());
+
+        let (game, vote) = service
+            .vote_take_back("room", "bob", true)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            vote,
+            TakeBackVote::TakenBack {
+                player: "alice".to_string(),
+                cards: cards("3D"),
+            }
+        );
+        assert_eq!(game.current_player_turn(), "alice");
+
+        let stored = service.get_game("room").await.unwrap().unwrap();
+        assert_eq!(stored.players()[0].cards.len(), 3);
+        assert!(stored.played_hands().is_empty());
+        assert!(service.request_take_back("missing", "alice").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_match_winner_leads_next_game() {
+        use crate::user::mapping_service::InMemoryPlayerMappingService;
+        let player_mapping = std::sync::Arc::new(InMemoryPlayerMappingService::new());
+
+        let players = create_test_players();
+        for player in &players {
+            player_mapping
+                .register_player(player.clone(), format!("Player{}", player))
+                .await
+                .unwrap();
+        }
+
+        let service = GameService::new(player_mapping);
+        service
+            .prepare_match("room", Some(MatchTarget::Games(2)))
+            .await;
+
+        // Deal the given hands in seat order and play out the listed moves
+        let play = |hands: [&'static str; 4], moves: Vec<(usize, &'static str)>| {
+            let service = &service;
+            let players = &players;
+            async move {
+                let player_data = players
+                    .iter()
+                    .zip(hands)
+                    .map(|(uuid, hand)| (uuid.clone(), uuid.clone(), cards(hand)))
+                    .collect();
+                service
+                    .create_game_with_cards("room", player_data)
+                    .await
+                    .unwrap();
+                for (seat, codes) in moves {
+                    service
+                        .try_play_move("room", &players[seat], &cards(codes))
+                        .await
+                        .unwrap();
+                }
+            }
+        };
+
+        // The match records the game as soon as its winning move is played
+        play(
+            ["3D 4D", "5D 6D", "2S", "7D 8D"],
+            vec![(0, "3D"), (1, "5D"), (2, "2S")],
+        )
+        .await;
+        let current = service.get_match("room").await.unwrap();
+        assert_eq!(current.games_played(), 1);
+        assert!(!current.is_over());
+        // Scored like the stats: one point per card left
+        let points: Vec<(String, i32)> = current
+            .standings()
+            .into_iter()
+            .map(|s| (s.player, s.points))
+            .collect();
+        assert_eq!(
+            points,
+            vec![
+                (players[2].clone(), 0),
+                (players[0].clone(), 1),
+                (players[1].clone(), 1),
+                (players[3].clone(), 2),
+            ]
+        );
+
+        let game = service
+            .create_game("room", &players, GameConfig::default())
+            .await
+            .unwrap();
+        assert_eq!(game.current_player_turn(), players[2]);
+
+        // A new target is ignored while the match is running
+        service
+            .prepare_match("room", Some(MatchTarget::Points(1)))
+            .await;
+        play(["3D", "5D 6D", "2S 4D", "7D 8D"], vec![(0, "3D")]).await;
+        let current = service.get_match("room").await.unwrap();
+        assert!(current.is_over());
+        assert_eq!(current.target(), MatchTarget::Games(2));
+        assert_eq!(current.standings()[0].games_won, 1);
+
+        // Finished matches take no more games, and are cleared before the next start
+        play(["3D", "5D 6D", "2S 4D", "7D 8D"], vec![(0, "3D")]).await;
+        assert_eq!(service.get_match("room").await.unwrap().games_played(), 2);
+        service.prepare_match("room", None).await;
+        assert!(service.get_match("room").await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_match_scores_finishing_places() {
+        use crate::game::HouseRules;
+        use crate::stats::scorer::GameScorer;
+        use crate::user::mapping_service::InMemoryPlayerMappingService;
+        let service = GameService::new(std::sync::Arc::new(InMemoryPlayerMappingService::new()))
+            .with_scorer(std::sync::Arc::new(GameScorer::default()));
+
+        let game = Game::new_game_with_cards(
+            "room".to_string(),
+            vec![
+                ("Alice".to_string(), "alice".to_string(), cards("3D")),
+                ("Bob".to_string(), "bob".to_string(), cards("4D 9S")),
+                ("Carol".to_string(), "carol".to_string(), cards("5D 6D")),
+            ],
+        )
+        .unwrap()
+        .with_house_rules(HouseRules {
+            finishing_order: true,
+            ..HouseRules::default()
+        });
+        service
+            .game_repository
+            .save_game("room", &game)
+            .await
+            .unwrap();
+        service
+            .prepare_match("room", Some(MatchTarget::Points(100)))
+            .await;
+
+        for (player, codes) in [("alice", "3D"), ("bob", "9S"), ("carol", ""), ("bob", "4D")] {
+            service
+                .try_play_move("room", player, &cards(codes))
+                .await
+                .unwrap();
+        }
+
+        // Five points per place behind the winner on top of the cards left
+        let points: Vec<(String, i32)> = service
+            .get_match("room")
+            .await
+            .unwrap()
+            .standings()
+            .into_iter()
+            .map(|s| (s.player, s.points))
+            .collect();
+        assert_eq!(
+            points,
+            vec![
+                ("alice".to_string(), 0),
+                ("bob".to_string(), 5),
+                ("carol".to_string(), 12),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_restarted_service_continues_the_game_and_match() {
+        use crate::user::mapping_service::InMemoryPlayerMappingService;
+        let player_mapping = std::sync::Arc::new(InMemoryPlayerMappingService::new());
+        let games = std::sync::Arc::new(InMemoryGameRepository::new());
+        let matches = std::sync::Arc::new(InMemoryMatchRepository::new());
+        let players = create_test_players();
+
+        let service = GameService::with_repository(player_mapping.clone(), games.clone())
+            .with_match_repository(matches.clone());
+        service
+            .prepare_match("room", Some(MatchTarget::Games(2)))
+            .await;
+        let player_data = players
+            .iter()
+            .zip(["3D 4D", "5D 6D", "2S", "7D 8D"])
+            .map(|(uuid, hand)| (uuid.clone(), uuid.clone(), cards(hand)))
+            .collect();
+        service
+            .create_game_with_cards("room", player_data)
+            .await
+            .unwrap();
+        service
+            .try_play_move("room", &players[0], &cards("3D"))
+            .await
+            .unwrap();
+        drop(service);
+
+        // A new service on the same storage picks up where the old one stopped
+        let service =
+            GameService::with_repository(player_mapping, games).with_match_repository(matches);
+        let (room_id, game) = service.list_games().await.unwrap().remove(0);
+        assert_eq!(room_id, "room");
+        assert_eq!(game.current_player_turn(), players[1]);
+        assert_eq!(game.moves().len(), 1);
+
+        service
+            .try_play_move("room", &players[1], &cards("5D"))
+            .await
+            .unwrap();
+        let result = service
+            .try_play_move("room", &players[2], &cards("2S"))
+            .await
+            .unwrap();
+        assert!(result.player_won);
+
+        let current = service.get_match("room").await.unwrap();
+        assert_eq!(current.target(), MatchTarget::Games(2));
+        assert_eq!(current.games_played(), 1);
+        assert_eq!(current.last_winner(), Some(players[2].as_str()));
+
+        service.remove_match("room").await;
+        assert!(service.get_match("room").await.is_none());
+    }
+
     #[tokio::test]
     async fn test_try_play_move_success() {
         use crate::user::mapping_service::InMemoryPlayerMappingService;
@@ -251,10 +835,13 @@ mod tests {
         }
 
         let service = GameService::new(player_mapping);
-        service.create_game("test_room", &players).await.unwrap();
+        service
+            .create_game("test_room", &players, GameConfig::default())
+            .await
+            .unwrap();
 
         // Get the game to see who has 3D and goes first
-        let game = service.get_game("test_room").await.unwrap();
+        let game = service.get_game("test_room").await.unwrap().unwrap();
         let first_player = game.current_player_turn();
 
         // First player plays 3D (valid first move)
@@ -292,6 +879,45 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_try_play_move_surfaces_game_error() {
+        use crate::game::core::GameError;
+        use crate::user::mapping_service::InMemoryPlayerMappingService;
+        let player_mapping = std::sync::Arc::new(InMemoryPlayerMappingService::new());
+        let service = GameService::new(player_mapping);
+
+        service
+            .create_game_with_cards(
+                "room",
+                vec![
+                    (
+                        "Alice".to_string(),
+                        "alice-uuid".to_string(),
+                        vec![Card::new(Rank::Three, Suit::Diamonds)],
+                    ),
+                    (
+                        "Bob".to_string(),
+                        "bob-uuid".to_string(),
+                        vec![Card::new(Rank::Four, Suit::Diamonds)],
+                    ),
+                ],
+            )
+            .await
+            .unwrap();
+
+        let result = service
+            .try_play_move("room", "bob-uuid", &[Card::new(Rank::Four, Suit::Diamonds)])
+            .await;
+
+        match result.unwrap_err() {
+            AppError::GameError(e) => {
+                assert!(matches!(e, GameError::InvalidPlayerTurn));
+                assert_eq!(e.code(), "NOT_YOUR_TURN");
+            }
+            other => panic!("Expected GameError, got {:?}", other),
+        }
+    }
+
     #[tokio::test]
     async fn test_try_play_move_player_wins() {
         use crate::user::mapping_service::InMemoryPlayerMappingService;
@@ -314,10 +940,13 @@ mod tests {
         }
 
         let service = GameService::new(player_mapping);
-        service.create_game("test_room", &players).await.unwrap();
+        service
+            .create_game("test_room", &players, GameConfig::default())
+            .await
+            .unwrap();
 
         // Get the game and check who goes first
-        let game = service.get_game("test_room").await.unwrap();
+        let game = service.get_game("test_room").await.unwrap().unwrap();
 
         // Find the player with 3D (since they must play it first)
         let player_with_3d = game
@@ -0,0 +1,366 @@
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use chrono::Utc;
+use tokio::{task::JoinHandle, time::Duration};
+use tracing::{debug, info, warn};
+
+use crate::{
+    bot::types::BotPlayer,
+    event::{EventBus, RoomEvent, RoomEventError, RoomEventHandler},
+    game::{
+        cards::Card,
+        core::Game,
+        service::{GameService, TurnClock},
+    },
+};
+
+/// What a player who ran out of time plays: a pass if allowed, otherwise their lowest legal single
+///
+/// Leading never passes. On the opening turn the only legal singles hold the opening card.
+fn timeout_move(game: &Game, player_uuid: &str) -> Vec<Card> {
+    let moves = game.legal_moves(player_uuid);
+    if moves.can_pass {
+        return Vec::new();
+    }
+
+    let lowest_single = moves
+        .singles
+        .iter()
+        .flat_map(|hand| hand.to_cards())
+        .min_by(|a, b| game.rules().compare_cards(a, b));
+    match lowest_single {
+        Some(card) => vec![card],
+        // Only reachable if a house rule forbids every single; play the first legal hand instead
+        None => moves
+            .hands()
+            .next()
+            .map(|hand| hand.to_cards())
+            .unwrap_or_default(),
+    }
+}
+
+/// Event subscriber that runs a room's turn clock
+///
+/// Each TurnChanged starts a fresh clock for the player to move; if it runs out before
+/// they act, the subscriber moves for them. Bots are not put on the clock, since their
+/// thinking time is already capped. The running clock is noted in the GameService so
+/// snapshots can show it.
+pub struct TurnTimerRoomSubscriber {
+    game_service: Arc<GameService>,
+    event_bus: EventBus,
+    turn_time_limit_secs: u32,
+    clock: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl TurnTimerRoomSubscriber {
+    pub fn new(
+        game_service: Arc<GameService>,
+        event_bus: EventBus,
+        turn_time_limit_secs: u32,
+    ) -> Self {
+        Self {
+            game_service,
+            event_bus,
+            turn_time_limit_secs,
+            clock: Mutex::new(None),
+        }
+    }
+
+    fn stop_clock(&self) {
+        if let Some(clock) = self.clock.lock().unwrap().take() {
+            clock.abort();
+        }
+    }
+
+    async fn handle_turn_changed(
+        &self,
+        room_id: &str,
+        player_uuid: &str,
+    ) -> Result<(), RoomEventError> {
+        self.stop_clock();
+        self.game_service.stop_turn_clock(room_id).await;
+
+        let Some(game) = self
+            .game_service
+            .get_game(room_id)
+            .await
+            .map_err(|e| RoomEventError::HandlerError(format!("Failed to load game: {}", e)))?
+        else {
+            return Ok(());
+        };
+        if game.is_over()
+            || game.current_player_turn() != player_uuid
+            || BotPlayer::is_bot_uuid(player_uuid)
+        {
+            return Ok(());
+        }
+
+        let limit = Duration::from_secs(u64::from(self.turn_time_limit_secs));
+        let expires_at = Utc::now() + limit;
+        self.game_service
+            .start_turn_clock(
+                room_id,
+                TurnClock {
+                    player: player_uuid.to_string(),
+                    expires_at,
+                },
+            )
+            .await;
+        self.event_bus
+            .emit_to_room(
+                room_id,
+                RoomEvent::TurnClockStarted {
+                    player: player_uuid.to_string(),
+                    expires_at,
+                },
+            )
+            .await;
+
+        let game_service = Arc::clone(&self.game_service);
+        let event_bus = self.event_bus.clone();
+        let room_id = room_id.to_string();
+        let player_uuid = player_uuid.to_string();
+        let move_count = game.moves().len();
+
+        let clock = tokio::spawn(async move {
+            tokio::time::sleep(limit).await;
+
+            // Guard against a move that landed just as the clock ran out
+            let game = match game_service.get_game(&room_id).await {
+                Ok(Some(game)) => game,
+                Ok(None) => return,
+                Err(e) => {
+                    warn!(room_id = %room_id, error = %e, "Failed to load game when the turn clock expired");
+                    return;
+                }
+            };
+            if game.is_over()
+                || game.current_player_turn() != player_uuid
+                || game.moves().len() != move_count
+            {
+                debug!(room_id = %room_id, player_uuid = %player_uuid, "Turn clock expired after the turn ended");
+                return;
+            }
+
+            let cards = timeout_move(&game, &player_uuid);
+            info!(
+                room_id = %room_id,
+                player_uuid = %player_uuid,
+                cards = ?cards,
+                "Turn clock expired, moving for player"
+            );
+            event_bus
+                .emit_to_room(
+                    &room_id,
+                    RoomEvent::TryPlayMove {
+                        player: player_uuid,
+                        cards,
+                    },
+                )
+                .await;
+        });
+
+        *self.clock.lock().unwrap() = Some(clock);
+        Ok(())
+    }
+}
+
+impl Drop for TurnTimerRoomSubscriber {
+    fn drop(&mut self) {
+        self.stop_clock();
+    }
+}
+
+#[async_trait]
+impl RoomEventHandler for TurnTimerRoomSubscriber {
+    async fn handle_room_event(
+        &self,
+        room_id: &str,
+        event: RoomEvent,
+    ) -> Result<(), RoomEventError> {
+        match event {
+            RoomEvent::TurnChanged { player } => {
+                self.handle_turn_changed(room_id, &player).await?;
+            }
+            RoomEvent::GameWon { .. } | RoomEvent::Misdeal { .. } => {
+                self.stop_clock();
+                self.game_service.stop_turn_clock(room_id).await;
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn handler_name(&self) -> &'static str {
+        "TurnTimerRoomSubscriber"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::test_utils::cards;
+    use crate::user::mapping_service::InMemoryPlayerMappingService;
+
+    fn game(alice: &str, bob: &str) -> Game {
+        Game::new_game_with_cards(
+            "clock".to_string(),
+            vec![
+                ("Alice".to_string(), "alice".to_string(), cards(alice)),
+                ("Bob".to_string(), "bob".to_string(), cards(bob)),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_timeout_move() {
+        // Opening: passing is allowed, so the clock passes
+        let opening = game("4H 3D 5S", "3C 6C");
+        assert!(timeout_move(&opening, "alice").is_empty());
+
+        let mut game = game("5S 3D 4H", "6C 7D 2S");
+        game.play_cards("alice", &cards("3D")).unwrap();
+
+        // Following: pass
+        assert!(timeout_move(&game, "bob").is_empty());
+
+        // Leading a cleared table, where passing is illegal: the lowest card
+        game.play_cards("bob", &[]).unwrap();
+        assert!(!game.legal_moves("alice").can_pass);
+        assert_eq!(timeout_move(&game, "alice"), cards("4H"));
+    }
+
+    #[tokio::test]
+    async fn test_clock_moves_for_an_idle_player() {
+        let game_service = Arc::new(GameService::new(Arc::new(
+            InMemoryPlayerMappingService::new(),
+        )));
+        let event_bus = EventBus::new();
+        game_service
+            .create_game_with_cards(
+                "room",
+                vec![
+                    ("Alice".to_string(), "alice".to_string(), cards("5S 3D")),
+                    ("Bob".to_string(), "bob".to_string(), cards("6C 7D")),
+                ],
+            )
+            .await
+            .unwrap();
+        let subscriber = TurnTimerRoomSubscriber::new(game_service.clone(), event_bus.clone(), 1);
+        let mut rx = event_bus.subscribe_to_room("room").await;
+
+        subscriber
+            .handle_room_event(
+                "room",
+                RoomEvent::TurnChanged {
+                    player: "alice".to_string(),
+                },
+            )
+            .await
+            .unwrap();
+
+        let RoomEvent::TurnClockStarted { player, expires_at } = rx.recv().await.unwrap() else {
+            panic!("expected TurnClockStarted");
+        };
+        assert_eq!(player, "alice");
+        // Snapshots see the same deadline the room was told about
+        assert_eq!(
+            game_service.turn_clock("room").await,
+            Some(TurnClock { player, expires_at })
+        );
+        // The opener may pass, so that is what the clock does
+        let expired = tokio::time::timeout(Duration::from_secs(3), rx.recv()).await;
+        match expired.unwrap().unwrap() {
+            RoomEvent::TryPlayMove {
+                player,
+                cards: played,
+            } => {
+                assert_eq!(player, "alice");
+                assert!(played.is_empty());
+            }
+            other => panic!("expected TryPlayMove, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_game_won_stops_the_clock() {
+        let game_service = Arc::new(GameService::new(Arc::new(
+            InMemoryPlayerMappingService::new(),
+        )));
+        let event_bus = EventBus::new();
+        game_service
+            .create_game_with_cards(
+                "room",
+                vec![
+                    ("Alice".to_string(), "alice".to_string(), cards("5S 3D")),
+                    ("Bob".to_string(), "bob".to_string(), cards("6C 7D")),
+                ],
+            )
+            .await
+            .unwrap();
+        let subscriber = TurnTimerRoomSubscriber::new(game_service.clone(), event_bus.clone(), 1);
+        let mut rx = event_bus.subscribe_to_room("room").await;
+
+        subscriber
+            .handle_room_event(
+                "room",
+                RoomEvent::TurnChanged {
+                    player: "alice".to_string(),
+                },
+            )
+            .await
+            .unwrap();
+        rx.recv().await.unwrap();
+        subscriber
+            .handle_room_event(
+                "room",
+                RoomEvent::GameWon {
+                    winner: "bob".to_string(),
+                    winning_hand: vec![],
+                },
+            )
+            .await
+            .unwrap();
+
+        let next = tokio::time::timeout(Duration::from_millis(1500), rx.recv()).await;
+        assert!(next.is_err());
+        assert_eq!(game_service.turn_clock("room").await, None);
+    }
+
+    #[tokio::test]
+    async fn test_bots_are_not_put_on_the_clock() {
+        let game_service = Arc::new(GameService::new(Arc::new(
+            InMemoryPlayerMappingService::new(),
+        )));
+        let event_bus = EventBus::new();
+        game_service
+            .create_game_with_cards(
+                "room",
+                vec![
+                    ("Bot".to_string(), "bot-1".to_string(), cards("5S 3D")),
+                    ("Bob".to_string(), "bob".to_string(), cards("6C 7D")),
+                ],
+            )
+            .await
+            .unwrap();
+        let subscriber = TurnTimerRoomSubscriber::new(game_service.clone(), event_bus.clone(), 1);
+        let mut rx = event_bus.subscribe_to_room("room").await;
+
+        subscriber
+            .handle_room_event(
+                "room",
+                RoomEvent::TurnChanged {
+                    player: "bot-1".to_string(),
+                },
+            )
+            .await
+            .unwrap();
+
+        let next = tokio::time::timeout(Duration::from_millis(1500), rx.recv()).await;
+        assert!(next.is_err());
+        assert_eq!(game_service.turn_clock("room").await, None);
+    }
+}
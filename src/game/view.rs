@@ -0,0 +1,176 @@
+// A player view is the redacted projection of a game that is safe to send to a single player.
+// It never contains another player's cards; opponents are only visible through their card counts.
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::game::{cards::Card, core::Game};
+
+/// Public information about a single seat at the table
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SeatView {
+    pub uuid: String,
+    pub name: String,
+    pub card_count: usize,
+}
+
+/// What a single player is allowed to see of a game
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlayerView {
+    pub game_id: String,
+    /// UUID of the player this view was built for
+    pub player_uuid: String,
+    /// The viewing player's own hand
+    pub cards: Vec<Card>,
+    /// Every seat in turn order, starting with the player who led the game
+    pub seats: Vec<SeatView>,
+    pub current_turn: String,
+    pub consecutive_passes: usize,
+    /// Whether the next play may be any hand (start of game or everyone else passed)
+    pub table_clear: bool,
+    /// Cards from the last non-pass hand, empty if nothing has been played
+    pub last_played_cards: Vec<Card>,
+    pub last_played_by: Option<String>,
+    /// UUID -> last played cards (empty vec for a pass)
+    pub last_plays_by_player: HashMap<String, Vec<Card>>,
+    /// UUID of the player who emptied their hand, once the game is won
+    pub winner: Option<String>,
+    /// Players who have gone out so far, first place first
+    pub finishing_order: Vec<String>,
+}
+
+impl PlayerView {
+    /// Build the view of `game` for `player_uuid`, or None if they are not seated in it
+    pub(crate) fn from_game(game: &Game, player_uuid: &str) -> Option<Self> {
+        let viewer = game.players().iter().find(|p| p.uuid == player_uuid)?;
+
+        let seats = game
+            .players()
+            .iter()
+            .map(|p| SeatView {
+                uuid: p.uuid.clone(),
+                name: p.name.clone(),
+                card_count: p.cards.len(),
+            })
+            .collect();
+
+        Some(Self {
+            game_id: game.id().to_string(),
+            player_uuid: viewer.uuid.clone(),
+            cards: viewer.cards.clone(),
+            seats,
+            current_turn: game.current_player_turn(),
+            consecutive_passes: game.consecutive_passes(),
+            table_clear: game.is_table_clear(),
+            last_played_cards: game.last_non_pass_cards(),
+            last_played_by: game.last_non_pass_player_uuid(),
+            last_plays_by_player: game.last_plays_by_player().clone(),
+            winner: game.winner(),
+            finishing_order: game.finishing_order().to_vec(),
+        })
+    }
+
+    /// Seat UUIDs in turn order
+    pub fn player_uuids(&self) -> Vec<String> {
+        self.seats.iter().map(|s| s.uuid.clone()).collect()
+    }
+
+    /// UUID -> number of cards left in hand
+    pub fn card_counts(&self) -> HashMap<String, usize> {
+        self.seats
+            .iter()
+            .map(|s| (s.uuid.clone(), s.card_count))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::cards::{Rank, Suit};
+
+    fn two_player_game() -> Game {
+        Game::new_game_with_cards(
+            "game-1".to_string(),
+            vec![
+                (
+                    "Alice".to_string(),
+                    "alice-uuid".to_string(),
+                    vec![
+                        Card::new(Rank::Three, Suit::Diamonds),
+                        Card::new(Rank::Five, Suit::Hearts),
+                    ],
+                ),
+                (
+                    "Bob".to_string(),
+                    "bob-uuid".to_string(),
+                    vec![
+                        Card::new(Rank::Four, Suit::Clubs),
+                        Card::new(Rank::Six, Suit::Spades),
+                        Card::new(Rank::Seven, Suit::Spades),
+                    ],
+                ),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_view_only_contains_own_cards() {
+        let game = two_player_game();
+        let view = game.view_for("alice-uuid").unwrap();
+
+        assert_eq!(view.game_id, "game-1");
+        assert_eq!(view.player_uuid, "alice-uuid");
+        assert_eq!(
+            view.cards,
+            vec![
+                Card::new(Rank::Three, Suit::Diamonds),
+                Card::new(Rank::Five, Suit::Hearts),
+            ]
+        );
+        assert_eq!(view.player_uuids(), vec!["alice-uuid", "bob-uuid"]);
+        assert_eq!(view.card_counts().get("bob-uuid"), Some(&3));
+        assert_eq!(view.current_turn, "alice-uuid");
+        assert!(view.table_clear);
+        assert!(view.winner.is_none());
+
+        // Opponents only show up as a seat with a card count, never with a card list
+        let json = serde_json::to_value(&view).unwrap();
+        for seat in json["seats"].as_array().unwrap() {
+            let mut fields: Vec<&String> = seat.as_object().unwrap().keys().collect();
+            fields.sort();
+            assert_eq!(fields, vec!["card_count", "name", "uuid"]);
+        }
+        assert_eq!(json["cards"], serde_json::to_value(&view.cards).unwrap());
+        let bob = game
+            .players()
+            .iter()
+            .find(|p| p.uuid == "bob-uuid")
+            .unwrap();
+        assert!(bob.cards.iter().all(|card| !view.cards.contains(card)));
+    }
+
+    #[test]
+    fn test_view_for_unknown_player() {
+        let game = two_player_game();
+        assert!(game.view_for("mallory-uuid").is_none());
+    }
+
+    #[test]
+    fn test_view_tracks_table_state() {
+        let mut game = two_player_game();
+        game.play_cards("alice-uuid", &[Card::new(Rank::Three, Suit::Diamonds)])
+            .unwrap();
+
+        let view = game.view_for("bob-uuid").unwrap();
+        assert_eq!(view.current_turn, "bob-uuid");
+        assert!(!view.table_clear);
+        assert_eq!(
+            view.last_played_cards,
+            vec![Card::new(Rank::Three, Suit::Diamonds)]
+        );
+        assert_eq!(view.last_played_by.as_deref(), Some("alice-uuid"));
+        assert_eq!(view.card_counts().get("alice-uuid"), Some(&1));
+    }
+}
@@ -16,6 +16,9 @@ use axum::{
 };
 use room::repository::InMemoryRoomRepository;
 use room::service::RoomService;
+use room::setup_repository::{
+    InMemoryRoomSetupRepository, PostgresRoomSetupRepository, RoomSetupRepository,
+};
 use session::repository::{
     InMemorySessionRepository, PostgresSessionRepository, SessionRepository,
 };
@@ -30,7 +33,9 @@ use crate::bot::BotManager;
 use crate::stats::{service::StatsService, InMemoryStatsRepository};
 use crate::websockets::InMemoryConnectionManager;
 use crate::{
-    event::EventBus, game::GameService, user::mapping_service::InMemoryPlayerMappingService,
+    event::EventBus,
+    game::{GameService, PostgresGameRepository, PostgresMatchRepository},
+    user::mapping_service::InMemoryPlayerMappingService,
 };
 
 #[tokio::main]
@@ -48,29 +53,37 @@ async fn main() {
 
     // Create shared application state with dependency injection
     // Smart configuration: Use PostgreSQL if DATABASE_URL is set, otherwise in-memory
-    let session_repository: Arc<dyn SessionRepository + Send + Sync> =
-        match std::env::var("DATABASE_URL") {
-            Ok(database_url) => {
-                info!("Using PostgreSQL session storage (persistent across restarts)");
-                match sqlx::PgPool::connect(&database_url).await {
-                    Ok(pool) => {
-                        info!("✅ Connected to PostgreSQL successfully");
-                        Arc::new(PostgresSessionRepository::new(pool))
-                    }
-                    Err(e) => {
-                        warn!("❌ Failed to connect to PostgreSQL: {}", e);
-                        info!("🔄 Falling back to in-memory session storage");
-                        Arc::new(InMemorySessionRepository::new())
-                    }
+    let pool = match std::env::var("DATABASE_URL") {
+        Ok(database_url) => {
+            info!("Using PostgreSQL session and game storage (persistent across restarts)");
+            match sqlx::PgPool::connect(&database_url).await {
+                Ok(pool) => {
+                    info!("✅ Connected to PostgreSQL successfully");
+                    Some(pool)
+                }
+                Err(e) => {
+                    warn!("❌ Failed to connect to PostgreSQL: {}", e);
+                    info!("🔄 Falling back to in-memory session and game storage");
+                    None
                 }
             }
-            Err(e) => {
-                info!("Error getting DATABASE_URL: {}", e);
-                info!("Using in-memory session storage (sessions lost on restart)");
-                info!("💡 Set DATABASE_URL to use PostgreSQL for persistent sessions");
-                Arc::new(InMemorySessionRepository::new())
-            }
-        };
+        }
+        Err(e) => {
+            info!("Error getting DATABASE_URL: {}", e);
+            info!("Using in-memory session and game storage (lost on restart)");
+            info!("💡 Set DATABASE_URL to use PostgreSQL for persistent sessions and games");
+            None
+        }
+    };
+
+    let session_repository: Arc<dyn SessionRepository + Send + Sync> = match &pool {
+        Some(pool) => Arc::new(PostgresSessionRepository::new(pool.clone())),
+        None => Arc::new(InMemorySessionRepository::new()),
+    };
+    let room_setup_repository: Arc<dyn RoomSetupRepository + Send + Sync> = match &pool {
+        Some(pool) => Arc::new(PostgresRoomSetupRepository::new(pool.clone())),
+        None => Arc::new(InMemoryRoomSetupRepository::new()),
+    };
 
     let player_mapping = Arc::new(InMemoryPlayerMappingService::new());
     let session_service = Arc::new(SessionService::new(
@@ -80,7 +93,6 @@ async fn main() {
     let room_repository = Arc::new(InMemoryRoomRepository::new());
     let event_bus = EventBus::new();
     let connection_manager = Arc::new(InMemoryConnectionManager::new());
-    let game_service = Arc::new(GameService::new(player_mapping.clone()));
     let bot_manager = Arc::new(BotManager::new());
 
     // Stats system: in-memory tracking of per-room game statistics
@@ -91,8 +103,21 @@ async fn main() {
             .with_bot_manager(bot_manager.clone())
             .build(),
     );
+    // Matches add up the same scores the stats record
+    let game_service = match &pool {
+        Some(pool) => GameService::with_repository(
+            player_mapping.clone(),
+            Arc::new(PostgresGameRepository::new(pool.clone())),
+        )
+        .with_match_repository(Arc::new(PostgresMatchRepository::new(pool.clone()))),
+        None => GameService::new(player_mapping.clone()),
+    };
+    let game_service = Arc::new(game_service.with_scorer(stats_service.scorer()));
     // Create RoomService focused purely on business logic
-    let room_service = Arc::new(RoomService::new(room_repository.clone()));
+    let room_service = Arc::new(
+        RoomService::new(room_repository.clone())
+            .with_setup_repository(room_setup_repository.clone()),
+    );
 
     // Activity tracking: monitors room events and updates activity timestamps
     let activity_tracker = Arc::new(room::activity_tracker::ActivityTracker::new(
@@ -106,6 +131,7 @@ async fn main() {
     let cleanup_config = room::cleanup_task::CleanupConfig::default();
     tokio::spawn(room::cleanup_task::start_cleanup_task(
         room_repository.clone(),
+        room_setup_repository,
         game_service.clone(),
         Arc::new(event_bus.clone()),
         cleanup_config,
@@ -126,6 +152,12 @@ async fn main() {
         .build()
         .expect("Failed to build AppState - all required dependencies should be provided");
 
+    // Pick stored games back up: rooms only live in memory, so rebuild them around their games
+    match room::resume::resume_rooms(&app_state).await {
+        Ok(resumed) => info!(resumed = resumed, "Resumed rooms from stored games"),
+        Err(e) => warn!("Failed to resume rooms from stored games: {}", e),
+    }
+
     // Configure CORS for development and production
     // Allow origins from environment variable for production, fallback to localhost for dev
     // Set ALLOWED_ORIGINS="*" to allow all origins (dev/staging only!)
@@ -199,6 +231,13 @@ async fn main() {
                 middleware::from_fn_with_state(app_state.clone(), session::jwt_auth),
             ),
         )
+        .route(
+            "/analysis/endgame",
+            post(bot::handlers::analyze_endgame_position).layer(middleware::from_fn_with_state(
+                app_state.clone(),
+                session::jwt_auth,
+            )),
+        )
         .route("/ws/:room_id", get(websockets::websocket_handler))
         .layer(cors)
         .layer(TraceLayer::new_for_http())
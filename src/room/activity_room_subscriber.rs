@@ -33,6 +33,10 @@ impl RoomEventHandler for ActivityRoomSubscriber {
                 | RoomEvent::ChatMessage { .. }
                 | RoomEvent::TryStartGame { .. }
                 | RoomEvent::TryPlayMove { .. }
+                | RoomEvent::TryPlayText { .. }
+                | RoomEvent::RedealRequested { .. }
+                | RoomEvent::TryTakeBack { .. }
+                | RoomEvent::TakeBackVote { .. }
                 | RoomEvent::PlayerReadyToggled { .. }
                 | RoomEvent::BotAdded { .. }
                 | RoomEvent::BotRemoved { .. }
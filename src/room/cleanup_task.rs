@@ -4,6 +4,7 @@ use tokio::time::interval;
 use tracing::{error, info, instrument, warn};
 
 use super::repository::RoomRepository;
+use super::setup_repository::RoomSetupRepository;
 use crate::event::EventBus;
 use crate::game::GameService;
 
@@ -26,9 +27,10 @@ impl Default for CleanupConfig {
 }
 
 /// Starts the background cleanup task that periodically removes inactive rooms
-#[instrument(skip(room_repository, game_service, event_bus))]
+#[instrument(skip(room_repository, setup_repository, game_service, event_bus))]
 pub async fn start_cleanup_task(
     room_repository: Arc<dyn RoomRepository + Send + Sync>,
+    setup_repository: Arc<dyn RoomSetupRepository + Send + Sync>,
     game_service: Arc<GameService>,
     event_bus: Arc<EventBus>,
     config: CleanupConfig,
@@ -48,6 +50,7 @@ pub async fn start_cleanup_task(
 
         match cleanup_inactive_rooms(
             &room_repository,
+            &setup_repository,
             &game_service,
             &event_bus,
             config.inactivity_threshold,
@@ -65,9 +68,10 @@ pub async fn start_cleanup_task(
 }
 
 /// Cleans up rooms that have been inactive for longer than the threshold
-#[instrument(skip(room_repository, game_service, event_bus))]
+#[instrument(skip(room_repository, setup_repository, game_service, event_bus))]
 async fn cleanup_inactive_rooms(
     room_repository: &Arc<dyn RoomRepository + Send + Sync>,
+    setup_repository: &Arc<dyn RoomSetupRepository + Send + Sync>,
     game_service: &Arc<GameService>,
     event_bus: &Arc<EventBus>,
     inactivity_threshold: Duration,
@@ -90,7 +94,15 @@ async fn cleanup_inactive_rooms(
     let mut deleted_count = 0;
 
     for room_id in inactive_room_ids {
-        match delete_room(room_repository, game_service, event_bus, &room_id).await {
+        match delete_room(
+            room_repository,
+            setup_repository,
+            game_service,
+            event_bus,
+            &room_id,
+        )
+        .await
+        {
             Ok(()) => {
                 deleted_count += 1;
                 info!(room_id = %room_id, "Deleted inactive room");
@@ -111,12 +123,17 @@ async fn cleanup_inactive_rooms(
 /// Deletes a room and cleans up associated resources
 async fn delete_room(
     room_repository: &Arc<dyn RoomRepository + Send + Sync>,
+    setup_repository: &Arc<dyn RoomSetupRepository + Send + Sync>,
     game_service: &Arc<GameService>,
     _event_bus: &Arc<EventBus>,
     room_id: &str,
 ) -> Result<(), crate::shared::AppError> {
-    // Remove game state if exists
+    // Remove game, match and setup state if exists
     game_service.remove_game(room_id).await;
+    game_service.remove_match(room_id).await;
+    if let Err(e) = setup_repository.remove_setup(room_id).await {
+        warn!(room_id = %room_id, error = %e, "Failed to remove room setup");
+    }
 
     // Delete the room from repository
     room_repository.delete_room(room_id).await?;
@@ -127,7 +144,10 @@ async fn delete_room(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::room::{models::RoomModel, repository::InMemoryRoomRepository};
+    use crate::room::{
+        models::RoomModel, repository::InMemoryRoomRepository,
+        setup_repository::InMemoryRoomSetupRepository,
+    };
     use crate::user::mapping_service::InMemoryPlayerMappingService;
 
     #[tokio::test]
@@ -138,6 +158,8 @@ mod tests {
             InMemoryPlayerMappingService::new(),
         )));
         let event_bus = Arc::new(EventBus::new());
+        let setups: Arc<dyn RoomSetupRepository + Send + Sync> =
+            Arc::new(InMemoryRoomSetupRepository::new());
 
         // Create a room
         let room = RoomModel::new("test-host".to_string());
@@ -151,10 +173,15 @@ mod tests {
         tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
 
         // Run cleanup with a very short threshold (should delete the room)
-        let deleted =
-            cleanup_inactive_rooms(&repo, &game_service, &event_bus, Duration::from_millis(1))
-                .await
-                .unwrap();
+        let deleted = cleanup_inactive_rooms(
+            &repo,
+            &setups,
+            &game_service,
+            &event_bus,
+            Duration::from_millis(1),
+        )
+        .await
+        .unwrap();
 
         assert_eq!(deleted, 1);
 
@@ -170,6 +197,8 @@ mod tests {
             InMemoryPlayerMappingService::new(),
         )));
         let event_bus = Arc::new(EventBus::new());
+        let setups: Arc<dyn RoomSetupRepository + Send + Sync> =
+            Arc::new(InMemoryRoomSetupRepository::new());
 
         // Create a room
         let room = RoomModel::new("test-host".to_string());
@@ -179,6 +208,7 @@ mod tests {
         // Run cleanup with a very long threshold (should not delete the room)
         let deleted = cleanup_inactive_rooms(
             &repo,
+            &setups,
             &game_service,
             &event_bus,
             Duration::from_secs(24 * 60 * 60), // 24 hours
@@ -200,6 +230,8 @@ mod tests {
             InMemoryPlayerMappingService::new(),
         )));
         let event_bus = Arc::new(EventBus::new());
+        let setups: Arc<dyn RoomSetupRepository + Send + Sync> =
+            Arc::new(InMemoryRoomSetupRepository::new());
 
         // Create multiple rooms
         let room1 = RoomModel::new("host1".to_string());
@@ -214,10 +246,15 @@ mod tests {
         tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
 
         // All rooms should be inactive with a short threshold
-        let deleted =
-            cleanup_inactive_rooms(&repo, &game_service, &event_bus, Duration::from_millis(1))
-                .await
-                .unwrap();
+        let deleted = cleanup_inactive_rooms(
+            &repo,
+            &setups,
+            &game_service,
+            &event_bus,
+            Duration::from_millis(1),
+        )
+        .await
+        .unwrap();
 
         assert_eq!(deleted, 3);
     }
@@ -233,13 +270,20 @@ mod tests {
             InMemoryPlayerMappingService::new(),
         )));
         let event_bus = Arc::new(EventBus::new());
+        let setups: Arc<dyn RoomSetupRepository + Send + Sync> =
+            Arc::new(InMemoryRoomSetupRepository::new());
 
         // No rooms created
 
-        let deleted =
-            cleanup_inactive_rooms(&repo, &game_service, &event_bus, Duration::from_millis(1))
-                .await
-                .unwrap();
+        let deleted = cleanup_inactive_rooms(
+            &repo,
+            &setups,
+            &game_service,
+            &event_bus,
+            Duration::from_millis(1),
+        )
+        .await
+        .unwrap();
 
         assert_eq!(deleted, 0);
     }
@@ -251,9 +295,18 @@ mod tests {
             InMemoryPlayerMappingService::new(),
         )));
         let event_bus = Arc::new(EventBus::new());
+        let setups: Arc<dyn RoomSetupRepository + Send + Sync> =
+            Arc::new(InMemoryRoomSetupRepository::new());
 
         // Try to delete a room that doesn't exist
-        let result = delete_room(&repo, &game_service, &event_bus, "nonexistent-room").await;
+        let result = delete_room(
+            &repo,
+            &setups,
+            &game_service,
+            &event_bus,
+            "nonexistent-room",
+        )
+        .await;
 
         assert!(result.is_err());
     }
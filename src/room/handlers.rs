@@ -5,11 +5,12 @@ use axum::{
 use std::sync::Arc;
 use tracing::{debug, info, instrument};
 
+use super::models::RoomModel;
 use super::types::{CreateRoomApiRequest, JoinRoomRequest, RoomCreateRequest, RoomResponse};
 use crate::{
     bot::BotRoomSubscriber,
     event::{RoomEvent, RoomSubscription},
-    game::GameEventRoomSubscriber,
+    game::{GameEventRoomSubscriber, TurnTimerRoomSubscriber},
     session::SessionClaims,
     shared::{AppError, AppState},
     stats::service::StatsRoomSubscriber,
@@ -25,7 +26,7 @@ use crate::{
 pub async fn create_room(
     State(state): State<AppState>,
     Extension(claims): Extension<SessionClaims>,
-    Json(_request): Json<CreateRoomApiRequest>,
+    Json(request): Json<CreateRoomApiRequest>,
 ) -> Result<Json<RoomResponse>, AppError> {
     // Get host UUID from authenticated session instead of trusting client
     let host_uuid = state
@@ -37,12 +38,43 @@ pub async fn create_room(
     info!(host_uuid = %host_uuid, "Creating new room");
 
     // Create request using authenticated session's UUID
+    let settings = request.settings;
     let request = RoomCreateRequest { host_uuid };
 
     // Create room using business-logic-only service
     let service = Arc::clone(&state.room_service);
-    let room_model = service.create_room(request).await?;
+    let room_model = service.create_room_with_settings(request, settings).await?;
 
+    // Set up the room's event subscriptions at the composition edge
+    start_room_subscriptions(&state, &room_model).await;
+
+    // Map host UUID to display name for response
+    let host_uuid = room_model.host_uuid.clone().unwrap_or_default();
+    let host_name = state
+        .player_mapping
+        .get_playername(&host_uuid)
+        .await
+        .unwrap_or(host_uuid);
+    let room = RoomResponse {
+        id: room_model.id.clone(),
+        host_name,
+        status: room_model.status.clone(),
+        player_count: room_model.get_player_count(),
+        settings: room_model.settings.clone(),
+    };
+
+    info!(
+        room_id = %room.id,
+        host_name = %room.host_name,
+        "Room created successfully with WebSocket subscription active"
+    );
+
+    Ok(Json(room))
+}
+
+/// Starts every subscriber a room needs: WebSocket output, game logic, turn clock, bots, stats
+/// and activity tracking
+pub(crate) async fn start_room_subscriptions(state: &AppState, room_model: &RoomModel) {
     // Set up WebSocket subscription for this room at the composition edge
     let subscriber_room_service = Arc::clone(&state.room_service);
     let room_subscriber = Arc::new(WebSocketRoomSubscriber::new(
@@ -52,6 +84,7 @@ pub async fn create_room(
         Arc::clone(&state.player_mapping),
         state.event_bus.clone(),
         Arc::clone(&state.bot_manager),
+        Arc::clone(&state.stats_service),
     ));
 
     let room_subscription = RoomSubscription::new(
@@ -76,6 +109,23 @@ pub async fn create_room(
     // Start background task - we don't need to track the JoinHandle
     drop(game_subscription.start().await);
 
+    // Set up the turn clock for rooms that have one
+    if let Some(turn_time_limit_secs) = room_model.settings.turn_time_limit_secs {
+        let timer_subscriber = Arc::new(TurnTimerRoomSubscriber::new(
+            Arc::clone(&state.game_service),
+            state.event_bus.clone(),
+            turn_time_limit_secs,
+        ));
+
+        let timer_subscription = RoomSubscription::new(
+            room_model.id.clone(),
+            timer_subscriber,
+            state.event_bus.clone(),
+        );
+        // Start background task - we don't need to track the JoinHandle
+        drop(timer_subscription.start().await);
+    }
+
     // Set up bot subscription for this room
     let bot_subscriber = Arc::new(BotRoomSubscriber::new(
         Arc::clone(&state.bot_manager),
@@ -116,28 +166,6 @@ pub async fn create_room(
     );
     // Start background task - we don't need to track the JoinHandle
     drop(activity_subscription.start().await);
-
-    // Map host UUID to display name for response
-    let host_uuid = room_model.host_uuid.clone().unwrap_or_default();
-    let host_name = state
-        .player_mapping
-        .get_playername(&host_uuid)
-        .await
-        .unwrap_or(host_uuid);
-    let room = RoomResponse {
-        id: room_model.id.clone(),
-        host_name,
-        status: room_model.status.clone(),
-        player_count: room_model.get_player_count(),
-    };
-
-    info!(
-        room_id = %room.id,
-        host_name = %room.host_name,
-        "Room created successfully with WebSocket subscription active"
-    );
-
-    Ok(Json(room))
 }
 
 /// HTTP handler for listing all rooms
@@ -167,6 +195,7 @@ pub async fn list_rooms(
             host_name,
             status: m.status,
             player_count,
+            settings: m.settings,
         });
     }
 
@@ -234,6 +263,7 @@ pub async fn join_room(
         host_name,
         status: room_model.status.clone(),
         player_count: room_model.get_player_count(),
+        settings: room_model.settings.clone(),
     };
 
     // Emit room-specific event directly to room subscribers
@@ -276,6 +306,7 @@ pub async fn get_room_details(
         host_name,
         status: room_model.status.clone(),
         player_count: room_model.get_player_count(),
+        settings: room_model.settings.clone(),
     };
 
     Ok(Json(room))
@@ -350,6 +381,7 @@ mod tests {
             host_name,
             status,
             player_count,
+            settings: model.settings.clone(),
         };
 
         // Verify room response
@@ -8,5 +8,7 @@ pub mod cleanup_task;
 mod handlers;
 pub mod models;
 pub mod repository;
+pub mod resume;
 pub mod service;
+pub mod setup_repository;
 mod types;
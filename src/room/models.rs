@@ -2,6 +2,32 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 
+use crate::bot::types::BotPlayer;
+pub use crate::game::TakeBackRule;
+use crate::game::{Game, HouseRules, RuleSet};
+
+/// Options the host picks when creating a room
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RoomSettings {
+    pub take_back: TakeBackRule,
+    /// Seconds each player has to act before they pass (or play their lowest single);
+    /// None for no turn clock
+    pub turn_time_limit_secs: Option<u32>,
+    /// Card ordering rules every game in the room is played under
+    pub rules: RuleSet,
+    /// Optional turn rules every game in the room is played under
+    pub house_rules: HouseRules,
+}
+
+/// What a room needs besides its game to pick up after a restart
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RoomSetup {
+    pub settings: RoomSettings,
+    /// Bots seated in the room, with their difficulty and profile
+    pub bots: Vec<BotPlayer>,
+}
+
 /// Database model for rooms table
 #[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
 pub struct RoomModel {
@@ -13,6 +39,9 @@ pub struct RoomModel {
     pub connected_players: Vec<String>,  // Players currently connected via WebSocket
     pub created_at: DateTime<Utc>,       // When the room was created
     pub last_activity_at: DateTime<Utc>, // Last time there was activity in the room
+    #[sqlx(skip)]
+    #[serde(default)]
+    pub settings: RoomSettings, // Host-chosen options
 }
 
 impl RoomModel {
@@ -30,9 +59,45 @@ impl RoomModel {
             connected_players: vec![],
             created_at: now,
             last_activity_at: now,
+            settings: RoomSettings::default(),
+        }
+    }
+
+    /// Rebuilds the room a stored game was being played in, e.g. after a restart
+    ///
+    /// The players are seated as in the game with the first human as host, and nobody is
+    /// connected until they rejoin. The turn clock is not part of the game, so it is off.
+    pub fn from_game(room_id: &str, game: &Game) -> Self {
+        let now = Utc::now();
+        let player_uuids: Vec<String> = game.players().iter().map(|p| p.uuid.clone()).collect();
+
+        Self {
+            id: room_id.to_string(),
+            host_uuid: player_uuids
+                .iter()
+                .find(|uuid| !BotPlayer::is_bot_uuid(uuid))
+                .cloned(),
+            status: "ONLINE".to_string(),
+            player_uuids,
+            ready_players: vec![],
+            connected_players: vec![],
+            created_at: now,
+            last_activity_at: now,
+            settings: RoomSettings {
+                take_back: game.take_back_rule(),
+                turn_time_limit_secs: None,
+                rules: *game.rules(),
+                house_rules: *game.house_rules(),
+            },
         }
     }
 
+    /// Use the given settings instead of the defaults
+    pub fn with_settings(mut self, settings: RoomSettings) -> Self {
+        self.settings = settings;
+        self
+    }
+
     /// Get the current number of players
     pub fn get_player_count(&self) -> i32 {
         self.player_uuids.len() as i32
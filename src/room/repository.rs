@@ -492,6 +492,7 @@ mod tests {
             connected_players: vec![host_uuid.to_string()],
             created_at: now,
             last_activity_at: now,
+            settings: Default::default(),
         }
     }
 
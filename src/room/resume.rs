@@ -0,0 +1,210 @@
+use tracing::{debug, info, instrument};
+
+use super::{handlers::start_room_subscriptions, models::RoomModel};
+use crate::bot::profile::BotProfile;
+use crate::bot::types::{BotDifficulty, BotPlayer};
+use crate::event::RoomEvent;
+use crate::shared::{AppError, AppState};
+
+/// Rebuilds the room around every unfinished stored game that has none, so games survive a restart
+///
+/// Rooms only live in memory, but their games may be stored in PostgreSQL. For each such game
+/// the room is recreated with its players and stored settings, bots are brought back with their
+/// difficulty and profile, the room's subscribers are started and the player to act is announced
+/// again so a bot on turn moves. Humans pick their seat back up by rejoining the room.
+#[instrument(skip(state))]
+pub async fn resume_rooms(state: &AppState) -> Result<usize, AppError> {
+    let mut resumed = 0;
+
+    for (room_id, game) in state.game_service.list_games().await? {
+        // A finished game has nothing left to play; drop it rather than reopen its room
+        if game.is_over() {
+            debug!(room_id = %room_id, "Stored game is already over, removing it");
+            state.game_service.remove_game(&room_id).await;
+            continue;
+        }
+        if state.room_service.get_room(&room_id).await?.is_some() {
+            debug!(room_id = %room_id, "Room already exists, nothing to resume");
+            continue;
+        }
+
+        // Games stored without a setup fall back to the game's own rules and Medium bots
+        let setup = state.room_service.get_room_setup(&room_id).await?;
+        let mut room = RoomModel::from_game(&room_id, &game);
+        if let Some(setup) = &setup {
+            room = room.with_settings(setup.settings.clone());
+        }
+        state.room_service.restore_room(&room).await?;
+
+        for player in game.players() {
+            if BotPlayer::is_bot_uuid(&player.uuid) {
+                let bot = setup
+                    .iter()
+                    .flat_map(|setup| &setup.bots)
+                    .find(|bot| bot.uuid == player.uuid)
+                    .cloned()
+                    .unwrap_or_else(|| BotPlayer {
+                        uuid: player.uuid.clone(),
+                        name: player.name.clone(),
+                        room_id: room_id.clone(),
+                        difficulty: BotDifficulty::Medium,
+                        time_budget_ms: None,
+                        profile: BotProfile::default(),
+                    });
+                state.bot_manager.restore_bot(bot).await;
+            }
+            if let Err(e) = state
+                .player_mapping
+                .register_player(player.uuid.clone(), player.name.clone())
+                .await
+            {
+                // Log but don't fail - player may already be registered
+                debug!(player_uuid = %player.uuid, error = %e, "Player mapping registration failed");
+            }
+        }
+
+        start_room_subscriptions(state, &room).await;
+        state
+            .event_bus
+            .emit_to_room(
+                &room_id,
+                RoomEvent::TurnChanged {
+                    player: game.current_player_turn(),
+                },
+            )
+            .await;
+
+        info!(room_id = %room_id, "Resumed room from stored game");
+        resumed += 1;
+    }
+
+    Ok(resumed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bot::profile::BotPersonality;
+    use crate::game::GameService;
+    use crate::room::models::{RoomSettings, RoomSetup, TakeBackRule};
+    use crate::room::{repository::InMemoryRoomRepository, service::RoomService};
+    use crate::shared::test_utils::cards;
+    use crate::shared::test_utils::AppStateBuilder;
+    use crate::user::mapping_service::InMemoryPlayerMappingService;
+    use std::sync::Arc;
+
+    const ALICE: &str = "550e8400-e29b-41d4-a716-446655440000";
+    const BOT: &str = "bot-550e8400-e29b-41d4-a716-446655440001";
+
+    #[tokio::test]
+    async fn test_resume_rebuilds_rooms_around_stored_games() {
+        let player_mapping = Arc::new(InMemoryPlayerMappingService::new());
+        let game_service = Arc::new(GameService::new(player_mapping.clone()));
+        game_service
+            .create_game_with_cards(
+                "lucky-otter",
+                vec![
+                    ("Sly Bot".to_string(), BOT.to_string(), cards("5D 6D")),
+                    ("Alice".to_string(), ALICE.to_string(), cards("3D 4D")),
+                ],
+            )
+            .await
+            .unwrap();
+
+        let settings = RoomSettings {
+            take_back: TakeBackRule::UntilNextMove,
+            turn_time_limit_secs: Some(30),
+            ..RoomSettings::default()
+        };
+        let bot = BotPlayer {
+            uuid: BOT.to_string(),
+            name: "Sly Bot".to_string(),
+            room_id: "lucky-otter".to_string(),
+            difficulty: BotDifficulty::Hard,
+            time_budget_ms: None,
+            profile: BotPersonality::Cautious.profile(),
+        };
+        let room_service = RoomService::new(Arc::new(InMemoryRoomRepository::new()));
+        room_service
+            .save_room_setup(
+                "lucky-otter",
+                &RoomSetup {
+                    settings: settings.clone(),
+                    bots: vec![bot],
+                },
+            )
+            .await
+            .unwrap();
+
+        let state = AppStateBuilder::new()
+            .with_room_service(Arc::new(room_service))
+            .with_game_service(game_service)
+            .with_player_mapping(player_mapping)
+            .build_with_test_defaults();
+
+        assert_eq!(resume_rooms(&state).await.unwrap(), 1);
+
+        let room = state
+            .room_service
+            .get_room("lucky-otter")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(room.get_player_count(), 2);
+        assert!(room.has_player(ALICE) && room.has_player(BOT));
+        assert_eq!(room.host_uuid.as_deref(), Some(ALICE));
+        assert!(room.connected_players.is_empty());
+        assert_eq!(room.settings, settings);
+
+        let bot = state.bot_manager.get_bot(BOT).await.unwrap();
+        assert_eq!(bot.room_id, "lucky-otter");
+        assert_eq!(bot.difficulty, BotDifficulty::Hard);
+        assert_eq!(bot.profile, BotPersonality::Cautious.profile());
+        assert_eq!(
+            state.player_mapping.get_playername(ALICE).await.as_deref(),
+            Some("Alice")
+        );
+
+        // Rooms that already exist are left alone
+        assert_eq!(resume_rooms(&state).await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_resume_drops_finished_games() {
+        let player_mapping = Arc::new(InMemoryPlayerMappingService::new());
+        let game_service = Arc::new(GameService::new(player_mapping.clone()));
+        game_service
+            .create_game_with_cards(
+                "lucky-otter",
+                vec![
+                    ("Alice".to_string(), ALICE.to_string(), cards("3D")),
+                    ("Sly Bot".to_string(), BOT.to_string(), cards("5D 6D")),
+                ],
+            )
+            .await
+            .unwrap();
+        game_service
+            .try_play_move("lucky-otter", ALICE, &cards("3D"))
+            .await
+            .unwrap();
+
+        let state = AppStateBuilder::new()
+            .with_game_service(game_service)
+            .with_player_mapping(player_mapping)
+            .build_with_test_defaults();
+
+        assert_eq!(resume_rooms(&state).await.unwrap(), 0);
+        assert!(state
+            .room_service
+            .get_room("lucky-otter")
+            .await
+            .unwrap()
+            .is_none());
+        assert!(state
+            .game_service
+            .get_game("lucky-otter")
+            .await
+            .unwrap()
+            .is_none());
+    }
+}
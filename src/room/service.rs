@@ -2,8 +2,9 @@ use std::sync::Arc;
 use tracing::{debug, info, instrument};
 
 use super::{
-    models::RoomModel,
+    models::{RoomModel, RoomSettings, RoomSetup},
     repository::{JoinRoomResult, LeaveRoomResult, RoomRepository},
+    setup_repository::{InMemoryRoomSetupRepository, RoomSetupRepository},
     types::RoomCreateRequest,
 };
 use crate::shared::AppError;
@@ -11,18 +12,54 @@ use crate::shared::AppError;
 /// Service for handling room business logic
 pub struct RoomService {
     repository: Arc<dyn RoomRepository + Send + Sync>,
+    setup_repository: Arc<dyn RoomSetupRepository + Send + Sync>,
 }
 
 impl RoomService {
     pub fn new(repository: Arc<dyn RoomRepository + Send + Sync>) -> Self {
-        Self { repository }
+        Self {
+            repository,
+            setup_repository: Arc::new(InMemoryRoomSetupRepository::new()),
+        }
+    }
+
+    /// Keep room setups in a specific repository (e.g. PostgreSQL); they stay in memory otherwise
+    pub fn with_setup_repository(
+        mut self,
+        setup_repository: Arc<dyn RoomSetupRepository + Send + Sync>,
+    ) -> Self {
+        self.setup_repository = setup_repository;
+        self
     }
 
     /// Creates a new room with a generated ID
     #[instrument(skip(self))]
     pub async fn create_room(&self, request: RoomCreateRequest) -> Result<RoomModel, AppError> {
+        self.create_room_with_settings(request, RoomSettings::default())
+            .await
+    }
+
+    /// Creates a new room with host-chosen settings
+    #[instrument(skip(self))]
+    pub async fn create_room_with_settings(
+        &self,
+        request: RoomCreateRequest,
+        settings: RoomSettings,
+    ) -> Result<RoomModel, AppError> {
+        if !settings.rules.has_valid_suit_order() {
+            return Err(AppError::BadRequest(
+                "suit_order must list each suit exactly once".to_string(),
+            ));
+        }
+        if settings.turn_time_limit_secs == Some(0) {
+            return Err(AppError::BadRequest(
+                "turn_time_limit_secs must be at least 1; leave it out for no turn clock"
+                    .to_string(),
+            ));
+        }
+
         // Create room model with generated ID
-        let room_model = RoomModel::new(request.host_uuid);
+        let room_model = RoomModel::new(request.host_uuid).with_settings(settings);
         debug!(room_id = %room_model.id, "Generated room ID");
 
         // Store room in repository
@@ -33,6 +70,28 @@ impl RoomService {
         Ok(room_model)
     }
 
+    /// Stores a room that was rebuilt rather than created, e.g. from a game after a restart
+    #[instrument(skip(self, room))]
+    pub async fn restore_room(&self, room: &RoomModel) -> Result<(), AppError> {
+        self.repository.create_room(room).await?;
+
+        info!(room_id = %room.id, "Room restored successfully");
+
+        Ok(())
+    }
+
+    /// Stores the settings and bots a room's games are played with, for resuming after a restart
+    #[instrument(skip(self, setup))]
+    pub async fn save_room_setup(&self, room_id: &str, setup: &RoomSetup) -> Result<(), AppError> {
+        self.setup_repository.save_setup(room_id, setup).await
+    }
+
+    /// Gets the settings and bots stored for a room, if any
+    #[instrument(skip(self))]
+    pub async fn get_room_setup(&self, room_id: &str) -> Result<Option<RoomSetup>, AppError> {
+        self.setup_repository.get_setup(room_id).await
+    }
+
     /// Gets room details as a response object for API endpoints
     #[instrument(skip(self))]
     pub async fn get_room_details(&self, room_id: String) -> Result<RoomModel, AppError> {
@@ -271,6 +330,71 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_create_room_with_settings() {
+        use crate::room::models::{RoomSettings, TakeBackRule};
+        let repo = Arc::new(InMemoryRoomRepository::new());
+        let service = RoomService::new(repo.clone());
+
+        let settings = RoomSettings {
+            take_back: TakeBackRule::UnanimousConsent,
+            turn_time_limit_secs: Some(30),
+            rules: crate::game::RuleSet::pusoy_dos(),
+            house_rules: crate::game::HouseRules {
+                pass_lockout: true,
+                ..Default::default()
+            },
+        };
+        let request = RoomCreateRequest {
+            host_uuid: "550e8400-e29b-41d4-a716-446655440000".to_string(),
+        };
+        let created = service
+            .create_room_with_settings(request, settings.clone())
+            .await
+            .unwrap();
+
+        let stored = repo.get_room(&created.id).await.unwrap().unwrap();
+        assert_eq!(stored.settings, settings);
+    }
+
+    #[tokio::test]
+    async fn test_create_room_rejects_suit_order_with_repeated_suits() {
+        use crate::game::{RuleSet, Suit};
+        use crate::room::models::RoomSettings;
+        let service = RoomService::new(Arc::new(InMemoryRoomRepository::new()));
+
+        let settings = RoomSettings {
+            rules: RuleSet {
+                suit_order: [Suit::Spades; 4],
+                ..RuleSet::standard()
+            },
+            ..RoomSettings::default()
+        };
+        let request = RoomCreateRequest {
+            host_uuid: "550e8400-e29b-41d4-a716-446655440000".to_string(),
+        };
+        let result = service.create_room_with_settings(request, settings).await;
+
+        assert!(matches!(result, Err(AppError::BadRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn test_create_room_rejects_a_zero_turn_time_limit() {
+        use crate::room::models::RoomSettings;
+        let service = RoomService::new(Arc::new(InMemoryRoomRepository::new()));
+
+        let settings = RoomSettings {
+            turn_time_limit_secs: Some(0),
+            ..RoomSettings::default()
+        };
+        let request = RoomCreateRequest {
+            host_uuid: "550e8400-e29b-41d4-a716-446655440000".to_string(),
+        };
+        let result = service.create_room_with_settings(request, settings).await;
+
+        assert!(matches!(result, Err(AppError::BadRequest(_))));
+    }
+
     #[tokio::test]
     async fn test_create_room_generates_unique_ids() {
         let repo = Arc::new(InMemoryRoomRepository::new());
@@ -0,0 +1,184 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sqlx::{PgPool, Row};
+use std::collections::HashMap;
+use tokio::sync::RwLock;
+use tracing::{debug, instrument, warn};
+
+use super::models::RoomSetup;
+use crate::shared::AppError;
+
+/// Trait for room setup repository operations, keyed by room ID
+///
+/// Rooms themselves only live in memory; their setups are kept so a room can be rebuilt
+/// around its stored game after a restart.
+#[async_trait]
+pub trait RoomSetupRepository {
+    /// Store the settings and bots a room's games are played with, replacing any previous ones
+    async fn save_setup(&self, room_id: &str, setup: &RoomSetup) -> Result<(), AppError>;
+    async fn get_setup(&self, room_id: &str) -> Result<Option<RoomSetup>, AppError>;
+    async fn remove_setup(&self, room_id: &str) -> Result<(), AppError>;
+}
+
+/// In-memory implementation of RoomSetupRepository; setups are lost when the server restarts
+pub struct InMemoryRoomSetupRepository {
+    /// A mapping from room ID to the room's settings and bots
+    setups: RwLock<HashMap<String, RoomSetup>>,
+}
+
+impl Default for InMemoryRoomSetupRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InMemoryRoomSetupRepository {
+    pub fn new() -> Self {
+        Self {
+            setups: RwLock::new(HashMap::new()),
+        }
+    }
+}
+
+#[async_trait]
+impl RoomSetupRepository for InMemoryRoomSetupRepository {
+    async fn save_setup(&self, room_id: &str, setup: &RoomSetup) -> Result<(), AppError> {
+        let mut setups = self.setups.write().await;
+        setups.insert(room_id.to_string(), setup.clone());
+        Ok(())
+    }
+
+    async fn get_setup(&self, room_id: &str) -> Result<Option<RoomSetup>, AppError> {
+        let setups = self.setups.read().await;
+        Ok(setups.get(room_id).cloned())
+    }
+
+    async fn remove_setup(&self, room_id: &str) -> Result<(), AppError> {
+        let mut setups = self.setups.write().await;
+        setups.remove(room_id);
+        Ok(())
+    }
+}
+
+/// PostgreSQL implementation of room setup repository, storing each setup as its serialized JSON
+pub struct PostgresRoomSetupRepository {
+    pool: PgPool,
+}
+
+impl PostgresRoomSetupRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn decode(room_id: &str, state: &str) -> Result<RoomSetup, AppError> {
+        serde_json::from_str(state).map_err(|e| {
+            warn!(error = %e, room_id = %room_id, "Stored room setup could not be deserialized");
+            AppError::DatabaseError(e.to_string())
+        })
+    }
+}
+
+#[async_trait]
+impl RoomSetupRepository for PostgresRoomSetupRepository {
+    #[instrument(skip(self, setup))]
+    async fn save_setup(&self, room_id: &str, setup: &RoomSetup) -> Result<(), AppError> {
+        debug!(room_id = %room_id, "Saving room setup to database");
+
+        let state = serde_json::to_string(setup).map_err(|e| {
+            warn!(error = %e, room_id = %room_id, "Failed to serialize room setup");
+            AppError::Internal
+        })?;
+
+        sqlx::query(
+            "INSERT INTO room_setups (room_id, state, updated_at) VALUES ($1, $2, $3)
+             ON CONFLICT (room_id) DO UPDATE SET state = EXCLUDED.state, updated_at = EXCLUDED.updated_at",
+        )
+        .bind(room_id)
+        .bind(state)
+        .bind(Utc::now())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            warn!(error = %e, room_id = %room_id, "Failed to save room setup to database");
+            AppError::DatabaseError(e.to_string())
+        })?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn get_setup(&self, room_id: &str) -> Result<Option<RoomSetup>, AppError> {
+        debug!(room_id = %room_id, "Fetching room setup from database");
+
+        let row = sqlx::query("SELECT state FROM room_setups WHERE room_id = $1")
+            .bind(room_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| {
+                warn!(error = %e, room_id = %room_id, "Failed to fetch room setup from database");
+                AppError::DatabaseError(e.to_string())
+            })?;
+
+        row.map(|row| Self::decode(room_id, row.get("state")))
+            .transpose()
+    }
+
+    #[instrument(skip(self))]
+    async fn remove_setup(&self, room_id: &str) -> Result<(), AppError> {
+        debug!(room_id = %room_id, "Deleting room setup from database");
+
+        sqlx::query("DELETE FROM room_setups WHERE room_id = $1")
+            .bind(room_id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| {
+                warn!(error = %e, room_id = %room_id, "Failed to delete room setup from database");
+                AppError::DatabaseError(e.to_string())
+            })?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::room::models::{RoomSettings, TakeBackRule};
+
+    #[tokio::test]
+    async fn test_in_memory_save_get_remove() {
+        let repo = InMemoryRoomSetupRepository::new();
+        assert!(repo.get_setup("room").await.unwrap().is_none());
+
+        let setup = RoomSetup {
+            settings: RoomSettings {
+                take_back: TakeBackRule::UntilNextMove,
+                ..RoomSettings::default()
+            },
+            bots: Vec::new(),
+        };
+        repo.save_setup("room", &setup).await.unwrap();
+        let stored = repo.get_setup("room").await.unwrap().unwrap();
+        assert_eq!(stored.settings, setup.settings);
+
+        repo.remove_setup("room").await.unwrap();
+        assert!(repo.get_setup("room").await.unwrap().is_none());
+    }
+
+    #[test]
+    fn test_stored_setup_round_trips() {
+        let setup = RoomSetup {
+            settings: RoomSettings {
+                turn_time_limit_secs: Some(30),
+                ..RoomSettings::default()
+            },
+            bots: Vec::new(),
+        };
+
+        let state = serde_json::to_string(&setup).unwrap();
+        let restored = PostgresRoomSetupRepository::decode("room", &state).unwrap();
+        assert_eq!(restored.settings, setup.settings);
+
+        assert!(PostgresRoomSetupRepository::decode("room", "not json").is_err());
+    }
+}
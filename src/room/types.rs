@@ -1,9 +1,14 @@
 use serde::{Deserialize, Serialize};
 
-/// External request payload for creating a new room (no data needed)
+use super::models::RoomSettings;
+
+/// External request payload for creating a new room
+///
+/// The host comes from the authenticated session; only optional settings are sent.
 #[derive(Debug, Deserialize)]
 pub struct CreateRoomApiRequest {
-    // Currently empty - room creation is based on authenticated session
+    #[serde(default)]
+    pub settings: RoomSettings,
 }
 
 /// Internal request payload for creating a new room
@@ -28,4 +33,5 @@ pub struct RoomResponse {
     pub host_name: String,
     pub status: String,
     pub player_count: i32,
+    pub settings: RoomSettings,
 }
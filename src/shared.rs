@@ -15,7 +15,11 @@ use crate::session::repository::SessionRepository;
 use crate::session::service::SessionService;
 use crate::stats::{service::StatsService, InMemoryStatsRepository, StatsRepository};
 use crate::websockets::ConnectionManager;
-use crate::{event::EventBus, game::GameService, user::PlayerMappingService};
+use crate::{
+    event::EventBus,
+    game::{GameError, GameService},
+    user::PlayerMappingService,
+};
 
 /// Shared application state containing all dependencies
 #[derive(Clone)]
@@ -333,6 +337,9 @@ pub enum AppError {
     #[error("Forbidden: {0}")]
     Forbidden(String),
 
+    #[error("Game error: {0}")]
+    GameError(#[from] GameError),
+
     #[error("Internal server error")]
     Internal,
 }
@@ -349,6 +356,7 @@ impl IntoResponse for AppError {
             AppError::BadRequest(msg) => (StatusCode::BAD_REQUEST, msg),
             AppError::NotFound(msg) => (StatusCode::NOT_FOUND, msg),
             AppError::Forbidden(msg) => (StatusCode::FORBIDDEN, msg),
+            AppError::GameError(e) => (StatusCode::BAD_REQUEST, e.to_string()),
             AppError::Internal => (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 "Internal server error".to_string(),
@@ -366,6 +374,7 @@ impl IntoResponse for AppError {
 #[cfg(test)]
 pub mod test_utils {
     use super::*;
+    use crate::game::{Card, Game};
     use crate::room::models::RoomModel;
     use crate::room::repository::RoomRepository;
     use crate::session::models::SessionModel;
@@ -423,6 +432,7 @@ pub mod test_utils {
                     connected_players: vec!["dummy-host-uuid".to_string()],
                     created_at: now,
                     last_activity_at: now,
+                    settings: Default::default(),
                 },
             ))
         }
@@ -576,4 +586,24 @@ pub mod test_utils {
             }
         }
     }
+
+    /// Cards from space-separated codes, e.g. "3D 9C 2S"
+    pub fn cards(codes: &str) -> Vec<Card> {
+        codes
+            .split_whitespace()
+            .map(|c| Card::from_string(c).unwrap())
+            .collect()
+    }
+
+    /// A game dealt the given (uuid, cards) hands, each player named after their UUID
+    pub fn game(hands: &[(&str, &str)]) -> Game {
+        Game::new_game_with_cards(
+            "test".to_string(),
+            hands
+                .iter()
+                .map(|(uuid, codes)| (uuid.to_string(), uuid.to_string(), cards(codes)))
+                .collect(),
+        )
+        .unwrap()
+    }
 }
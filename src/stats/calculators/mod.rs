@@ -1,5 +1,7 @@
 pub mod card_count;
+pub mod placement;
 pub mod ten_plus_multiplier;
 
 pub use card_count::*;
+pub use placement::*;
 pub use ten_plus_multiplier::*;
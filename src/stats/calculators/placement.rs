@@ -0,0 +1,120 @@
+use super::super::{CalculationContext, CollectedData, ScoreCalculator};
+
+/// Penalty added for each place a player finished behind the winner
+pub const DEFAULT_PENALTY_PER_PLACE: i32 = 5;
+
+/// Adds a penalty by finishing place; players without a recorded place are left unchanged
+pub struct PlacementScoreCalculator {
+    penalty_per_place: i32,
+}
+
+impl Default for PlacementScoreCalculator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PlacementScoreCalculator {
+    pub fn new() -> Self {
+        Self::with_penalty_per_place(DEFAULT_PENALTY_PER_PLACE)
+    }
+
+    pub fn with_penalty_per_place(penalty_per_place: i32) -> Self {
+        Self { penalty_per_place }
+    }
+}
+
+impl ScoreCalculator for PlacementScoreCalculator {
+    fn calculate(
+        &self,
+        player_uuid: &str,
+        collected_data: &[CollectedData],
+        context: &CalculationContext,
+    ) -> i32 {
+        let base_score = context
+            .current_scores
+            .get(player_uuid)
+            .copied()
+            .unwrap_or_default();
+
+        let place = collected_data.iter().find_map(|data| match data {
+            CollectedData::Placement {
+                player_uuid: uuid,
+                place,
+            } if uuid == player_uuid => Some(*place as i32),
+            _ => None,
+        });
+
+        match place {
+            Some(place) => base_score + (place - 1) * self.penalty_per_place,
+            None => base_score,
+        }
+    }
+
+    fn priority(&self) -> u32 {
+        crate::stats::calculator_priority::PLACEMENT
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stats::GameResult;
+    use chrono::Utc;
+    use std::collections::HashMap;
+
+    fn build_context<'a>(
+        game_result: &'a GameResult,
+        scores: &'a HashMap<String, i32>,
+    ) -> CalculationContext<'a> {
+        CalculationContext {
+            game_result,
+            current_scores: scores,
+        }
+    }
+
+    fn sample_game() -> GameResult {
+        GameResult {
+            room_id: "room".into(),
+            game_number: 1,
+            winner_uuid: "winner".into(),
+            players: vec![],
+            completed_at: Utc::now(),
+            had_bots: false,
+            deal_seed: None,
+        }
+    }
+
+    #[test]
+    fn adds_penalty_per_place_behind_the_winner() {
+        let calculator = PlacementScoreCalculator::with_penalty_per_place(4);
+        let data = vec![
+            CollectedData::Placement {
+                player_uuid: "winner".into(),
+                place: 1,
+            },
+            CollectedData::Placement {
+                player_uuid: "third".into(),
+                place: 3,
+            },
+        ];
+        let game = sample_game();
+        let mut scores = HashMap::new();
+        scores.insert("third".into(), 2);
+
+        let context = build_context(&game, &scores);
+        assert_eq!(calculator.calculate("winner", &data, &context), 0);
+        assert_eq!(calculator.calculate("third", &data, &context), 10);
+    }
+
+    #[test]
+    fn leaves_score_unchanged_without_a_place() {
+        let calculator = PlacementScoreCalculator::new();
+        let game = sample_game();
+        let mut scores = HashMap::new();
+        scores.insert("player".into(), 7);
+
+        let score = calculator.calculate("player", &[], &build_context(&game, &scores));
+        assert_eq!(score, 7);
+    }
+}
@@ -72,6 +72,7 @@ mod tests {
             players: vec![],
             completed_at: Utc::now(),
             had_bots: false,
+            deal_seed: None,
         }
     }
 
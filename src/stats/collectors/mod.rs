@@ -1,5 +1,7 @@
 pub mod cards_remaining;
+pub mod placement;
 pub mod win_loss;
 
 pub use cards_remaining::*;
+pub use placement::*;
 pub use win_loss::*;
@@ -0,0 +1,115 @@
+use async_trait::async_trait;
+
+use crate::game::Game;
+
+use super::super::{CollectedData, StatCollector, StatsError};
+
+/// Records each player's finishing place; only the winner is placed unless places were played out
+pub struct PlacementCollector;
+
+impl Default for PlacementCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PlacementCollector {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait]
+impl StatCollector for PlacementCollector {
+    async fn collect(
+        &self,
+        game: &Game,
+        _winner_uuid: &str,
+    ) -> Result<Vec<CollectedData>, StatsError> {
+        let data = game
+            .finishing_order()
+            .iter()
+            .enumerate()
+            .map(|(index, uuid)| CollectedData::Placement {
+                player_uuid: uuid.clone(),
+                place: index as u8 + 1,
+            })
+            .collect();
+
+        Ok(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::HouseRules;
+    use crate::shared::test_utils::cards;
+
+    fn places(data: &[CollectedData]) -> Vec<(String, u8)> {
+        data.iter()
+            .filter_map(|d| match d {
+                CollectedData::Placement { player_uuid, place } => {
+                    Some((player_uuid.clone(), *place))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn places_every_player_when_played_out() {
+        let mut game = Game::new_game_with_cards(
+            "room".to_string(),
+            vec![
+                ("Alice".to_string(), "alice".to_string(), cards("3D")),
+                ("Bob".to_string(), "bob".to_string(), cards("4D 9S")),
+                ("Carol".to_string(), "carol".to_string(), cards("5D 6D")),
+            ],
+        )
+        .unwrap()
+        .with_house_rules(HouseRules {
+            finishing_order: true,
+            ..HouseRules::default()
+        });
+
+        game.play_cards("alice", &cards("3D")).unwrap();
+        game.play_cards("bob", &cards("9S")).unwrap();
+        game.play_cards("carol", &[]).unwrap();
+        // Carol passed, so Bob leads again
+        game.play_cards("bob", &[]).unwrap_err();
+        assert!(game.play_cards("bob", &cards("4D")).unwrap());
+
+        let data = PlacementCollector::new()
+            .collect(&game, "alice")
+            .await
+            .unwrap();
+        assert_eq!(
+            places(&data),
+            vec![
+                ("alice".to_string(), 1),
+                ("bob".to_string(), 2),
+                ("carol".to_string(), 3),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn places_only_the_winner_by_default() {
+        let mut game = Game::new_game_with_cards(
+            "room".to_string(),
+            vec![
+                ("Alice".to_string(), "alice".to_string(), cards("3D")),
+                ("Bob".to_string(), "bob".to_string(), cards("4D")),
+            ],
+        )
+        .unwrap();
+        assert!(game.play_cards("alice", &cards("3D")).unwrap());
+
+        let data = PlacementCollector::new()
+            .collect(&game, "alice")
+            .await
+            .unwrap();
+        assert_eq!(places(&data), vec![("alice".to_string(), 1)]);
+    }
+}
@@ -5,6 +5,7 @@ pub mod service;
 mod errors;
 pub mod models;
 pub mod repository;
+pub mod scorer;
 
 pub use errors::StatsError;
 pub use models::*;
@@ -24,6 +25,8 @@ pub type CollectedDataBatch = Vec<CollectedData>;
 pub mod calculator_priority {
     /// Base score calculation (e.g., card count)
     pub const BASE_SCORE: u32 = 100;
+    /// Adjustments on top of the base score (e.g., finishing place)
+    pub const PLACEMENT: u32 = 150;
     /// Score multipliers (e.g., 10+ cards doubles score)
     pub const MULTIPLIER: u32 = 200;
 }
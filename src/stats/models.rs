@@ -13,6 +13,9 @@ pub struct GameResult {
     pub completed_at: DateTime<Utc>,
     #[allow(dead_code)] // Metadata for filtering bot games
     pub had_bots: bool,
+    /// Seed the deal was shuffled with, for reproducing the game
+    #[allow(dead_code)] // Metadata for reproducing deals
+    pub deal_seed: Option<u64>,
 }
 
 #[derive(Debug, Clone)]
@@ -53,6 +56,11 @@ pub enum CollectedData {
         player_uuid: String,
         won: bool,
     },
+    /// Finishing place, starting at 1 for the winner
+    Placement {
+        player_uuid: String,
+        place: u8,
+    },
 }
 
 impl CollectedData {
@@ -61,6 +69,7 @@ impl CollectedData {
         match self {
             CollectedData::CardsRemaining { player_uuid, .. } => player_uuid,
             CollectedData::WinLoss { player_uuid, .. } => player_uuid,
+            CollectedData::Placement { player_uuid, .. } => player_uuid,
         }
     }
 }
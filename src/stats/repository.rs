@@ -106,6 +106,7 @@ mod tests {
                 .collect(),
             completed_at: Utc::now(),
             had_bots: false,
+            deal_seed: None,
         }
     }
 
@@ -0,0 +1,189 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tracing::warn;
+
+use crate::bot::types::BotPlayer;
+use crate::game::{Game, MatchScorer};
+use crate::shared::AppError;
+
+use super::{
+    calculators::{
+        CardCountScoreCalculator, PlacementScoreCalculator, TenPlusMultiplierCalculator,
+    },
+    collectors::{CardsRemainingCollector, PlacementCollector, WinLossCollector},
+    CalculationContext, CollectedData, GameResult, PlayerGameResult, ScoreCalculator,
+    StatCollector, StatsError,
+};
+
+/// Scores a finished game by running its collectors and then its calculators in priority order
+///
+/// StatsService records games with it, and matches add up the same scores.
+pub struct GameScorer {
+    pub(super) collectors: Vec<Arc<dyn StatCollector>>,
+    pub(super) calculators: Vec<Arc<dyn ScoreCalculator>>,
+}
+
+impl Default for GameScorer {
+    fn default() -> Self {
+        Self::new(
+            vec![
+                Arc::new(CardsRemainingCollector::new()),
+                Arc::new(WinLossCollector::new()),
+                Arc::new(PlacementCollector::new()),
+            ],
+            vec![
+                Arc::new(CardCountScoreCalculator::new()),
+                Arc::new(PlacementScoreCalculator::new()),
+                Arc::new(TenPlusMultiplierCalculator::new()),
+            ],
+        )
+    }
+}
+
+/// Matches add up the same final scores the stats record
+#[async_trait]
+impl MatchScorer for GameScorer {
+    async fn score_game(
+        &self,
+        room_id: &str,
+        game: &Game,
+    ) -> Result<HashMap<String, i32>, AppError> {
+        let Some(winner_uuid) = game.winner() else {
+            return Err(AppError::BadRequest(format!(
+                "Game in room {} has no winner to score",
+                room_id
+            )));
+        };
+
+        let result = GameResult {
+            room_id: room_id.to_string(),
+            // Matches keep their own game count
+            game_number: 0,
+            winner_uuid,
+            players: Vec::new(),
+            completed_at: chrono::Utc::now(),
+            had_bots: game
+                .players()
+                .iter()
+                .any(|p| BotPlayer::is_bot_uuid(&p.uuid)),
+            deal_seed: game.deal_seed(),
+        };
+        let result = self.score(game, result).await.map_err(|e| {
+            warn!(room_id = %room_id, error = %e, "Failed to score game");
+            AppError::Internal
+        })?;
+
+        Ok(result
+            .players
+            .into_iter()
+            .map(|p| (p.uuid, p.final_score))
+            .collect())
+    }
+}
+
+impl GameScorer {
+    pub fn new(
+        collectors: Vec<Arc<dyn StatCollector>>,
+        mut calculators: Vec<Arc<dyn ScoreCalculator>>,
+    ) -> Self {
+        calculators.sort_by_key(|c| c.priority());
+        Self {
+            collectors,
+            calculators,
+        }
+    }
+
+    pub fn collectors(&self) -> Vec<Arc<dyn StatCollector>> {
+        self.collectors.clone()
+    }
+
+    /// Fill in every player's raw and final score for a finished game
+    ///
+    /// `result` carries the game's metadata; its players are replaced with the scored ones.
+    pub async fn score(
+        &self,
+        game: &Game,
+        mut result: GameResult,
+    ) -> Result<GameResult, StatsError> {
+        let collected = self.collect_all(game, &result.winner_uuid).await?;
+
+        let player_metadata: Vec<(String, usize)> = game
+            .players()
+            .iter()
+            .map(|p| (p.uuid.clone(), p.cards.len()))
+            .collect();
+
+        let (raw_scores, final_scores) =
+            self.calculate_scores(&player_metadata, &collected, &result);
+
+        result.players = player_metadata
+            .iter()
+            .map(|(uuid, cards)| PlayerGameResult {
+                uuid: uuid.clone(),
+                cards_remaining: *cards as u8,
+                raw_score: raw_scores.get(uuid).copied().unwrap_or_default(),
+                final_score: final_scores.get(uuid).copied().unwrap_or_default(),
+            })
+            .collect();
+
+        Ok(result)
+    }
+
+    /// Calculate scores for all players using registered calculators
+    fn calculate_scores(
+        &self,
+        player_metadata: &[(String, usize)],
+        collected: &[CollectedData],
+        result: &GameResult,
+    ) -> (HashMap<String, i32>, HashMap<String, i32>) {
+        let mut current_scores: HashMap<String, i32> = HashMap::new();
+        let mut raw_scores: HashMap<String, i32> = HashMap::new();
+
+        for (index, calculator) in self.calculators.iter().enumerate() {
+            let snapshot_players: Vec<PlayerGameResult> = player_metadata
+                .iter()
+                .map(|(uuid, cards)| PlayerGameResult {
+                    uuid: uuid.clone(),
+                    cards_remaining: *cards as u8,
+                    raw_score: current_scores.get(uuid).copied().unwrap_or_default(),
+                    final_score: current_scores.get(uuid).copied().unwrap_or_default(),
+                })
+                .collect();
+
+            let snapshot = GameResult {
+                players: snapshot_players,
+                ..result.clone()
+            };
+
+            let context = CalculationContext::new(&snapshot, &current_scores);
+
+            let mut next_scores = current_scores.clone();
+            for (uuid, _) in player_metadata {
+                let updated = calculator.calculate(uuid, collected, &context);
+                next_scores.insert(uuid.clone(), updated);
+            }
+
+            if index == 0 {
+                raw_scores = next_scores.clone();
+            }
+
+            current_scores = next_scores;
+        }
+
+        (raw_scores, current_scores)
+    }
+
+    async fn collect_all(
+        &self,
+        game: &Game,
+        winner_uuid: &str,
+    ) -> Result<Vec<CollectedData>, StatsError> {
+        let mut collected = Vec::new();
+        for collector in &self.collectors {
+            collected.extend(collector.collect(game, winner_uuid).await?);
+        }
+        Ok(collected)
+    }
+}
@@ -11,16 +11,12 @@ use crate::{
 };
 
 use super::{
-    calculators::{CardCountScoreCalculator, TenPlusMultiplierCalculator},
-    collectors::{CardsRemainingCollector, WinLossCollector},
-    repository::StatsRepository,
-    CalculationContext, CollectedData, GameResult, PlayerGameResult, RoomStats, ScoreCalculator,
+    repository::StatsRepository, scorer::GameScorer, GameResult, RoomStats, ScoreCalculator,
     StatCollector, StatsError,
 };
 
 pub struct StatsService {
-    collectors: Vec<Arc<dyn StatCollector>>,
-    calculators: Vec<Arc<dyn ScoreCalculator>>,
+    scorer: Arc<GameScorer>,
     repository: Arc<dyn StatsRepository>,
     bot_manager: Option<Arc<BotManager>>,
     room_mutexes: Arc<RwLock<HashMap<String, Arc<AsyncMutex<()>>>>>,
@@ -33,7 +29,12 @@ impl StatsService {
 
     #[allow(dead_code)] // Public API for inspecting configured collectors
     pub fn collectors(&self) -> Vec<Arc<dyn StatCollector>> {
-        self.collectors.clone()
+        self.scorer.collectors()
+    }
+
+    /// The scorer games are recorded with, so matches can add up the same scores
+    pub fn scorer(&self) -> Arc<GameScorer> {
+        self.scorer.clone()
     }
 
     pub async fn process_completed_game(
@@ -50,42 +51,16 @@ impl StatsService {
         let game_number = self.next_game_number(room_id).await?;
         let had_bots = self.room_contains_bots(room_id).await;
 
-        let collected = self.collect_all(game, winner_uuid).await?;
-
-        let player_metadata: Vec<(String, usize)> = game
-            .players()
-            .iter()
-            .map(|p| (p.uuid.clone(), p.cards.len()))
-            .collect();
-
-        let (raw_scores, final_scores) = self.calculate_scores(
-            &player_metadata,
-            &collected,
-            room_id,
-            game_number,
-            winner_uuid,
-            had_bots,
-            completed_at,
-        );
-
-        let player_results: Vec<PlayerGameResult> = player_metadata
-            .iter()
-            .map(|(uuid, cards)| PlayerGameResult {
-                uuid: uuid.clone(),
-                cards_remaining: *cards as u8,
-                raw_score: raw_scores.get(uuid).copied().unwrap_or_default(),
-                final_score: final_scores.get(uuid).copied().unwrap_or_default(),
-            })
-            .collect();
-
         let game_result = GameResult {
             room_id: room_id.to_string(),
             game_number,
             winner_uuid: winner_uuid.to_string(),
-            players: player_results,
+            players: Vec::new(),
             completed_at,
             had_bots,
+            deal_seed: game.deal_seed(),
         };
+        let game_result = self.scorer.score(game, game_result).await?;
 
         // Record game and get updated stats in one operation
         let updated_room_stats = self.repository.record_game(game_result.clone()).await?;
@@ -103,71 +78,6 @@ impl StatsService {
         Ok(())
     }
 
-    /// Calculate scores for all players using registered calculators
-    #[allow(clippy::too_many_arguments)]
-    fn calculate_scores(
-        &self,
-        player_metadata: &[(String, usize)],
-        collected: &[CollectedData],
-        room_id: &str,
-        game_number: u32,
-        winner_uuid: &str,
-        had_bots: bool,
-        completed_at: chrono::DateTime<chrono::Utc>,
-    ) -> (HashMap<String, i32>, HashMap<String, i32>) {
-        let mut current_scores: HashMap<String, i32> = HashMap::new();
-        let mut raw_scores: HashMap<String, i32> = HashMap::new();
-
-        for (index, calculator) in self.calculators.iter().enumerate() {
-            let snapshot_players: Vec<PlayerGameResult> = player_metadata
-                .iter()
-                .map(|(uuid, cards)| PlayerGameResult {
-                    uuid: uuid.clone(),
-                    cards_remaining: *cards as u8,
-                    raw_score: current_scores.get(uuid).copied().unwrap_or_default(),
-                    final_score: current_scores.get(uuid).copied().unwrap_or_default(),
-                })
-                .collect();
-
-            let snapshot = GameResult {
-                room_id: room_id.to_string(),
-                game_number,
-                winner_uuid: winner_uuid.to_string(),
-                players: snapshot_players,
-                completed_at,
-                had_bots,
-            };
-
-            let context = CalculationContext::new(&snapshot, &current_scores);
-
-            let mut next_scores = current_scores.clone();
-            for (uuid, _) in player_metadata {
-                let updated = calculator.calculate(uuid, collected, &context);
-                next_scores.insert(uuid.clone(), updated);
-            }
-
-            if index == 0 {
-                raw_scores = next_scores.clone();
-            }
-
-            current_scores = next_scores;
-        }
-
-        (raw_scores, current_scores)
-    }
-
-    async fn collect_all(
-        &self,
-        game: &Game,
-        winner_uuid: &str,
-    ) -> Result<Vec<CollectedData>, StatsError> {
-        let mut collected = Vec::new();
-        for collector in &self.collectors {
-            collected.extend(collector.collect(game, winner_uuid).await?);
-        }
-        Ok(collected)
-    }
-
     async fn room_lock(&self, room_id: &str) -> Arc<AsyncMutex<()>> {
         // Fast path: check if lock exists with read lock
         {
@@ -216,15 +126,10 @@ pub struct StatsServiceBuilder {
 
 impl StatsServiceBuilder {
     fn new(repository: Arc<dyn StatsRepository>) -> Self {
+        let defaults = GameScorer::default();
         Self {
-            collectors: vec![
-                Arc::new(CardsRemainingCollector::new()),
-                Arc::new(WinLossCollector::new()),
-            ],
-            calculators: vec![
-                Arc::new(CardCountScoreCalculator::new()),
-                Arc::new(TenPlusMultiplierCalculator::new()),
-            ],
+            collectors: defaults.collectors,
+            calculators: defaults.calculators,
             repository,
             bot_manager: None,
         }
@@ -247,11 +152,9 @@ impl StatsServiceBuilder {
         self
     }
 
-    pub fn build(mut self) -> StatsService {
-        self.calculators.sort_by_key(|c| c.priority());
+    pub fn build(self) -> StatsService {
         StatsService {
-            collectors: self.collectors,
-            calculators: self.calculators,
+            scorer: Arc::new(GameScorer::new(self.collectors, self.calculators)),
             repository: self.repository,
             bot_manager: self.bot_manager,
             room_mutexes: Arc::new(RwLock::new(HashMap::new())),
@@ -290,8 +193,8 @@ impl RoomEventHandler for StatsRoomSubscriber {
         event: RoomEvent,
     ) -> Result<(), RoomEventError> {
         match event {
-            RoomEvent::GameWon { winner, .. } => {
-                if let Some(game) = self.game_service.get_game(room_id).await {
+            RoomEvent::GameWon { winner, .. } => match self.game_service.get_game(room_id).await {
+                Ok(Some(game)) => {
                     let result = self
                         .stats_service
                         .process_completed_game(room_id, &game, &winner)
@@ -312,7 +215,11 @@ impl RoomEventHandler for StatsRoomSubscriber {
                         }
                     }
                 }
-            }
+                Ok(None) => {}
+                Err(err) => {
+                    tracing::error!(?err, room_id, "Failed to load game for stats");
+                }
+            },
             RoomEvent::PlayerLeft { .. } => match self.room_service.get_room(room_id).await {
                 Ok(Some(room)) if room.player_uuids.is_empty() => {
                     if let Err(err) = self.stats_service.reset_room_stats(room_id).await {
@@ -341,7 +248,7 @@ mod tests {
     use crate::{
         bot::BotManager,
         game::{Card, Game},
-        stats::InMemoryStatsRepository,
+        stats::{CalculationContext, CollectedData, InMemoryStatsRepository},
     };
     use tokio::sync::Mutex;
 
@@ -433,6 +340,28 @@ mod tests {
         assert!(alice.final_score >= alice.raw_score);
     }
 
+    #[tokio::test]
+    async fn process_completed_game_records_deal_seed() {
+        let repo = Arc::new(InMemoryStatsRepository::new());
+        let service = StatsService::builder(repo).build();
+
+        let game = Game::new_game_with_seed(
+            "room".to_string(),
+            &[
+                ("Alice".to_string(), "alice".to_string()),
+                ("Bob".to_string(), "bob".to_string()),
+            ],
+            7,
+        )
+        .unwrap();
+
+        let (game_result, _) = service
+            .process_completed_game("room", &game, "bob")
+            .await
+            .unwrap();
+        assert_eq!(game_result.deal_seed, Some(7));
+    }
+
     #[tokio::test]
     async fn calculates_game_numbers_sequentially_per_room() {
         let repo = Arc::new(InMemoryStatsRepository::new());
@@ -461,7 +390,12 @@ mod tests {
         let repo = Arc::new(InMemoryStatsRepository::new());
         let bot_manager = Arc::new(BotManager::new());
         let bot_player = bot_manager
-            .create_bot("room".to_string(), crate::bot::types::BotDifficulty::Easy)
+            .create_bot(
+                "room".to_string(),
+                crate::bot::types::BotDifficulty::Easy,
+                None,
+                crate::bot::profile::BotProfile::default(),
+            )
             .await
             .unwrap();
 
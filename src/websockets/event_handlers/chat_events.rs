@@ -101,6 +101,7 @@ mod tests {
             connected_players: vec!["a".into(), "b".into()],
             created_at: now,
             last_activity_at: now,
+            settings: Default::default(),
         };
         repo.create_room(&room).await.unwrap();
         let room_service = Arc::new(RoomService::new(repo));
@@ -1,25 +1,53 @@
 use std::sync::Arc;
-use tracing::info;
+use tracing::{info, warn};
 
 use crate::{
     event::{EventBus, RoomEvent, RoomEventError},
-    game::{Card, Game, GameService},
-    room::service::RoomService,
-    websockets::{connection_manager::ConnectionManager, messages::WebSocketMessage},
+    game::{
+        write_game, Card, Game, GameConfig, GameService, InstantWin, MatchStanding, MatchTarget,
+        PlayerView, TurnClock,
+    },
+    room::{models::RoomSetup, service::RoomService},
+    stats::StatsService,
+    websockets::{
+        connection_manager::ConnectionManager,
+        messages::{ArrangementPayload, WebSocketMessage},
+    },
 };
 
-use super::shared::{MessageBroadcaster, RoomQueryUtils};
+use super::shared::{GameStateUtils, MessageBroadcaster, RoomQueryUtils};
+
+/// How many arrangements a player is offered when they ask for one
+const ARRANGEMENT_SUGGESTIONS: usize = 3;
 
 fn cards_to_strings(cards: &[Card]) -> Vec<String> {
     cards.iter().map(|card| card.to_string()).collect()
 }
 
+/// Build a GAME_STARTED message from a player's redacted view
+pub(crate) fn game_started_message(view: &PlayerView) -> WebSocketMessage {
+    let last_plays_by_player = view
+        .last_plays_by_player
+        .iter()
+        .map(|(uuid, cards)| (uuid.clone(), cards_to_strings(cards)))
+        .collect();
+
+    WebSocketMessage::game_started(
+        view.current_turn.clone(),
+        cards_to_strings(&view.cards),
+        view.player_uuids(),
+        view.card_counts(),
+        last_plays_by_player,
+    )
+}
+
 pub struct GameEventHandlers {
     room_service: Arc<RoomService>,
     connection_manager: Arc<dyn ConnectionManager>,
     game_service: Arc<GameService>,
     event_bus: EventBus,
     bot_manager: Arc<crate::bot::BotManager>,
+    stats_service: Arc<StatsService>,
 }
 
 impl GameEventHandlers {
@@ -29,6 +57,7 @@ impl GameEventHandlers {
         game_service: Arc<GameService>,
         event_bus: EventBus,
         bot_manager: Arc<crate::bot::BotManager>,
+        stats_service: Arc<StatsService>,
     ) -> Self {
         Self {
             room_service,
@@ -36,10 +65,11 @@ impl GameEventHandlers {
             game_service,
             event_bus,
             bot_manager,
+            stats_service,
         }
     }
 
-    pub async fn handle_start_game(&self, room_id: &str, game: Game) -> Result<(), RoomEventError> {
+    pub async fn handle_start_game(&self, room_id: &str) -> Result<(), RoomEventError> {
         info!(room_id = %room_id, "Starting game");
 
         // Clear all ready states when game starts
@@ -50,29 +80,16 @@ impl GameEventHandlers {
                 RoomEventError::HandlerError(format!("Failed to clear ready states: {}", e))
             })?;
 
-        let current_player_turn = game.current_player_turn();
-
-        // Build card counts map for all players
-        let card_counts: std::collections::HashMap<String, usize> = game
-            .players()
-            .iter()
-            .map(|p| (p.uuid.clone(), p.cards.len()))
-            .collect();
+        let game = self.get_game_or_error(room_id).await?;
 
-        // At game start, no one has played yet, so last_plays_by_player is empty
-        let last_plays_by_player = std::collections::HashMap::new();
+        let current_player_turn = game.current_player_turn();
 
+        // Each player only ever receives their own redacted view
         for player in game.players() {
-            let player_message = WebSocketMessage::game_started(
-                current_player_turn.clone(),
-                cards_to_strings(&player.cards),
-                game.players()
-                    .iter()
-                    .map(|player| player.uuid.clone())
-                    .collect(),
-                card_counts.clone(),
-                last_plays_by_player.clone(),
-            );
+            let Some(view) = game.view_for(&player.uuid) else {
+                continue;
+            };
+            let player_message = game_started_message(&view);
 
             let message_json = serde_json::to_string(&player_message).map_err(|e| {
                 RoomEventError::HandlerError(format!(
@@ -86,6 +103,11 @@ impl GameEventHandlers {
                 .await;
         }
 
+        // An instant win ends the game at the deal, so nobody is to move
+        if game.is_over() {
+            return Ok(());
+        }
+
         // Notify subscribers whose turn it is so bots can act immediately
         self.event_bus
             .emit_to_room(
@@ -103,6 +125,8 @@ impl GameEventHandlers {
         &self,
         room_id: &str,
         host: &str,
+        match_target: Option<MatchTarget>,
+        seed: Option<u64>,
     ) -> Result<(), RoomEventError> {
         info!(
             room_id = %room_id,
@@ -122,8 +146,8 @@ impl GameEventHandlers {
             return Ok(());
         }
 
-        if room.get_player_uuids().len() != 4 {
-            info!(room_id = %room_id, "Room does not have 4 players, cannot start game");
+        if room.get_player_uuids().len() < 2 {
+            info!(room_id = %room_id, "Room needs at least 2 players to start a game");
             return Ok(());
         }
 
@@ -157,11 +181,26 @@ impl GameEventHandlers {
             return Ok(());
         }
 
+        self.game_service.prepare_match(room_id, match_target).await;
+        let setup = RoomSetup {
+            settings: room.settings.clone(),
+            bots: self.bot_manager.get_bots_in_room(room_id).await,
+        };
+        if let Err(e) = self.room_service.save_room_setup(room_id, &setup).await {
+            warn!(room_id = %room_id, error = %e, "Failed to save room setup");
+        }
+
         self.event_bus
             .emit_to_room(
                 room_id,
                 RoomEvent::CreateGame {
                     players: room.get_player_uuids().clone(),
+                    config: GameConfig {
+                        rules: room.settings.rules,
+                        house_rules: room.settings.house_rules,
+                        take_back: room.settings.take_back,
+                        seed,
+                    },
                 },
             )
             .await;
@@ -174,7 +213,7 @@ impl GameEventHandlers {
         room_id: &str,
         player_uuid: &str,
         cards: &[Card],
-        game: Game,
+        remaining_cards: usize,
     ) -> Result<(), RoomEventError> {
         info!(
             room_id = %room_id,
@@ -183,24 +222,17 @@ impl GameEventHandlers {
             "Handling move played event"
         );
 
-        // Get the player who made the move to find their remaining card count
-        let remaining_cards = game
-            .players()
-            .iter()
-            .find(|p| p.uuid == player_uuid)
-            .map(|p| p.cards.len())
-            .unwrap_or(0);
-
+        // Everything sent comes from the event itself: the stored game may already have moved on
+        let room = RoomQueryUtils::get_room_or_error(&self.room_service, room_id).await?;
         let player_message = WebSocketMessage::move_played(
             player_uuid.to_string(),
             cards_to_strings(cards),
             remaining_cards,
         );
 
-        let player_uuids: Vec<String> = game.players().iter().map(|p| p.uuid.clone()).collect();
         MessageBroadcaster::broadcast_to_players(
             &self.connection_manager,
-            &player_uuids,
+            room.get_player_uuids(),
             &player_message,
         )
         .await?;
@@ -219,14 +251,7 @@ impl GameEventHandlers {
             "Handling turn changed event"
         );
 
-        let game =
-            self.game_service
-                .get_game(room_id)
-                .await
-                .ok_or(RoomEventError::HandlerError(format!(
-                    "Game not found for room: {}",
-                    room_id
-                )))?;
+        let game = self.get_game_or_error(room_id).await?;
 
         let turn_change_message = WebSocketMessage::turn_change(player.to_string());
         let player_uuids: Vec<String> = game.players().iter().map(|p| p.uuid.clone()).collect();
@@ -247,6 +272,235 @@ impl GameEventHandlers {
         Ok(())
     }
 
+    pub async fn handle_turn_clock_started(
+        &self,
+        room_id: &str,
+        clock: &TurnClock,
+    ) -> Result<(), RoomEventError> {
+        info!(room_id = %room_id, player = %clock.player, expires_at = %clock.expires_at, "Handling turn clock event");
+
+        let room = RoomQueryUtils::get_room_or_error(&self.room_service, room_id).await?;
+        let clock_message = WebSocketMessage::turn_clock(clock);
+        MessageBroadcaster::broadcast_to_players(
+            &self.connection_manager,
+            room.get_player_uuids(),
+            &clock_message,
+        )
+        .await
+    }
+
+    pub async fn handle_request_rejected(
+        &self,
+        room_id: &str,
+        player: &str,
+        code: &str,
+        message: &str,
+    ) -> Result<(), RoomEventError> {
+        info!(
+            room_id = %room_id,
+            player = %player,
+            code = %code,
+            "Handling request rejected event"
+        );
+
+        let error_message =
+            WebSocketMessage::error_with_code(code.to_string(), message.to_string());
+        MessageBroadcaster::broadcast_to_players(
+            &self.connection_manager,
+            &[player.to_string()],
+            &error_message,
+        )
+        .await
+    }
+
+    pub async fn handle_sync_requested(
+        &self,
+        room_id: &str,
+        player: &str,
+    ) -> Result<(), RoomEventError> {
+        info!(
+            room_id = %room_id,
+            player = %player,
+            "Handling sync request"
+        );
+
+        let snapshot = GameStateUtils::build_game_state(
+            &self.game_service,
+            &self.stats_service,
+            room_id,
+            player,
+        )
+        .await;
+
+        MessageBroadcaster::broadcast_to_players(
+            &self.connection_manager,
+            &[player.to_string()],
+            &snapshot,
+        )
+        .await
+    }
+
+    /// Send a player the best few ways to split their current hand into plays
+    pub async fn handle_arrange_requested(
+        &self,
+        room_id: &str,
+        player: &str,
+    ) -> Result<(), RoomEventError> {
+        let Some(game) = self
+            .game_service
+            .get_game(room_id)
+            .await
+            .map_err(|e| RoomEventError::HandlerError(format!("Failed to load game: {}", e)))?
+        else {
+            return self
+                .send_error(player, "GAME_NOT_FOUND", "No game in progress")
+                .await;
+        };
+
+        let arrangements = game
+            .hand_partitions(player, ARRANGEMENT_SUGGESTIONS)
+            .into_iter()
+            .map(|partition| ArrangementPayload {
+                hands: partition
+                    .hands
+                    .iter()
+                    .map(|hand| cards_to_strings(&hand.to_cards()))
+                    .collect(),
+                cost: partition.cost,
+            })
+            .collect();
+
+        MessageBroadcaster::broadcast_to_players(
+            &self.connection_manager,
+            &[player.to_string()],
+            &WebSocketMessage::hand_arrangement(arrangements),
+        )
+        .await
+    }
+
+    /// Load the room's game, failing the handler if it is missing or cannot be loaded
+    async fn get_game_or_error(&self, room_id: &str) -> Result<Game, RoomEventError> {
+        self.game_service
+            .get_game(room_id)
+            .await
+            .map_err(|e| RoomEventError::HandlerError(format!("Failed to load game: {}", e)))?
+            .ok_or_else(|| {
+                RoomEventError::HandlerError(format!("Game not found for room: {}", room_id))
+            })
+    }
+
+    /// Send an ERROR with a code to a single player
+    async fn send_error(
+        &self,
+        player: &str,
+        code: &str,
+        reason: &str,
+    ) -> Result<(), RoomEventError> {
+        let error_message = WebSocketMessage::error_with_code(code.to_string(), reason.to_string());
+        MessageBroadcaster::broadcast_to_players(
+            &self.connection_manager,
+            &[player.to_string()],
+            &error_message,
+        )
+        .await
+    }
+
+    pub async fn handle_take_back_requested(
+        &self,
+        room_id: &str,
+        player: &str,
+        voters: Vec<String>,
+    ) -> Result<(), RoomEventError> {
+        info!(room_id = %room_id, player = %player, "Handling take-back requested event");
+
+        let room = RoomQueryUtils::get_room_or_error(&self.room_service, room_id).await?;
+        let request_message = WebSocketMessage::take_back_requested(player.to_string(), voters);
+        MessageBroadcaster::broadcast_to_players(
+            &self.connection_manager,
+            room.get_player_uuids(),
+            &request_message,
+        )
+        .await
+    }
+
+    pub async fn handle_move_taken_back(
+        &self,
+        room_id: &str,
+        player: &str,
+        cards: &[Card],
+    ) -> Result<(), RoomEventError> {
+        info!(room_id = %room_id, player = %player, "Handling move taken back event");
+
+        let game = self.get_game_or_error(room_id).await?;
+
+        let message = WebSocketMessage::move_taken_back(
+            player.to_string(),
+            cards_to_strings(cards),
+            game.current_player_turn(),
+        );
+        let player_uuids: Vec<String> = game.players().iter().map(|p| p.uuid.clone()).collect();
+        MessageBroadcaster::broadcast_to_players(&self.connection_manager, &player_uuids, &message)
+            .await
+    }
+
+    pub async fn handle_instant_win(
+        &self,
+        room_id: &str,
+        player: &str,
+        kind: InstantWin,
+        hand: &[Card],
+    ) -> Result<(), RoomEventError> {
+        info!(room_id = %room_id, player = %player, kind = ?kind, "Handling instant win event");
+
+        let room = RoomQueryUtils::get_room_or_error(&self.room_service, room_id).await?;
+        let instant_win_message =
+            WebSocketMessage::instant_win(player.to_string(), kind, cards_to_strings(hand));
+        MessageBroadcaster::broadcast_to_players(
+            &self.connection_manager,
+            room.get_player_uuids(),
+            &instant_win_message,
+        )
+        .await
+    }
+
+    pub async fn handle_misdeal(&self, room_id: &str, player: &str) -> Result<(), RoomEventError> {
+        info!(room_id = %room_id, player = %player, "Handling misdeal event");
+
+        let room = RoomQueryUtils::get_room_or_error(&self.room_service, room_id).await?;
+        let misdeal_message = WebSocketMessage::misdeal(player.to_string());
+        MessageBroadcaster::broadcast_to_players(
+            &self.connection_manager,
+            room.get_player_uuids(),
+            &misdeal_message,
+        )
+        .await
+    }
+
+    pub async fn handle_match_over(
+        &self,
+        room_id: &str,
+        standings: Vec<MatchStanding>,
+    ) -> Result<(), RoomEventError> {
+        info!(room_id = %room_id, "Handling match over event");
+
+        let room = RoomQueryUtils::get_room_or_error(&self.room_service, room_id).await?;
+        let match_over_message = WebSocketMessage::match_over(standings);
+        MessageBroadcaster::broadcast_to_players(
+            &self.connection_manager,
+            room.get_player_uuids(),
+            &match_over_message,
+        )
+        .await?;
+
+        info!(
+            room_id = %room_id,
+            players_notified = room.get_player_uuids().len(),
+            "Match over notification sent to all players"
+        );
+
+        Ok(())
+    }
+
     pub async fn handle_game_won(
         &self,
         room_id: &str,
@@ -259,17 +513,16 @@ impl GameEventHandlers {
             "Handling game won event"
         );
 
-        let game =
-            self.game_service
-                .get_game(room_id)
-                .await
-                .ok_or(RoomEventError::HandlerError(format!(
-                    "Game not found for room: {}",
-                    room_id
-                )))?;
+        let game = self.get_game_or_error(room_id).await?;
 
         let card_strings = cards_to_strings(winning_hand);
-        let game_won_message = WebSocketMessage::game_won(winner.to_string(), card_strings);
+        let game_won_message = WebSocketMessage::game_won(
+            winner.to_string(),
+            card_strings,
+            game.finishing_order().to_vec(),
+            game.deal_seed(),
+            write_game(&game),
+        );
         let player_uuids: Vec<String> = game.players().iter().map(|p| p.uuid.clone()).collect();
         MessageBroadcaster::broadcast_to_players(
             &self.connection_manager,
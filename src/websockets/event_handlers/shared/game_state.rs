@@ -0,0 +1,174 @@
+use crate::{
+    game::GameService,
+    stats::StatsService,
+    websockets::messages::{
+        GameSnapshotPayload, MatchStatePayload, TurnClockPayload, WebSocketMessage,
+    },
+};
+use std::sync::Arc;
+use tracing::warn;
+
+pub struct GameStateUtils;
+
+impl GameStateUtils {
+    /// Build a GAME_STATE snapshot of the room for a single player
+    ///
+    /// The game section is only present when the player is seated in an active game,
+    /// and it is always built from that player's redacted view.
+    pub async fn build_game_state(
+        game_service: &Arc<GameService>,
+        stats_service: &Arc<StatsService>,
+        room_id: &str,
+        player_uuid: &str,
+    ) -> WebSocketMessage {
+        let game = match game_service.get_game(room_id).await {
+            Ok(game) => game
+                .and_then(|game| game.view_for(player_uuid))
+                .map(|view| GameSnapshotPayload::from(&view)),
+            Err(e) => {
+                warn!(room_id = %room_id, error = %e, "Failed to load game for snapshot");
+                None
+            }
+        };
+
+        let room_stats = match stats_service.get_room_stats(room_id).await {
+            Ok(stats) => stats,
+            Err(e) => {
+                warn!(room_id = %room_id, error = %e, "Failed to load room stats for snapshot");
+                None
+            }
+        };
+
+        let match_state = game_service
+            .get_match(room_id)
+            .await
+            .map(|current| MatchStatePayload::from(&current));
+
+        // Reconnecting players pick the clock up where it is instead of waiting for the next turn
+        let turn_clock = match game {
+            Some(_) => game_service
+                .turn_clock(room_id)
+                .await
+                .map(|clock| TurnClockPayload::from(&clock)),
+            None => None,
+        };
+
+        WebSocketMessage::game_state(game, room_stats, match_state, turn_clock)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::{Card, Rank, Suit};
+    use crate::stats::InMemoryStatsRepository;
+    use crate::user::mapping_service::InMemoryPlayerMappingService;
+    use crate::websockets::messages::{GameStatePayload, MessageType};
+
+    fn services() -> (Arc<GameService>, Arc<StatsService>) {
+        let game_service = Arc::new(GameService::new(Arc::new(
+            InMemoryPlayerMappingService::new(),
+        )));
+        let stats_service =
+            Arc::new(StatsService::builder(Arc::new(InMemoryStatsRepository::new())).build());
+        (game_service, stats_service)
+    }
+
+    #[tokio::test]
+    async fn test_build_game_state_without_game() {
+        let (game_service, stats_service) = services();
+
+        let msg = GameStateUtils::build_game_state(&game_service, &stats_service, "r1", "p1").await;
+        assert_eq!(msg.message_type, MessageType::GameState);
+
+        let payload: GameStatePayload = serde_json::from_value(msg.payload).unwrap();
+        assert!(payload.game.is_none());
+        assert!(payload.room_stats.is_none());
+        assert!(payload.match_state.is_none());
+        assert!(payload.turn_clock.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_build_game_state_is_redacted() {
+        let (game_service, stats_service) = services();
+        game_service
+            .create_game_with_cards(
+                "r1",
+                vec![
+                    (
+                        "Alice".to_string(),
+                        "p1".to_string(),
+                        vec![
+                            Card::new(Rank::Three, Suit::Diamonds),
+                            Card::new(Rank::Four, Suit::Diamonds),
+                        ],
+                    ),
+                    (
+                        "Bob".to_string(),
+                        "p2".to_string(),
+                        vec![Card::new(Rank::Five, Suit::Spades)],
+                    ),
+                ],
+            )
+            .await
+            .unwrap();
+        game_service
+            .try_play_move("r1", "p1", &[Card::new(Rank::Three, Suit::Diamonds)])
+            .await
+            .unwrap();
+        game_service.try_play_move("r1", "p2", &[]).await.unwrap();
+
+        let msg = GameStateUtils::build_game_state(&game_service, &stats_service, "r1", "p2").await;
+        let payload: GameStatePayload = serde_json::from_value(msg.payload).unwrap();
+        let game = payload.game.unwrap();
+
+        assert_eq!(game.cards, vec!["5S".to_string()]);
+        assert_eq!(game.card_counts.get("p1"), Some(&1));
+        assert_eq!(game.current_turn, "p1");
+        assert_eq!(game.consecutive_passes, 1);
+        assert_eq!(game.last_played_cards, vec!["3D".to_string()]);
+        assert_eq!(game.last_played_by.as_deref(), Some("p1"));
+        assert!(game.winner.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_build_game_state_keeps_the_turn_clock() {
+        let (game_service, stats_service) = services();
+        game_service
+            .create_game_with_cards(
+                "r1",
+                vec![
+                    (
+                        "Alice".to_string(),
+                        "p1".to_string(),
+                        vec![Card::new(Rank::Three, Suit::Diamonds)],
+                    ),
+                    (
+                        "Bob".to_string(),
+                        "p2".to_string(),
+                        vec![Card::new(Rank::Five, Suit::Spades)],
+                    ),
+                ],
+            )
+            .await
+            .unwrap();
+        let expires_at = chrono::Utc::now() + chrono::Duration::seconds(20);
+        game_service
+            .start_turn_clock(
+                "r1",
+                crate::game::TurnClock {
+                    player: "p1".to_string(),
+                    expires_at,
+                },
+            )
+            .await;
+
+        let msg = GameStateUtils::build_game_state(&game_service, &stats_service, "r1", "p2").await;
+        let payload: GameStatePayload = serde_json::from_value(msg.payload).unwrap();
+        let clock = payload.turn_clock.unwrap();
+
+        assert_eq!(clock.player, "p1");
+        assert_eq!(clock.expires_at, expires_at);
+        assert!((19..=20).contains(&clock.remaining_seconds));
+    }
+}
@@ -1,7 +1,9 @@
+pub mod game_state;
 pub mod message_broadcast;
 pub mod player_mapping;
 pub mod room_queries;
 
+pub use game_state::GameStateUtils;
 pub use message_broadcast::MessageBroadcaster;
 pub use player_mapping::PlayerMappingUtils;
 pub use room_queries::RoomQueryUtils;
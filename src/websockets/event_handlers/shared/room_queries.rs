@@ -50,6 +50,7 @@ mod tests {
             connected_players: vec![],
             created_at: now,
             last_activity_at: now,
+            settings: Default::default(),
         };
         repo.create_room(&room).await.unwrap();
 
@@ -91,6 +92,7 @@ mod tests {
             connected_players: vec![],
             created_at: now,
             last_activity_at: now,
+            settings: Default::default(),
         };
         repo.create_room(&room).await.unwrap();
         let some = RoomQueryUtils::get_room_if_exists(&service, "r2")
@@ -10,10 +10,10 @@ use tracing::{debug, info, warn};
 
 use crate::event::EventBus;
 use crate::event::RoomEvent;
-use crate::game::Card;
-// Use Game's public methods to access last non-pass state instead of private types
+use crate::game::{Card, MatchTarget, PlayParseError, MAX_SEED};
 use crate::shared::{AppError, AppState};
-use crate::websockets::messages::{MessageType, WebSocketMessage};
+use crate::websockets::event_handlers::shared::GameStateUtils;
+use crate::websockets::messages::{MessageType, TakeBackVotePayload, WebSocketMessage};
 
 use super::socket::{Connection, MessageHandler};
 
@@ -67,11 +67,63 @@ impl MessageHandler for WebsocketReceiveHandler {
                         .await;
                 }
                 MessageType::StartGame => {
+                    // An optional {"match": {"points": 50}} or {"match": {"games": 5}}
+                    let match_target = match ws_message.payload.get("match") {
+                        None | Some(serde_json::Value::Null) => None,
+                        Some(value) => {
+                            let target = serde_json::from_value::<MatchTarget>(value.clone())
+                                .map_err(|e| e.to_string())
+                                .and_then(|target| target.validate().map(|()| target));
+                            match target {
+                                Ok(target) => Some(target),
+                                Err(reason) => {
+                                    self.event_bus
+                                        .emit_to_room(
+                                            room_id,
+                                            RoomEvent::RequestRejected {
+                                                player: username.to_string(),
+                                                code: "INVALID_MATCH".to_string(),
+                                                message: format!("Invalid match: {}", reason),
+                                            },
+                                        )
+                                        .await;
+                                    return;
+                                }
+                            }
+                        }
+                    };
+
+                    // An optional {"seed": 42} replays an earlier deal
+                    let seed = match ws_message.payload.get("seed") {
+                        None | Some(serde_json::Value::Null) => None,
+                        Some(value) => match value.as_u64().filter(|seed| *seed <= MAX_SEED) {
+                            Some(seed) => Some(seed),
+                            None => {
+                                self.event_bus
+                                    .emit_to_room(
+                                        room_id,
+                                        RoomEvent::RequestRejected {
+                                            player: username.to_string(),
+                                            code: "INVALID_SEED".to_string(),
+                                            message: format!(
+                                                "Seed must be an integer from 0 to {}, got {}",
+                                                MAX_SEED, value
+                                            ),
+                                        },
+                                    )
+                                    .await;
+                                return;
+                            }
+                        },
+                    };
+
                     self.event_bus
                         .emit_to_room(
                             room_id,
                             RoomEvent::TryStartGame {
                                 host: username.to_string(),
+                                match_target,
+                                seed,
                             },
                         )
                         .await;
@@ -91,45 +143,83 @@ impl MessageHandler for WebsocketReceiveHandler {
                             .await;
                     }
                 }
-                MessageType::Move => {
-                    if let Some(cards_array) =
-                        ws_message.payload.get("cards").and_then(|v| v.as_array())
-                    {
-                        let card_strings: Vec<String> = cards_array
-                            .iter()
-                            .filter_map(|v| v.as_str().map(|s| s.to_string()))
-                            .collect();
-
+                MessageType::Move => match ws_message.payload.get("cards") {
+                    // A typed move ("3D 3C", "pair of 7s") is read against the player's hand
+                    Some(serde_json::Value::String(text)) => {
+                        self.event_bus
+                            .emit_to_room(
+                                room_id,
+                                RoomEvent::TryPlayText {
+                                    player: username.to_string(),
+                                    text: text.clone(),
+                                },
+                            )
+                            .await;
+                    }
+                    Some(serde_json::Value::Array(cards_array)) => {
                         // Convert card strings to Card objects early
-                        let cards: Result<Vec<Card>, _> = card_strings
+                        let cards: Result<Vec<Card>, String> = cards_array
                             .iter()
-                            .map(|card_str| Card::from_string(card_str))
+                            .map(|value| {
+                                let card_str = value.as_str().ok_or_else(|| {
+                                    format!("Invalid card {}: cards must be strings", value)
+                                })?;
+                                Card::from_string(card_str).map_err(|error| {
+                                    PlayParseError::InvalidCard {
+                                        token: card_str.to_string(),
+                                        error,
+                                    }
+                                    .to_string()
+                                })
+                            })
                             .collect();
 
-                        match cards {
-                            Ok(cards) => {
-                                self.event_bus
-                                    .emit_to_room(
-                                        room_id,
-                                        RoomEvent::TryPlayMove {
-                                            player: username.to_string(),
-                                            cards,
-                                        },
-                                    )
-                                    .await;
-                            }
-                            Err(e) => {
+                        let event = match cards {
+                            Ok(cards) => RoomEvent::TryPlayMove {
+                                player: username.to_string(),
+                                cards,
+                            },
+                            Err(reason) => {
                                 warn!(
                                     username = %username,
                                     room_id = %room_id,
-                                    error = %e,
+                                    error = %reason,
                                     "Invalid card format in move"
                                 );
-                                // TODO: Send error message back to client
+                                RoomEvent::RequestRejected {
+                                    player: username.to_string(),
+                                    code: "INVALID_CARD".to_string(),
+                                    message: reason,
+                                }
                             }
-                        }
+                        };
+                        self.event_bus.emit_to_room(room_id, event).await;
                     }
-                }
+                    other => {
+                        warn!(
+                            username = %username,
+                            room_id = %room_id,
+                            "Move without a card list or typed play"
+                        );
+                        let reason = match other {
+                            Some(value) => format!(
+                                "Cards must be a list of card codes or a typed play, got {}",
+                                value
+                            ),
+                            None => "A move needs cards (an empty list passes)".to_string(),
+                        };
+                        self.event_bus
+                            .emit_to_room(
+                                room_id,
+                                RoomEvent::RequestRejected {
+                                    player: username.to_string(),
+                                    code: "INVALID_CARD".to_string(),
+                                    message: reason,
+                                },
+                            )
+                            .await;
+                    }
+                },
                 MessageType::Heartbeat => {
                     // Client sent heartbeat to check connection health
                     // Emit event to send HEARTBEAT_ACK back to this specific player
@@ -147,6 +237,75 @@ impl MessageHandler for WebsocketReceiveHandler {
                         )
                         .await;
                 }
+                MessageType::Redeal => {
+                    self.event_bus
+                        .emit_to_room(
+                            room_id,
+                            RoomEvent::RedealRequested {
+                                player: username.to_string(),
+                            },
+                        )
+                        .await;
+                }
+                MessageType::TakeBack => {
+                    self.event_bus
+                        .emit_to_room(
+                            room_id,
+                            RoomEvent::TryTakeBack {
+                                player: username.to_string(),
+                            },
+                        )
+                        .await;
+                }
+                MessageType::TakeBackVote => {
+                    let event = match serde_json::from_value::<TakeBackVotePayload>(
+                        ws_message.payload.clone(),
+                    ) {
+                        Ok(vote) => RoomEvent::TakeBackVote {
+                            player: username.to_string(),
+                            approve: vote.approve,
+                        },
+                        Err(e) => {
+                            warn!(
+                                username = %username,
+                                room_id = %room_id,
+                                error = %e,
+                                "Invalid take-back vote"
+                            );
+                            RoomEvent::RequestRejected {
+                                player: username.to_string(),
+                                code: "INVALID_VOTE".to_string(),
+                                message: format!("Invalid take-back vote: {}", e),
+                            }
+                        }
+                    };
+                    self.event_bus.emit_to_room(room_id, event).await;
+                }
+                MessageType::Sync => {
+                    debug!(
+                        username = %username,
+                        room_id = %room_id,
+                        "Received sync request from client"
+                    );
+                    self.event_bus
+                        .emit_to_room(
+                            room_id,
+                            RoomEvent::SyncRequested {
+                                player: username.to_string(),
+                            },
+                        )
+                        .await;
+                }
+                MessageType::Arrange => {
+                    self.event_bus
+                        .emit_to_room(
+                            room_id,
+                            RoomEvent::ArrangeRequested {
+                                player: username.to_string(),
+                            },
+                        )
+                        .await;
+                }
                 _ => {
                     debug!(
                         message_type = ?ws_message.message_type,
@@ -340,84 +499,29 @@ async fn handle_websocket_connection(
         )
         .await;
 
-    // If a game is active, send game hydration data to the reconnecting player
-    if let Some(game) = app_state.game_service.get_game(&room_id).await {
-        // Find the reconnecting player in the game
-        if let Some(player) = game.players().iter().find(|p| p.uuid == player_uuid) {
-            // Build card counts map for all players
-            let card_counts: std::collections::HashMap<String, usize> = game
-                .players()
-                .iter()
-                .map(|p| (p.uuid.clone(), p.cards.len()))
-                .collect();
-
-            // Convert last plays by player from Card to String
-            let last_plays_by_player: std::collections::HashMap<String, Vec<String>> = game
-                .last_plays_by_player()
-                .iter()
-                .map(|(uuid, cards)| (uuid.clone(), cards.iter().map(|c| c.to_string()).collect()))
-                .collect();
-
-            let hydration_message = crate::websockets::messages::WebSocketMessage::game_started(
-                game.current_player_turn().clone(),
-                player.cards.iter().map(|card| card.to_string()).collect(),
-                game.players().iter().map(|p| p.uuid.clone()).collect(),
-                card_counts,
-                last_plays_by_player,
-            );
-
-            if let Ok(message_json) = serde_json::to_string(&hydration_message) {
-                let _ = outbound_sender.send(message_json);
-                debug!(
-                    room_id = %room_id,
-                    username = %username,
-                    "Sent game hydration GAME_STARTED to reconnecting player"
-                );
-            }
+    // If a game is active, send a full GAME_STATE snapshot to the reconnecting player
+    let has_game = match app_state.game_service.get_game(&room_id).await {
+        Ok(game) => game.is_some(),
+        Err(e) => {
+            warn!(room_id = %room_id, error = %e, "Failed to load game for reconnecting player");
+            false
+        }
+    };
+    if has_game {
+        let snapshot = GameStateUtils::build_game_state(
+            &app_state.game_service,
+            &app_state.stats_service,
+            &room_id,
+            &player_uuid,
+        )
+        .await;
 
-            // Additionally hydrate last played cards (if any) so UI shows table state
-            let last_cards = game
-                .last_non_pass_cards()
-                .iter()
-                .map(|c| c.to_string())
-                .collect::<Vec<_>>();
-            if !last_cards.is_empty() {
-                if let Some(last_player_uuid) = game.last_non_pass_player_uuid() {
-                    // Get remaining cards for the last player who played
-                    let remaining_cards = game
-                        .players()
-                        .iter()
-                        .find(|p| p.uuid == last_player_uuid)
-                        .map(|p| p.cards.len())
-                        .unwrap_or(0);
-
-                    let move_message = crate::websockets::messages::WebSocketMessage::move_played(
-                        last_player_uuid,
-                        last_cards,
-                        remaining_cards,
-                    );
-                    if let Ok(move_json) = serde_json::to_string(&move_message) {
-                        let _ = outbound_sender.send(move_json);
-                        debug!(
-                            room_id = %room_id,
-                            username = %username,
-                            "Sent game hydration GAME_STARTED to reconnecting player"
-                        );
-                    }
-                } else {
-                    warn!(
-                        room_id = %room_id,
-                        username = %username,
-                        "Failed to serialize game hydration message"
-                    );
-                }
-            }
-        } else {
-            warn!(
+        if let Ok(message_json) = serde_json::to_string(&snapshot) {
+            let _ = outbound_sender.send(message_json);
+            debug!(
                 room_id = %room_id,
                 username = %username,
-                player_uuid = %player_uuid,
-                "Reconnecting player UUID not present in active game"
+                "Sent GAME_STATE snapshot to reconnecting player"
             );
         }
     }
@@ -561,4 +665,214 @@ mod tests {
             .iter()
             .any(|e| matches!(e, RoomEvent::TryPlayMove { .. })));
     }
+
+    #[tokio::test]
+    async fn test_move_accepts_typed_text_and_rejects_bad_cards() {
+        let bus = EventBus::new();
+        let handler = WebsocketReceiveHandler::new(bus.clone());
+        let mut rx = bus.subscribe_to_room("r1").await;
+
+        let typed = serde_json::json!({
+            "type": "MOVE",
+            "payload": {"cards": "pair of 7s"},
+            "meta": null
+        });
+        handler
+            .handle_message("alice", "r1", typed.to_string())
+            .await;
+        match rx.recv().await.unwrap() {
+            RoomEvent::TryPlayText { player, text } => {
+                assert_eq!(player, "alice");
+                assert_eq!(text, "pair of 7s");
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+
+        let spelled = serde_json::json!({
+            "type": "MOVE",
+            "payload": {"cards": ["10♦", "td"]},
+            "meta": null
+        });
+        handler
+            .handle_message("alice", "r1", spelled.to_string())
+            .await;
+        match rx.recv().await.unwrap() {
+            RoomEvent::TryPlayMove { cards, .. } => {
+                assert_eq!(cards, vec![Card::from_string("TD").unwrap(); 2]);
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+
+        let bad = serde_json::json!({
+            "type": "MOVE",
+            "payload": {"cards": ["3D", "ZZ"]},
+            "meta": null
+        });
+        handler.handle_message("alice", "r1", bad.to_string()).await;
+        match rx.recv().await.unwrap() {
+            RoomEvent::RequestRejected { player, code, .. } => {
+                assert_eq!(player, "alice");
+                assert_eq!(code, "INVALID_CARD");
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+
+        // Non-string entries and payloads that are neither a list nor text are refused too
+        for cards in [
+            serde_json::json!(["3D", 7]),
+            serde_json::json!(42),
+            serde_json::json!(null),
+        ] {
+            let odd = serde_json::json!({
+                "type": "MOVE",
+                "payload": {"cards": cards},
+                "meta": null
+            });
+            handler.handle_message("alice", "r1", odd.to_string()).await;
+            match rx.recv().await.unwrap() {
+                RoomEvent::RequestRejected { code, .. } => assert_eq!(code, "INVALID_CARD"),
+                other => panic!("unexpected event: {:?}", other),
+            }
+        }
+        let missing = serde_json::json!({"type": "MOVE", "payload": {}, "meta": null});
+        handler
+            .handle_message("alice", "r1", missing.to_string())
+            .await;
+        match rx.recv().await.unwrap() {
+            RoomEvent::RequestRejected { code, .. } => assert_eq!(code, "INVALID_CARD"),
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_start_game_reads_match_target() {
+        let bus = EventBus::new();
+        let handler = WebsocketReceiveHandler::new(bus.clone());
+        let mut rx = bus.subscribe_to_room("r1").await;
+
+        let start = serde_json::json!({
+            "type": "START_GAME",
+            "payload": {"match": {"points": 50}},
+            "meta": null
+        });
+        handler
+            .handle_message("host", "r1", start.to_string())
+            .await;
+
+        match rx.recv().await.unwrap() {
+            RoomEvent::TryStartGame {
+                host,
+                match_target,
+                seed,
+            } => {
+                assert_eq!(host, "host");
+                assert_eq!(match_target, Some(MatchTarget::Points(50)));
+                assert_eq!(seed, None);
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_start_game_reads_seed() {
+        let bus = EventBus::new();
+        let handler = WebsocketReceiveHandler::new(bus.clone());
+        let mut rx = bus.subscribe_to_room("r1").await;
+
+        let start = |seed: serde_json::Value| {
+            serde_json::json!({
+                "type": "START_GAME",
+                "payload": {"seed": seed},
+                "meta": null
+            })
+            .to_string()
+        };
+        handler
+            .handle_message("host", "r1", start(serde_json::json!(42)))
+            .await;
+        handler
+            .handle_message("host", "r1", start(serde_json::json!("lucky")))
+            .await;
+        // Seeds past 2^53 - 1 would not survive a JavaScript client
+        handler
+            .handle_message("host", "r1", start(serde_json::json!(MAX_SEED + 1)))
+            .await;
+
+        match rx.recv().await.unwrap() {
+            RoomEvent::TryStartGame { seed, .. } => assert_eq!(seed, Some(42)),
+            other => panic!("unexpected event: {:?}", other),
+        }
+        for _ in 0..2 {
+            match rx.recv().await.unwrap() {
+                RoomEvent::RequestRejected { player, code, .. } => {
+                    assert_eq!(player, "host");
+                    assert_eq!(code, "INVALID_SEED");
+                }
+                other => panic!("unexpected event: {:?}", other),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_start_game_rejects_a_bad_match_target() {
+        let bus = EventBus::new();
+        let handler = WebsocketReceiveHandler::new(bus.clone());
+        let mut rx = bus.subscribe_to_room("r1").await;
+
+        for target in [
+            serde_json::json!({"games": 0}),
+            serde_json::json!({"points": -5}),
+            serde_json::json!({"rounds": 3}),
+        ] {
+            let start = serde_json::json!({
+                "type": "START_GAME",
+                "payload": {"match": target},
+                "meta": null
+            });
+            handler
+                .handle_message("host", "r1", start.to_string())
+                .await;
+
+            match rx.recv().await.unwrap() {
+                RoomEvent::RequestRejected { player, code, .. } => {
+                    assert_eq!(player, "host");
+                    assert_eq!(code, "INVALID_MATCH");
+                }
+                other => panic!("unexpected event: {:?}", other),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_take_back_vote_rejects_a_malformed_payload() {
+        let bus = EventBus::new();
+        let handler = WebsocketReceiveHandler::new(bus.clone());
+        let mut rx = bus.subscribe_to_room("r1").await;
+
+        let vote = |payload: serde_json::Value| {
+            serde_json::json!({"type": "TAKE_BACK_VOTE", "payload": payload, "meta": null})
+                .to_string()
+        };
+        handler
+            .handle_message("bob", "r1", vote(serde_json::json!({"approve": true})))
+            .await;
+        handler
+            .handle_message("bob", "r1", vote(serde_json::json!({"approve": "yes"})))
+            .await;
+
+        match rx.recv().await.unwrap() {
+            RoomEvent::TakeBackVote { player, approve } => {
+                assert_eq!(player, "bob");
+                assert!(approve);
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+        match rx.recv().await.unwrap() {
+            RoomEvent::RequestRejected { player, code, .. } => {
+                assert_eq!(player, "bob");
+                assert_eq!(code, "INVALID_VOTE");
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
 }
@@ -11,7 +11,12 @@ pub enum MessageType {
     Leave,
     StartGame,
     Ready,
-    Heartbeat, // Client heartbeat to check connection health
+    Heartbeat,    // Client heartbeat to check connection health
+    Sync,         // Client request for a fresh GAME_STATE snapshot
+    Redeal,       // Client shows a misdealt hand and asks for a new deal
+    TakeBack,     // Client asks to undo their latest action
+    TakeBackVote, // Client answers another player's take-back request
+    Arrange,      // Client asks how to split their hand into plays
 
     // Server -> Client
     PlayersList,
@@ -24,7 +29,15 @@ pub enum MessageType {
     BotAdded,
     BotRemoved,
     StatsUpdated,
-    HeartbeatAck, // Server acknowledgment of heartbeat
+    HeartbeatAck,      // Server acknowledgment of heartbeat
+    GameState,         // Full snapshot of the room's game for one player
+    MatchOver,         // Final standings once a match reaches its target
+    InstantWin,        // A dealt hand won the game outright
+    Misdeal,           // A player's weak hand was accepted and the cards are redealt
+    TakeBackRequested, // A player asked to undo their latest action and needs the table's consent
+    MoveTakenBack,     // A player's latest action was undone
+    TurnClock,         // Time left for the player to move before they are moved for
+    HandArrangement,   // Suggested ways to split a player's hand into plays
 }
 
 /// Metadata for WebSocket messages
@@ -55,6 +68,11 @@ pub struct LeavePayload {
     pub player: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TakeBackVotePayload {
+    pub approve: bool,
+}
+
 /// Server-to-Client message payloads
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayersListPayload {
@@ -88,6 +106,8 @@ pub struct MovePlayedPayload {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ErrorPayload {
     pub message: String,
+    /// Machine-readable error code, e.g. "NOT_YOUR_TURN"
+    pub code: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -99,6 +119,85 @@ pub struct GameStartedPayload {
     pub last_plays_by_player: std::collections::HashMap<String, Vec<String>>, // UUID -> last played cards
 }
 
+/// Redacted snapshot of an active game, as seen by the receiving player
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameSnapshotPayload {
+    pub game_id: String,
+    pub current_turn: String,
+    pub cards: Vec<String>, // Player's hand
+    pub player_list: Vec<String>,
+    pub card_counts: std::collections::HashMap<String, usize>, // UUID -> card count
+    pub last_plays_by_player: std::collections::HashMap<String, Vec<String>>, // UUID -> last played cards
+    pub consecutive_passes: usize,
+    pub table_clear: bool,
+    pub last_played_cards: Vec<String>,
+    pub last_played_by: Option<String>,
+    pub winner: Option<String>,
+    /// Players who have gone out so far, first place first
+    #[serde(default)]
+    pub finishing_order: Vec<String>,
+}
+
+impl From<&crate::game::PlayerView> for GameSnapshotPayload {
+    fn from(view: &crate::game::PlayerView) -> Self {
+        let to_strings =
+            |cards: &[crate::game::Card]| cards.iter().map(|c| c.to_string()).collect();
+
+        Self {
+            game_id: view.game_id.clone(),
+            current_turn: view.current_turn.clone(),
+            cards: to_strings(&view.cards),
+            player_list: view.player_uuids(),
+            card_counts: view.card_counts(),
+            last_plays_by_player: view
+                .last_plays_by_player
+                .iter()
+                .map(|(uuid, cards)| (uuid.clone(), to_strings(cards)))
+                .collect(),
+            consecutive_passes: view.consecutive_passes,
+            table_clear: view.table_clear,
+            last_played_cards: to_strings(&view.last_played_cards),
+            last_played_by: view.last_played_by.clone(),
+            winner: view.winner.clone(),
+            finishing_order: view.finishing_order.clone(),
+        }
+    }
+}
+
+/// Progress of the match being played in a room
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchStatePayload {
+    pub target: crate::game::MatchTarget,
+    pub games_played: u32,
+    /// False once the target has been reached
+    pub in_progress: bool,
+    pub standings: Vec<crate::game::MatchStanding>,
+}
+
+impl From<&crate::game::Match> for MatchStatePayload {
+    fn from(current: &crate::game::Match) -> Self {
+        Self {
+            target: current.target(),
+            games_played: current.games_played(),
+            in_progress: !current.is_over(),
+            standings: current.standings(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameStatePayload {
+    /// None when the room has no active game (lobby)
+    pub game: Option<GameSnapshotPayload>,
+    pub room_stats: Option<crate::stats::models::RoomStats>,
+    /// None when the room is not playing a match
+    #[serde(default)]
+    pub match_state: Option<MatchStatePayload>,
+    /// None when nobody is on a turn clock
+    #[serde(default)]
+    pub turn_clock: Option<TurnClockPayload>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TurnChangePayload {
     pub player: String,
@@ -108,6 +207,12 @@ pub struct TurnChangePayload {
 pub struct GameWonPayload {
     pub winner: String,
     pub winning_hand: Vec<String>,
+    /// Every player, first place first, when places were played out; otherwise just the winner
+    pub finishing_order: Vec<String>,
+    /// Seed the deal was shuffled from; a START_GAME with this seed deals the same hands
+    pub deal_seed: Option<u64>,
+    /// The whole game in the text notation, for saving or replaying it
+    pub notation: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -126,6 +231,74 @@ pub struct StatsUpdatedPayload {
     pub room_stats: crate::stats::models::RoomStats,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InstantWinPayload {
+    pub player: String,
+    pub kind: crate::game::InstantWin,
+    pub hand: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MisdealPayload {
+    pub player: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TakeBackRequestedPayload {
+    pub player: String,
+    /// UUIDs of the players who must approve
+    pub voters: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MoveTakenBackPayload {
+    pub player: String,
+    /// Cards returned to the player's hand (empty when a pass was taken back)
+    pub cards: Vec<String>,
+    pub current_turn: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TurnClockPayload {
+    pub player: String,
+    pub remaining_seconds: u32,
+    /// When the player will pass (or play their lowest single) if they have not acted
+    pub expires_at: DateTime<Utc>,
+}
+
+impl From<&crate::game::TurnClock> for TurnClockPayload {
+    fn from(clock: &crate::game::TurnClock) -> Self {
+        // Round up, so a player is never shown 0 while time is left
+        let remaining_ms = (clock.expires_at - Utc::now()).num_milliseconds().max(0);
+        Self {
+            player: clock.player.clone(),
+            remaining_seconds: u32::try_from((remaining_ms + 999) / 1000).unwrap_or(u32::MAX),
+            expires_at: clock.expires_at,
+        }
+    }
+}
+
+/// One suggested split of a hand
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArrangementPayload {
+    /// Five-card hands first, then triples, pairs and singles
+    pub hands: Vec<Vec<String>>,
+    /// Lower is better
+    pub cost: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HandArrangementPayload {
+    /// Best first
+    pub arrangements: Vec<ArrangementPayload>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchOverPayload {
+    /// Best first
+    pub standings: Vec<crate::game::MatchStanding>,
+}
+
 /// Helper functions for creating messages
 impl WebSocketMessage {
     pub fn new(message_type: MessageType, payload: serde_json::Value) -> Self {
@@ -174,7 +347,19 @@ impl WebSocketMessage {
     /// Create an ERROR message
     #[allow(dead_code)] // Message constructor for error responses
     pub fn error(message: String) -> Self {
-        let payload = ErrorPayload { message };
+        let payload = ErrorPayload {
+            message,
+            code: None,
+        };
+        Self::new(MessageType::Error, serde_json::to_value(payload).unwrap())
+    }
+
+    /// Create an ERROR message carrying a machine-readable code
+    pub fn error_with_code(code: String, message: String) -> Self {
+        let payload = ErrorPayload {
+            message,
+            code: Some(code),
+        };
         Self::new(MessageType::Error, serde_json::to_value(payload).unwrap())
     }
 
@@ -237,10 +422,19 @@ impl WebSocketMessage {
     }
 
     /// Create a GAME_WON message
-    pub fn game_won(winner: String, winning_hand: Vec<String>) -> Self {
+    pub fn game_won(
+        winner: String,
+        winning_hand: Vec<String>,
+        finishing_order: Vec<String>,
+        deal_seed: Option<u64>,
+        notation: String,
+    ) -> Self {
         let payload = GameWonPayload {
             winner,
             winning_hand,
+            finishing_order,
+            deal_seed,
+            notation,
         };
         Self::new(MessageType::GameWon, serde_json::to_value(payload).unwrap())
     }
@@ -276,6 +470,89 @@ impl WebSocketMessage {
     pub fn heartbeat_ack() -> Self {
         Self::new(MessageType::HeartbeatAck, serde_json::json!({}))
     }
+
+    /// Create a GAME_STATE message
+    pub fn game_state(
+        game: Option<GameSnapshotPayload>,
+        room_stats: Option<crate::stats::models::RoomStats>,
+        match_state: Option<MatchStatePayload>,
+        turn_clock: Option<TurnClockPayload>,
+    ) -> Self {
+        let payload = GameStatePayload {
+            game,
+            room_stats,
+            match_state,
+            turn_clock,
+        };
+        Self::new(
+            MessageType::GameState,
+            serde_json::to_value(payload).unwrap(),
+        )
+    }
+
+    /// Create an INSTANT_WIN message
+    pub fn instant_win(player: String, kind: crate::game::InstantWin, hand: Vec<String>) -> Self {
+        let payload = InstantWinPayload { player, kind, hand };
+        Self::new(
+            MessageType::InstantWin,
+            serde_json::to_value(payload).unwrap(),
+        )
+    }
+
+    /// Create a MISDEAL message
+    pub fn misdeal(player: String) -> Self {
+        let payload = MisdealPayload { player };
+        Self::new(MessageType::Misdeal, serde_json::to_value(payload).unwrap())
+    }
+
+    /// Create a TAKE_BACK_REQUESTED message
+    pub fn take_back_requested(player: String, voters: Vec<String>) -> Self {
+        let payload = TakeBackRequestedPayload { player, voters };
+        Self::new(
+            MessageType::TakeBackRequested,
+            serde_json::to_value(payload).unwrap(),
+        )
+    }
+
+    /// Create a MOVE_TAKEN_BACK message
+    pub fn move_taken_back(player: String, cards: Vec<String>, current_turn: String) -> Self {
+        let payload = MoveTakenBackPayload {
+            player,
+            cards,
+            current_turn,
+        };
+        Self::new(
+            MessageType::MoveTakenBack,
+            serde_json::to_value(payload).unwrap(),
+        )
+    }
+
+    /// Create a TURN_CLOCK message
+    pub fn turn_clock(clock: &crate::game::TurnClock) -> Self {
+        let payload = TurnClockPayload::from(clock);
+        Self::new(
+            MessageType::TurnClock,
+            serde_json::to_value(payload).unwrap(),
+        )
+    }
+
+    /// Create a HAND_ARRANGEMENT message
+    pub fn hand_arrangement(arrangements: Vec<ArrangementPayload>) -> Self {
+        let payload = HandArrangementPayload { arrangements };
+        Self::new(
+            MessageType::HandArrangement,
+            serde_json::to_value(payload).unwrap(),
+        )
+    }
+
+    /// Create a MATCH_OVER message
+    pub fn match_over(standings: Vec<crate::game::MatchStanding>) -> Self {
+        let payload = MatchOverPayload { standings };
+        Self::new(
+            MessageType::MatchOver,
+            serde_json::to_value(payload).unwrap(),
+        )
+    }
 }
 
 #[cfg(test)]
@@ -304,6 +581,14 @@ mod tests {
         let e = WebSocketMessage::error("oops".to_string());
         assert!(matches!(e.message_type, MessageType::Error));
 
+        // error_with_code
+        let ec = WebSocketMessage::error_with_code(
+            "NOT_YOUR_TURN".to_string(),
+            "Invalid player".to_string(),
+        );
+        assert!(matches!(ec.message_type, MessageType::Error));
+        assert_eq!(ec.payload["code"], "NOT_YOUR_TURN");
+
         // host_change
         let h = WebSocketMessage::host_change("u1".to_string(), "host-uuid".to_string());
         assert!(matches!(h.message_type, MessageType::HostChange));
@@ -338,8 +623,17 @@ mod tests {
         assert!(matches!(t.message_type, MessageType::TurnChange));
 
         // game_won
-        let gw = WebSocketMessage::game_won("u3".to_string(), vec!["Card1".to_string()]);
+        let gw = WebSocketMessage::game_won(
+            "u3".to_string(),
+            vec!["Card1".to_string()],
+            vec!["u3".to_string(), "u1".to_string()],
+            Some(7),
+            "[Game \"room\"]\n".to_string(),
+        );
         assert!(matches!(gw.message_type, MessageType::GameWon));
+        assert_eq!(gw.payload["finishing_order"][1], "u1");
+        assert_eq!(gw.payload["deal_seed"], 7);
+        assert_eq!(gw.payload["notation"], "[Game \"room\"]\n");
 
         // bot_added
         let ba = WebSocketMessage::bot_added("bot-123".to_string(), "Bot 1".to_string());
@@ -353,5 +647,73 @@ mod tests {
         let room_stats = crate::stats::models::RoomStats::default();
         let su = WebSocketMessage::stats_updated(room_stats);
         assert!(matches!(su.message_type, MessageType::StatsUpdated));
+
+        // game_state
+        let gst = WebSocketMessage::game_state(None, None, None, None);
+        assert!(matches!(gst.message_type, MessageType::GameState));
+        let s = serde_json::to_string(&gst).unwrap();
+        assert!(s.contains("\"type\":\"GAME_STATE\""));
+
+        // instant_win
+        let iw = WebSocketMessage::instant_win(
+            "u1".to_string(),
+            crate::game::InstantWin::FourTwos,
+            vec!["2D".to_string()],
+        );
+        assert!(matches!(iw.message_type, MessageType::InstantWin));
+        assert_eq!(iw.payload["kind"], "four_twos");
+
+        // misdeal
+        let md = WebSocketMessage::misdeal("u1".to_string());
+        assert!(matches!(md.message_type, MessageType::Misdeal));
+        let s = serde_json::to_string(&md).unwrap();
+        assert!(s.contains("\"type\":\"MISDEAL\""));
+
+        // take_back_requested
+        let tbr = WebSocketMessage::take_back_requested("u1".to_string(), vec!["u2".to_string()]);
+        assert!(matches!(tbr.message_type, MessageType::TakeBackRequested));
+        let s = serde_json::to_string(&tbr).unwrap();
+        assert!(s.contains("\"type\":\"TAKE_BACK_REQUESTED\""));
+
+        // move_taken_back
+        let mtb = WebSocketMessage::move_taken_back(
+            "u1".to_string(),
+            vec!["3D".to_string()],
+            "u1".to_string(),
+        );
+        assert!(matches!(mtb.message_type, MessageType::MoveTakenBack));
+        assert_eq!(mtb.payload["cards"][0], "3D");
+        assert_eq!(mtb.payload["current_turn"], "u1");
+
+        // turn_clock
+        let tc = WebSocketMessage::turn_clock(&crate::game::TurnClock {
+            player: "u1".to_string(),
+            expires_at: Utc::now() + chrono::Duration::seconds(30),
+        });
+        assert!(matches!(tc.message_type, MessageType::TurnClock));
+        let s = serde_json::to_string(&tc).unwrap();
+        assert!(s.contains("\"type\":\"TURN_CLOCK\""));
+        assert_eq!(tc.payload["remaining_seconds"], 30);
+
+        // hand_arrangement
+        let ha = WebSocketMessage::hand_arrangement(vec![ArrangementPayload {
+            hands: vec![vec!["3D".to_string(), "3S".to_string()]],
+            cost: 2,
+        }]);
+        assert!(matches!(ha.message_type, MessageType::HandArrangement));
+        assert_eq!(ha.payload["arrangements"][0]["hands"][0][1], "3S");
+        let s = serde_json::to_string(&ha).unwrap();
+        assert!(s.contains("\"type\":\"HAND_ARRANGEMENT\""));
+
+        // match_over
+        let mo = WebSocketMessage::match_over(vec![crate::game::MatchStanding {
+            player: "u1".to_string(),
+            points: 4,
+            games_won: 2,
+        }]);
+        assert!(matches!(mo.message_type, MessageType::MatchOver));
+        assert_eq!(mo.payload["standings"][0]["games_won"], 2);
+        let s = serde_json::to_string(&mo).unwrap();
+        assert!(s.contains("\"type\":\"MATCH_OVER\""));
     }
 }
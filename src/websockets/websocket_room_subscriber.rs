@@ -4,7 +4,7 @@ use tracing::info;
 
 use crate::{
     event::{RoomEvent, RoomEventError, RoomEventHandler},
-    game::GameService,
+    game::{GameService, TurnClock},
     room::service::RoomService,
     user::PlayerMappingService,
     websockets::connection_manager::ConnectionManager,
@@ -19,7 +19,8 @@ use super::event_handlers::{
 /// Handles room events by delegating to specialized event handlers:
 /// - RoomEventHandlers: PlayerJoined, PlayerLeft, HostChanged
 /// - ChatEventHandlers: ChatMessage
-/// - GameEventHandlers: StartGame, MovePlayed, TurnChanged, GameWon, GameReset
+/// - GameEventHandlers: StartGame, MovePlayed, RequestRejected, TurnChanged, GameWon, GameReset, SyncRequested,
+///   ArrangeRequested, take-back requests and votes
 /// - ConnectionEventHandlers: PlayerDisconnected, leave requests
 pub struct WebSocketRoomSubscriber {
     room_handlers: RoomEventHandlers,
@@ -75,21 +76,55 @@ impl RoomEventHandler for WebSocketRoomSubscriber {
                     .handle_disconnect(room_id, &player)
                     .await
             }
-            RoomEvent::StartGame { game } => {
-                self.game_handlers.handle_start_game(room_id, game).await
+            RoomEvent::StartGame { .. } => self.game_handlers.handle_start_game(room_id).await,
+            RoomEvent::TryStartGame {
+                host,
+                match_target,
+                seed,
+            } => {
+                self.game_handlers
+                    .handle_try_start_game(room_id, &host, match_target, seed)
+                    .await
+            }
+            RoomEvent::InstantWin { player, kind, hand } => {
+                self.game_handlers
+                    .handle_instant_win(room_id, &player, kind, &hand)
+                    .await
+            }
+            RoomEvent::Misdeal { player } => {
+                self.game_handlers.handle_misdeal(room_id, &player).await
             }
-            RoomEvent::TryStartGame { host } => {
+            RoomEvent::TakeBackRequested { player, voters } => {
                 self.game_handlers
-                    .handle_try_start_game(room_id, &host)
+                    .handle_take_back_requested(room_id, &player, voters)
+                    .await
+            }
+            RoomEvent::MoveTakenBack { player, cards } => {
+                self.game_handlers
+                    .handle_move_taken_back(room_id, &player, &cards)
+                    .await
+            }
+            RoomEvent::MatchOver { standings } => {
+                self.game_handlers
+                    .handle_match_over(room_id, standings)
                     .await
             }
             RoomEvent::MovePlayed {
                 player,
                 cards,
-                game,
+                remaining_cards,
             } => {
                 self.game_handlers
-                    .handle_move_played(room_id, &player, &cards, game)
+                    .handle_move_played(room_id, &player, &cards, remaining_cards)
+                    .await
+            }
+            RoomEvent::RequestRejected {
+                player,
+                code,
+                message,
+            } => {
+                self.game_handlers
+                    .handle_request_rejected(room_id, &player, &code, &message)
                     .await
             }
             RoomEvent::TurnChanged { player } => {
@@ -97,6 +132,11 @@ impl RoomEventHandler for WebSocketRoomSubscriber {
                     .handle_turn_changed(room_id, &player)
                     .await
             }
+            RoomEvent::TurnClockStarted { player, expires_at } => {
+                self.game_handlers
+                    .handle_turn_clock_started(room_id, &TurnClock { player, expires_at })
+                    .await
+            }
             RoomEvent::GameWon {
                 winner,
                 winning_hand,
@@ -171,6 +211,16 @@ impl RoomEventHandler for WebSocketRoomSubscriber {
 
                 Ok(())
             }
+            RoomEvent::SyncRequested { player } => {
+                self.game_handlers
+                    .handle_sync_requested(room_id, &player)
+                    .await
+            }
+            RoomEvent::ArrangeRequested { player } => {
+                self.game_handlers
+                    .handle_arrange_requested(room_id, &player)
+                    .await
+            }
             _ => {
                 info!(
                     room_id = %room_id,
@@ -195,6 +245,7 @@ impl WebSocketRoomSubscriber {
         player_mapping: Arc<dyn PlayerMappingService>,
         event_bus: crate::event::EventBus,
         bot_manager: Arc<crate::bot::BotManager>,
+        stats_service: Arc<crate::stats::StatsService>,
     ) -> Self {
         let room_handlers = RoomEventHandlers::new(
             Arc::clone(&room_service),
@@ -212,6 +263,7 @@ impl WebSocketRoomSubscriber {
             Arc::clone(&game_service),
             event_bus.clone(),
             Arc::clone(&bot_manager),
+            stats_service,
         );
 
         let connection_handlers = ConnectionEventHandlers::new(
@@ -15,6 +15,7 @@ async fn stats_service_records_game_when_game_won_event_emitted() {
         .game_service
         .get_game("room-123")
         .await
+        .unwrap()
         .expect("game should exist after creation");
 
     assert_eq!(game.current_player_turn(), first_player_uuid);
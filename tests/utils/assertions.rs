@@ -23,6 +23,17 @@ impl<'a> MessageAssertion<'a> {
         Self { setup, players }
     }
 
+    /// Create an assertion for every player except one
+    pub fn for_all_players_except(setup: &'a TestSetup, excluded: &str) -> Self {
+        let players = setup
+            .players
+            .iter()
+            .map(|s| s.0.as_str())
+            .filter(|uuid| *uuid != excluded)
+            .collect();
+        Self { setup, players }
+    }
+
     /// Create an assertion for specific players
     pub fn for_players(setup: &'a TestSetup, players: Vec<&'a str>) -> Self {
         Self { setup, players }
@@ -145,6 +156,11 @@ pub struct MessageContent {
 }
 
 impl MessageContent {
+    /// Access the raw payload for ad-hoc assertions
+    pub fn payload(&self) -> &serde_json::Value {
+        &self.payload
+    }
+
     /// Assert the message has a specific sender
     pub fn with_sender(self, expected_sender: &str) -> Self {
         assert_eq!(self.payload["sender"], expected_sender);
@@ -173,6 +189,12 @@ impl MessageContent {
         self
     }
 
+    /// Assert the message carries a specific error code
+    pub fn with_error_code(self, expected_code: &str) -> Self {
+        assert_eq!(self.payload["code"], expected_code);
+        self
+    }
+
     /// Assert the message has a specific current turn
     pub fn with_current_turn(self, expected_player: &str) -> Self {
         assert_eq!(self.payload["current_turn"], expected_player);
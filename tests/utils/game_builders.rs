@@ -5,7 +5,7 @@ use std::sync::Arc;
 
 use bigtwo::{
     event::RoomSubscription,
-    game::{Card, GameEventRoomSubscriber, Rank, Suit},
+    game::{Card, GameConfig, GameEventRoomSubscriber, Rank, Suit},
 };
 
 use super::setup::TestSetup;
@@ -150,12 +150,18 @@ impl GameBuilder {
                         .iter()
                         .map(|p| p.0.clone())
                         .collect::<Vec<String>>(),
+                    GameConfig::default(),
                 )
                 .await
                 .unwrap();
 
             // Get the game to find who has 3D (they go first)
-            let game = setup.game_service.get_game("room-123").await.unwrap();
+            let game = setup
+                .game_service
+                .get_game("room-123")
+                .await
+                .unwrap()
+                .unwrap();
             game.current_player_turn()
         } else {
             // Custom cards specified - create game with predetermined cards
@@ -9,7 +9,7 @@ use bigtwo::{
     event::{EventBus, RoomSubscription},
     game::{GameEventRoomSubscriber, GameService},
     room::{
-        models::RoomModel,
+        models::{RoomModel, RoomSettings},
         repository::{InMemoryRoomRepository, RoomRepository},
         service::RoomService,
     },
@@ -42,6 +42,7 @@ pub struct TestSetupBuilder {
     players: Vec<(String, String)>,
     room_id: String,
     bot_manager: Arc<BotManager>,
+    settings: RoomSettings,
 }
 
 impl Default for TestSetupBuilder {
@@ -56,9 +57,16 @@ impl TestSetupBuilder {
             players: vec![],
             room_id: "room-123".to_string(),
             bot_manager: Arc::new(BotManager::new()),
+            settings: RoomSettings::default(),
         }
     }
 
+    /// Create the room with host-chosen settings
+    pub fn with_settings(mut self, settings: RoomSettings) -> Self {
+        self.settings = settings;
+        self
+    }
+
     pub fn with_players(mut self, players: Vec<(String, String)>) -> Self {
         self.players = players;
         self
@@ -124,6 +132,7 @@ impl TestSetupBuilder {
             connected_players: self.players.iter().map(|p| p.0.clone()).collect(),
             created_at: now,
             last_activity_at: now,
+            settings: self.settings.clone(),
         };
         repo.create_room(&room).await.unwrap();
 
@@ -170,6 +179,7 @@ impl TestSetupBuilder {
             player_mapping.clone(),
             event_bus.clone(),
             Arc::clone(&bot_manager),
+            Arc::clone(&stats_service),
         );
 
         let subscription = RoomSubscription::new(
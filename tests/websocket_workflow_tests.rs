@@ -1,6 +1,7 @@
 use bigtwo::{
     event::RoomEvent,
-    game::{Card, Rank, Suit},
+    game::{read_game, Card, Game, GameConfig, HouseRules, OpeningRule, Rank, RuleSet, Suit},
+    room::models::{RoomSettings, TakeBackRule},
     websockets::{MessageType, WebSocketMessage},
 };
 
@@ -37,7 +38,13 @@ async fn test_non_host_cannot_start_game() {
 
 #[tokio::test]
 async fn test_insufficient_players_cannot_start_game() {
-    let setup = TestSetupBuilder::new().with_two_players().build().await;
+    let setup = TestSetupBuilder::new()
+        .with_players(vec![(
+            "550e8400-e29b-41d4-a716-446655440000".to_string(),
+            "alice".to_string(),
+        )])
+        .build()
+        .await;
 
     setup
         .send_start_game("550e8400-e29b-41d4-a716-446655440000")
@@ -48,6 +55,25 @@ async fn test_insufficient_players_cannot_start_game() {
         .await;
 }
 
+#[tokio::test]
+async fn test_two_players_can_start_game() {
+    let setup = TestSetupBuilder::new().with_two_players().build().await;
+
+    setup
+        .send_start_game("550e8400-e29b-41d4-a716-446655440000")
+        .await;
+
+    let started = MessageAssertion::for_all_players(&setup)
+        .received_message_type(MessageType::GameStarted)
+        .await;
+
+    let player_list: Vec<String> =
+        serde_json::from_value(started.payload()["player_list"].clone()).unwrap();
+    let cards: Vec<String> = serde_json::from_value(started.payload()["cards"].clone()).unwrap();
+    assert_eq!(player_list.len(), 2);
+    assert_eq!(cards.len(), 13);
+}
+
 #[tokio::test]
 async fn test_turn_progression_after_move() {
     let setup = TestSetupBuilder::new().with_four_players().build().await;
@@ -106,6 +132,7 @@ async fn test_turn_progression_after_move() {
         .game_service
         .get_game("room-123")
         .await
+        .unwrap()
         .expect("Game should exist");
 
     let new_current_player = updated_game.current_player_turn();
@@ -145,7 +172,12 @@ async fn test_pass_move_after_initial_play() {
     setup.clear_messages().await;
 
     // Get the updated game to see who's next
-    let updated_game = setup.game_service.get_game("room-123").await.unwrap();
+    let updated_game = setup
+        .game_service
+        .get_game("room-123")
+        .await
+        .unwrap()
+        .unwrap();
     let second_player = updated_game.current_player_turn();
     setup.send_pass(&second_player).await;
 
@@ -165,7 +197,12 @@ async fn test_wrong_turn_player_cannot_move() {
         .await;
 
     // Find a player that's not the current player
-    let game = setup.game_service.get_game("room-123").await.unwrap();
+    let game = setup
+        .game_service
+        .get_game("room-123")
+        .await
+        .unwrap()
+        .unwrap();
     let wrong_player = game
         .players()
         .iter()
@@ -175,7 +212,12 @@ async fn test_wrong_turn_player_cannot_move() {
         .clone();
     setup.send_move(&wrong_player, vec!["4H"]).await;
 
-    MessageAssertion::for_all_players(&setup)
+    MessageAssertion::for_players(&setup, vec![wrong_player.as_str()])
+        .received_message_type(MessageType::Error)
+        .await
+        .with_error_code("NOT_YOUR_TURN");
+
+    MessageAssertion::for_all_players_except(&setup, &wrong_player)
         .received_no_messages()
         .await;
 }
@@ -193,11 +235,21 @@ async fn test_cannot_beat_single_card_with_pair() {
     setup.clear_messages().await;
 
     // Get the updated game to see who's next
-    let updated_game = setup.game_service.get_game("room-123").await.unwrap();
+    let updated_game = setup
+        .game_service
+        .get_game("room-123")
+        .await
+        .unwrap()
+        .unwrap();
     let second_player = updated_game.current_player_turn();
     setup.send_move(&second_player, vec!["4H", "4S"]).await;
 
-    MessageAssertion::for_all_players(&setup)
+    MessageAssertion::for_players(&setup, vec![second_player.as_str()])
+        .received_message_type(MessageType::Error)
+        .await
+        .with_error_code("CANNOT_BEAT_TABLE");
+
+    MessageAssertion::for_all_players_except(&setup, &second_player)
         .received_no_messages()
         .await;
 }
@@ -230,7 +282,12 @@ async fn test_first_player_can_play_anything_after_all_others_pass() {
     setup.clear_messages().await;
 
     // Get the current state and find all other players
-    let game = setup.game_service.get_game("room-123").await.unwrap();
+    let game = setup
+        .game_service
+        .get_game("room-123")
+        .await
+        .unwrap()
+        .unwrap();
     let other_players: Vec<String> = game
         .players()
         .iter()
@@ -246,7 +303,12 @@ async fn test_first_player_can_play_anything_after_all_others_pass() {
 
     // Now the first player has control and should be able to play anything
     // Let's just verify the current player is correct
-    let updated_game = setup.game_service.get_game("room-123").await.unwrap();
+    let updated_game = setup
+        .game_service
+        .get_game("room-123")
+        .await
+        .unwrap()
+        .unwrap();
     assert_eq!(updated_game.current_player_turn(), first_player);
 }
 
@@ -263,7 +325,12 @@ async fn test_first_player_can_change_combination_type_after_all_pass() {
     setup.clear_messages().await;
 
     // Get the current state and find all other players
-    let game = setup.game_service.get_game("room-123").await.unwrap();
+    let game = setup
+        .game_service
+        .get_game("room-123")
+        .await
+        .unwrap()
+        .unwrap();
     let other_players: Vec<String> = game
         .players()
         .iter()
@@ -279,7 +346,12 @@ async fn test_first_player_can_change_combination_type_after_all_pass() {
 
     // Now the first player should have control again and can play different combination types
     // Let's just verify the game state allows the first player to play
-    let updated_game = setup.game_service.get_game("room-123").await.unwrap();
+    let updated_game = setup
+        .game_service
+        .get_game("room-123")
+        .await
+        .unwrap()
+        .unwrap();
     assert_eq!(updated_game.current_player_turn(), first_player);
     // In Big Two, after all players pass, the last player to play can play any combination type
     assert_eq!(updated_game.consecutive_passes(), 3); // 3 other players passed
@@ -296,8 +368,13 @@ async fn test_first_turn_must_include_three_of_diamonds() {
     // Try to play without 3 of diamonds on first turn - should fail
     setup.send_move(&first_player, vec!["4H"]).await;
 
-    // No messages should be sent since the move is invalid
-    MessageAssertion::for_all_players(&setup)
+    // Only the mover is told why the move was rejected
+    MessageAssertion::for_players(&setup, vec![first_player.as_str()])
+        .received_message_type(MessageType::Error)
+        .await
+        .with_error_code("FIRST_TURN_REQUIRES_THREE_OF_DIAMONDS");
+
+    MessageAssertion::for_all_players_except(&setup, &first_player)
         .received_no_messages()
         .await;
 }
@@ -321,6 +398,27 @@ async fn test_first_turn_with_three_of_diamonds_succeeds() {
         .with_cards(vec!["3D"]);
 }
 
+#[tokio::test]
+async fn test_move_played_is_announced_from_the_event() {
+    // The stored game may have moved on (or ended) by the time MOVE_PLAYED is sent,
+    // so the message must describe the move as it was played, not the current game
+    let setup = TestSetupBuilder::new().with_two_players().build().await;
+    setup
+        .emit_event(RoomEvent::MovePlayed {
+            player: "550e8400-e29b-41d4-a716-446655440000".to_string(),
+            cards: vec![Card::new(Rank::Three, Suit::Diamonds)],
+            remaining_cards: 12,
+        })
+        .await;
+
+    let message = MessageAssertion::for_all_players(&setup)
+        .received_message_type(MessageType::MovePlayed)
+        .await
+        .with_player("550e8400-e29b-41d4-a716-446655440000")
+        .with_cards(vec!["3D"]);
+    assert_eq!(message.payload()["remaining_cards"], 12);
+}
+
 #[tokio::test]
 async fn test_game_started_includes_last_played_cards_in_single_message() {
     // Regression test for reconnection bug: GAME_STARTED should include last_played_cards,
@@ -337,9 +435,17 @@ async fn test_game_started_includes_last_played_cards_in_single_message() {
 
     // Simulate what happens during game start/reconnection
     // by triggering a fresh start game event with existing game state
-    let game = setup.game_service.get_game("room-123").await.unwrap();
+    let game = setup
+        .game_service
+        .get_game("room-123")
+        .await
+        .unwrap()
+        .unwrap();
     setup
-        .emit_event(bigtwo::event::RoomEvent::StartGame { game: game.clone() })
+        .emit_event(bigtwo::event::RoomEvent::StartGame {
+            players: game.players().iter().map(|p| p.uuid.clone()).collect(),
+            current_turn: game.current_player_turn(),
+        })
         .await;
 
     // Each player should receive exactly ONE GAME_STARTED message
@@ -387,7 +493,12 @@ async fn test_bot_player_mapping_cleaned_up_when_room_deleted() {
     // Add a bot to the room
     let bot = setup
         .bot_manager
-        .create_bot(room_id.clone(), bigtwo::bot::types::BotDifficulty::Easy)
+        .create_bot(
+            room_id.clone(),
+            bigtwo::bot::types::BotDifficulty::Easy,
+            None,
+            bigtwo::bot::profile::BotProfile::default(),
+        )
         .await
         .expect("Failed to create bot");
 
@@ -499,7 +610,12 @@ async fn test_heartbeat_during_active_game_does_not_interfere() {
         .await;
 
     // Verify game state is unchanged
-    let game = setup.game_service.get_game("room-123").await.unwrap();
+    let game = setup
+        .game_service
+        .get_game("room-123")
+        .await
+        .unwrap()
+        .unwrap();
     assert_ne!(
         game.current_player_turn(),
         first_player,
@@ -525,3 +641,448 @@ async fn test_heartbeat_before_game_starts() {
         .received_message_type(MessageType::HeartbeatAck)
         .await;
 }
+
+#[tokio::test]
+async fn test_sync_returns_game_state_to_requester_only() {
+    let setup = TestSetupBuilder::new().with_four_players().build().await;
+    let first_player = GameBuilder::new()
+        .with_simple_four_player_game()
+        .build_with_setup(&setup)
+        .await;
+
+    setup.send_move(&first_player, vec!["3D"]).await;
+    setup.clear_messages().await;
+
+    setup
+        .send_message(
+            &first_player,
+            WebSocketMessage::new(MessageType::Sync, serde_json::json!({})),
+        )
+        .await;
+
+    let snapshot = MessageAssertion::for_players(&setup, vec![first_player.as_str()])
+        .received_message_type(MessageType::GameState)
+        .await;
+    let payload = snapshot.payload();
+    assert_eq!(
+        payload["game"]["last_played_cards"],
+        serde_json::json!(["3D"])
+    );
+    assert_eq!(payload["game"]["last_played_by"], first_player.as_str());
+    assert_eq!(payload["game"]["table_clear"], false);
+
+    MessageAssertion::for_all_players_except(&setup, &first_player)
+        .received_no_messages()
+        .await;
+}
+
+#[tokio::test]
+async fn test_room_rule_set_applies_to_new_games() {
+    let setup = TestSetupBuilder::new()
+        .with_two_players()
+        .with_settings(RoomSettings {
+            rules: RuleSet::pusoy_dos(),
+            ..RoomSettings::default()
+        })
+        .build()
+        .await;
+
+    setup
+        .send_start_game("550e8400-e29b-41d4-a716-446655440000")
+        .await;
+
+    let game = setup
+        .game_service
+        .get_game("room-123")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(*game.rules(), RuleSet::pusoy_dos());
+    // In Pusoy Dos clubs are the lowest suit, so the lowest card dealt is at most the 3♣
+    let lowest = game
+        .players()
+        .iter()
+        .flat_map(|p| p.cards.iter())
+        .min_by(|a, b| game.rules().compare_cards(a, b))
+        .copied()
+        .unwrap();
+    assert!(game.current_player().cards.contains(&lowest));
+}
+
+#[tokio::test]
+async fn test_room_house_rules_apply_to_new_games() {
+    let house_rules = HouseRules {
+        pass_lockout: true,
+        highest_single_against_last_card: true,
+        opening: OpeningRule::HolderLeads,
+        ..HouseRules::default()
+    };
+    let setup = TestSetupBuilder::new()
+        .with_two_players()
+        .with_settings(RoomSettings {
+            house_rules,
+            ..RoomSettings::default()
+        })
+        .build()
+        .await;
+
+    setup
+        .send_start_game("550e8400-e29b-41d4-a716-446655440000")
+        .await;
+
+    let game = setup
+        .game_service
+        .get_game("room-123")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(*game.house_rules(), house_rules);
+}
+
+#[tokio::test]
+async fn test_start_game_with_seed_replays_the_deal() {
+    let start = WebSocketMessage::new(MessageType::StartGame, serde_json::json!({"seed": 42}));
+    let mut dealt = Vec::new();
+    for _ in 0..2 {
+        let setup = TestSetupBuilder::new().with_four_players().build().await;
+        setup
+            .send_message("550e8400-e29b-41d4-a716-446655440000", start.clone())
+            .await;
+        let game = setup
+            .game_service
+            .get_game("room-123")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(game.deal_seed(), Some(42));
+        dealt.push(game.starting_hands().clone());
+    }
+    assert_eq!(dealt[0], dealt[1]);
+}
+
+#[tokio::test]
+async fn test_match_over_is_announced_after_the_last_game() {
+    let setup = TestSetupBuilder::new().with_two_players().build().await;
+    setup
+        .send_message(
+            "550e8400-e29b-41d4-a716-446655440000",
+            WebSocketMessage::new(
+                MessageType::StartGame,
+                serde_json::json!({"match": {"games": 1}}),
+            ),
+        )
+        .await;
+
+    // Both players lead or follow with their first legal hand until the game ends
+    for _ in 0..200 {
+        let Some(game) = setup.game_service.get_game("room-123").await.unwrap() else {
+            break;
+        };
+        let player = game.current_player_turn();
+        let cards: Vec<String> = game
+            .legal_moves(&player)
+            .hands()
+            .next()
+            .map(|hand| hand.to_cards().iter().map(|c| c.to_string()).collect())
+            .unwrap_or_default();
+        setup
+            .send_move(&player, cards.iter().map(String::as_str).collect())
+            .await;
+    }
+
+    let current_match = setup.game_service.get_match("room-123").await.unwrap();
+    assert!(current_match.is_over());
+    let messages = setup
+        .mock_conn_manager
+        .get_messages_for("550e8400-e29b-41d4-a716-446655440001")
+        .await;
+    let match_over = messages
+        .iter()
+        .map(|m| serde_json::from_str::<WebSocketMessage>(m).unwrap())
+        .find(|m| m.message_type == MessageType::MatchOver)
+        .expect("MATCH_OVER follows the game that ends the match");
+    assert_eq!(
+        match_over.payload["standings"],
+        serde_json::to_value(current_match.standings()).unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_room_finishing_order_plays_out_every_place() {
+    let setup = TestSetupBuilder::new()
+        .with_four_players()
+        .with_settings(RoomSettings {
+            house_rules: HouseRules {
+                finishing_order: true,
+                ..HouseRules::default()
+            },
+            ..RoomSettings::default()
+        })
+        .build()
+        .await;
+
+    setup
+        .send_start_game("550e8400-e29b-41d4-a716-446655440000")
+        .await;
+
+    // Everyone plays their first legal hand until every place is decided and the game is cleared
+    let mut first_out = None;
+    for _ in 0..200 {
+        let Some(game) = setup.game_service.get_game("room-123").await.unwrap() else {
+            break;
+        };
+        if first_out.is_none() {
+            first_out = game.finishing_order().first().cloned();
+        }
+        let player = game.current_player_turn();
+        let cards: Vec<String> = game
+            .legal_moves(&player)
+            .hands()
+            .next()
+            .map(|hand| hand.to_cards().iter().map(|c| c.to_string()).collect())
+            .unwrap_or_default();
+        setup
+            .send_move(&player, cards.iter().map(String::as_str).collect())
+            .await;
+    }
+    assert!(setup
+        .game_service
+        .get_game("room-123")
+        .await
+        .unwrap()
+        .is_none());
+    // The first player out did not end the game
+    let first_out = first_out.expect("someone went out while the game carried on");
+
+    let messages = setup
+        .mock_conn_manager
+        .get_messages_for("550e8400-e29b-41d4-a716-446655440000")
+        .await;
+    let game_won = messages
+        .iter()
+        .map(|m| serde_json::from_str::<WebSocketMessage>(m).unwrap())
+        .find(|m| m.message_type == MessageType::GameWon)
+        .expect("GAME_WON is sent once every place is decided");
+    let finishing_order = game_won.payload["finishing_order"].as_array().unwrap();
+    assert_eq!(finishing_order.len(), 4);
+    assert_eq!(finishing_order[0], first_out.as_str());
+}
+
+#[tokio::test]
+async fn test_room_misdeal_rule_allows_a_redeal() {
+    // With the threshold at Two every dealt hand is a misdeal
+    let house_rules = HouseRules {
+        misdeal_max_rank: Some(Rank::Two),
+        ..HouseRules::default()
+    };
+    let setup = TestSetupBuilder::new()
+        .with_two_players()
+        .with_settings(RoomSettings {
+            house_rules,
+            ..RoomSettings::default()
+        })
+        .build()
+        .await;
+
+    setup
+        .send_start_game("550e8400-e29b-41d4-a716-446655440000")
+        .await;
+    let first = setup
+        .game_service
+        .get_game("room-123")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(*first.house_rules(), house_rules);
+    setup.clear_messages().await;
+
+    setup
+        .send_message(
+            "550e8400-e29b-41d4-a716-446655440001",
+            WebSocketMessage::new(MessageType::Redeal, serde_json::json!({})),
+        )
+        .await;
+
+    MessageAssertion::for_all_players(&setup)
+        .received_message_type(MessageType::Misdeal)
+        .await
+        .with_player("550e8400-e29b-41d4-a716-446655440001");
+    MessageAssertion::for_all_players(&setup)
+        .received_message_type(MessageType::GameStarted)
+        .await;
+
+    let redealt = setup
+        .game_service
+        .get_game("room-123")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(*redealt.house_rules(), house_rules);
+    assert_ne!(redealt.deal_seed(), first.deal_seed());
+}
+
+#[tokio::test]
+async fn test_dealt_instant_win_ends_the_game() {
+    let setup = TestSetupBuilder::new().with_four_players().build().await;
+    let house_rules = HouseRules {
+        instant_wins: true,
+        ..HouseRules::default()
+    };
+
+    // Find a deal that hands someone a dragon or all four 2s
+    let player_data: Vec<(String, String)> = setup
+        .players
+        .iter()
+        .map(|(uuid, name)| (name.clone(), uuid.clone()))
+        .collect();
+    let (seed, winner) = (0..)
+        .find_map(|seed| {
+            let config = GameConfig {
+                house_rules,
+                seed: Some(seed),
+                ..GameConfig::default()
+            };
+            let mut game =
+                Game::new_game_with_config("room-123".to_string(), &player_data, &config).unwrap();
+            game.claim_instant_win()?;
+            Some((seed, game.winner().unwrap()))
+        })
+        .unwrap();
+
+    setup
+        .emit_event(RoomEvent::CreateGame {
+            players: setup.players.iter().map(|(uuid, _)| uuid.clone()).collect(),
+            config: GameConfig {
+                house_rules,
+                seed: Some(seed),
+                ..GameConfig::default()
+            },
+        })
+        .await;
+
+    // Everyone sees the deal before the instant win is announced
+    MessageAssertion::for_all_players(&setup)
+        .received_message_type(MessageType::GameStarted)
+        .await;
+    MessageAssertion::for_all_players(&setup)
+        .received_message_type(MessageType::InstantWin)
+        .await
+        .with_player(&winner);
+    let game_won = MessageAssertion::for_all_players(&setup)
+        .received_message_type(MessageType::GameWon)
+        .await;
+    assert_eq!(game_won.payload()["winner"], winner.as_str());
+
+    // The notation replays to the same finished game
+    let notation = game_won.payload()["notation"].as_str().unwrap().to_string();
+    let replayed = read_game(&notation).unwrap();
+    assert_eq!(replayed.winner(), Some(winner));
+}
+
+/// Start a two-player game under `take_back` and have the opener lead their lowest single
+async fn opener_has_played(take_back: TakeBackRule) -> (TestSetup, String, String) {
+    let setup = TestSetupBuilder::new()
+        .with_two_players()
+        .with_settings(RoomSettings {
+            take_back,
+            ..RoomSettings::default()
+        })
+        .build()
+        .await;
+    setup
+        .send_start_game("550e8400-e29b-41d4-a716-446655440000")
+        .await;
+
+    let game = setup
+        .game_service
+        .get_game("room-123")
+        .await
+        .unwrap()
+        .unwrap();
+    let opener = game.current_player_turn();
+    let other = game
+        .players()
+        .iter()
+        .find(|p| p.uuid != opener)
+        .unwrap()
+        .uuid
+        .clone();
+    let lead = game.legal_moves(&opener).singles[0].to_cards()[0].to_string();
+    setup.send_move(&opener, vec![lead.as_str()]).await;
+    setup.clear_messages().await;
+    (setup, opener, other)
+}
+
+#[tokio::test]
+async fn test_take_back_follows_the_room_setting() {
+    let take_back = WebSocketMessage::new(MessageType::TakeBack, serde_json::json!({}));
+
+    let (setup, opener, _) = opener_has_played(TakeBackRule::Off).await;
+    setup.send_message(&opener, take_back.clone()).await;
+    MessageAssertion::for_players(&setup, vec![&opener])
+        .received_message_type(MessageType::Error)
+        .await
+        .with_error_code("TAKE_BACK_DISABLED");
+
+    let (setup, opener, _) = opener_has_played(TakeBackRule::UntilNextMove).await;
+    setup.send_message(&opener, take_back).await;
+    MessageAssertion::for_all_players(&setup)
+        .received_message_type(MessageType::MoveTakenBack)
+        .await
+        .with_player(&opener);
+    let game = setup
+        .game_service
+        .get_game("room-123")
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(game.played_hands().is_empty());
+}
+
+#[tokio::test]
+async fn test_take_back_waits_for_consent() {
+    let (setup, opener, other) = opener_has_played(TakeBackRule::UnanimousConsent).await;
+    setup
+        .send_message(
+            &opener,
+            WebSocketMessage::new(MessageType::TakeBack, serde_json::json!({})),
+        )
+        .await;
+    let request = MessageAssertion::for_all_players(&setup)
+        .received_message_type(MessageType::TakeBackRequested)
+        .await
+        .with_player(&opener);
+    assert_eq!(request.payload()["voters"], serde_json::json!([other]));
+    assert_eq!(
+        setup
+            .game_service
+            .get_game("room-123")
+            .await
+            .unwrap()
+            .unwrap()
+            .played_hands()
+            .len(),
+        1
+    );
+
+    setup
+        .send_message(
+            &other,
+            WebSocketMessage::new(
+                MessageType::TakeBackVote,
+                serde_json::json!({"approve": true}),
+            ),
+        )
+        .await;
+    MessageAssertion::for_all_players(&setup)
+        .received_message_type(MessageType::MoveTakenBack)
+        .await
+        .with_player(&opener);
+    let game = setup
+        .game_service
+        .get_game("room-123")
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(game.played_hands().is_empty());
+}